@@ -0,0 +1,136 @@
+//! Co-signed payouts.
+//!
+//! A group moving large sums may not want a single transaction to release a
+//! payout. The group admin can name approvers, such as the group's elders
+//! or an officer of a partner bank, and require that any payout above a
+//! threshold be approved by some of them first. Approvals are recorded per
+//! cycle and lapse after the policy's expiry, so an old sign-off never
+//! releases a payout nobody looked at.
+
+use crate::error::StellarSaveError;
+use crate::storage::{set_persistent, StorageKeyBuilder};
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+/// Who must approve large payouts, and how many of them.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ApprovalPolicy {
+    /// Addresses whose approval counts
+    pub approvers: Vec<Address>,
+    /// Approvals a payout above `threshold` needs
+    pub required: u32,
+    /// Largest payout to a single recipient that needs no approval
+    pub threshold: i128,
+    /// How long an approval counts for, in seconds
+    pub expiry: u64,
+}
+
+impl ApprovalPolicy {
+    /// Whether the policy can be met: at least one approval required, no
+    /// more than there are distinct approvers, a threshold of at least zero
+    /// and a non-zero expiry.
+    pub fn is_valid(&self) -> bool {
+        let distinct = self.approvers.iter().enumerate().all(|(index, approver)| {
+            self.approvers.first_index_of(&approver) == Some(index as u32)
+        });
+        distinct
+            && self.required > 0
+            && self.required <= self.approvers.len()
+            && self.threshold >= 0
+            && self.expiry > 0
+    }
+}
+
+/// An approver's sign-off on a cycle's payout.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutApproval {
+    pub approver: Address,
+    pub approved_at: u64,
+}
+
+/// Returns the group's approval policy, if it has one.
+pub fn load_policy(env: &Env, group_id: u64) -> Option<ApprovalPolicy> {
+    env.storage()
+        .persistent()
+        .get(&StorageKeyBuilder::group_approval_policy(group_id))
+}
+
+/// Sets the group's approval policy, or removes it with `None`.
+pub fn store_policy(env: &Env, group_id: u64, policy: &Option<ApprovalPolicy>) {
+    let key = StorageKeyBuilder::group_approval_policy(group_id);
+    match policy {
+        Some(policy) => set_persistent(env, &key, policy),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+/// Returns the approvals recorded for a cycle's payout, oldest first.
+pub fn load_approvals(env: &Env, group_id: u64, cycle: u32) -> Vec<PayoutApproval> {
+    env.storage()
+        .persistent()
+        .get(&StorageKeyBuilder::payout_approvals(group_id, cycle))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Records `approver`'s approval of a cycle's payout at `timestamp`,
+/// replacing any earlier one of theirs.
+pub(crate) fn approve(env: &Env, group_id: u64, cycle: u32, approver: &Address, timestamp: u64) {
+    let mut approvals = Vec::new(env);
+    for approval in load_approvals(env, group_id, cycle).iter() {
+        if approval.approver != *approver {
+            approvals.push_back(approval);
+        }
+    }
+    approvals.push_back(PayoutApproval {
+        approver: approver.clone(),
+        approved_at: timestamp,
+    });
+    set_persistent(
+        env,
+        &StorageKeyBuilder::payout_approvals(group_id, cycle),
+        &approvals,
+    );
+}
+
+/// Counts the approvals of a cycle's payout that still count at `now`:
+/// given by a current approver, and not yet expired.
+pub(crate) fn live_approvals(
+    env: &Env,
+    policy: &ApprovalPolicy,
+    group_id: u64,
+    cycle: u32,
+    now: u64,
+) -> u32 {
+    load_approvals(env, group_id, cycle)
+        .iter()
+        .filter(|approval| {
+            policy.approvers.contains(&approval.approver)
+                && approval.approved_at.saturating_add(policy.expiry) >= now
+        })
+        .count() as u32
+}
+
+/// Checks that a payout of `amount` to a single recipient has the approvals
+/// the group's policy asks for. Groups without a policy need none.
+///
+/// # Errors
+/// - `PayoutNotApproved` - The amount is above the threshold and fewer than
+///   `required` approvals still count
+pub(crate) fn check(
+    env: &Env,
+    group_id: u64,
+    cycle: u32,
+    amount: i128,
+    now: u64,
+) -> Result<(), StellarSaveError> {
+    let Some(policy) = load_policy(env, group_id) else {
+        return Ok(());
+    };
+    if amount > policy.threshold
+        && live_approvals(env, &policy, group_id, cycle, now) < policy.required
+    {
+        return Err(StellarSaveError::PayoutNotApproved);
+    }
+    Ok(())
+}
@@ -0,0 +1,63 @@
+//! Attestation-gated groups.
+//!
+//! A community group may only want members a third party vouches for, such
+//! as residents of a country or members of a cooperative. Rather than keep
+//! KYC or community records here, the group admin names an attestation
+//! contract and a claim, and `join_group` asks that contract whether the
+//! joining address holds the claim. A gate can also turn away holders of a
+//! claim instead. If the attestation contract fails, the address is treated
+//! as not attested, so an outage never lets anyone past the gate.
+
+use crate::storage::{set_persistent, StorageKeyBuilder};
+use soroban_sdk::{contractclient, contracttype, Address, Env, Symbol};
+
+/// Interface an attestation contract must implement.
+#[contractclient(name = "AttestorClient")]
+pub trait Attestor {
+    /// Whether `address` holds `claim`.
+    fn has_attestation(env: Env, address: Address, claim: Symbol) -> bool;
+}
+
+/// Who a group admits, as vouched for by an attestation contract.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttestationGate {
+    /// Contract implementing `Attestor`
+    pub attestor: Address,
+    /// Claim the attestor is asked about, such as a country or community tag
+    pub claim: Symbol,
+    /// If true, holders of the claim are turned away and everyone else is
+    /// admitted
+    pub deny: bool,
+}
+
+/// Returns the group's attestation gate, if it has one.
+pub fn load(env: &Env, group_id: u64) -> Option<AttestationGate> {
+    env.storage()
+        .persistent()
+        .get(&StorageKeyBuilder::group_attestation_gate(group_id))
+}
+
+/// Sets the group's attestation gate, or removes it with `None`.
+pub fn store(env: &Env, group_id: u64, gate: &Option<AttestationGate>) {
+    let key = StorageKeyBuilder::group_attestation_gate(group_id);
+    match gate {
+        Some(gate) => set_persistent(env, &key, gate),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+/// Whether the group's gate lets `address` in. Groups without a gate admit
+/// everyone.
+pub(crate) fn admits(env: &Env, group_id: u64, address: &Address) -> bool {
+    let Some(gate) = load(env, group_id) else {
+        return true;
+    };
+
+    let attested =
+        AttestorClient::new(env, &gate.attestor).try_has_attestation(address, &gate.claim);
+    match attested {
+        Ok(Ok(holds_claim)) => holds_claim != gate.deny,
+        _ => false,
+    }
+}
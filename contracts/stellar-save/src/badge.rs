@@ -0,0 +1,120 @@
+//! Membership badges.
+//!
+//! Joining a group mints the member a badge for it, so other Soroban
+//! contracts, such as lenders underwriting credit, can check someone's ROSCA
+//! record with a single call. Badges are soulbound: there is no way to
+//! transfer one, and they outlive the membership itself, so a completed
+//! group or a removal stays on the member's record. Only leaving a group
+//! before it starts burns its badge.
+//!
+//! The index kept here only lists which groups an address holds a badge for;
+//! the join date and standing are read from the member's profile, or their
+//! final profile once removed, so a badge always shows their current status.
+
+use crate::group::GroupStatus;
+use crate::storage::{set_persistent, MemberStore, StorageKeyBuilder};
+use crate::{status, MemberStatus};
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+/// Proof that an address is or was a member of a group.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MembershipBadge {
+    /// Group the badge was minted for
+    pub group_id: u64,
+    /// When the member joined the group
+    pub joined_at: u64,
+    /// The member's standing in the group
+    pub member_status: MemberStatus,
+    /// The group's lifecycle status
+    pub group_status: GroupStatus,
+}
+
+/// Returns the IDs of the groups an address holds a badge for, in the order
+/// they were minted.
+pub fn groups(env: &Env, holder: &Address) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&StorageKeyBuilder::member_badges_index(holder.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Whether an address holds a badge for a group.
+pub fn has_badge(env: &Env, holder: &Address, group_id: u64) -> bool {
+    groups(env, holder).contains(group_id)
+}
+
+/// Mints a badge for a group, if the address doesn't hold one already.
+pub fn mint(env: &Env, holder: &Address, group_id: u64) {
+    let mut ids = groups(env, holder);
+    if !ids.contains(group_id) {
+        ids.push_back(group_id);
+        set_persistent(
+            env,
+            &StorageKeyBuilder::member_badges_index(holder.clone()),
+            &ids,
+        );
+    }
+}
+
+/// Burns an address's badge for a group.
+pub fn burn(env: &Env, holder: &Address, group_id: u64) {
+    let key = StorageKeyBuilder::member_badges_index(holder.clone());
+    let mut ids = groups(env, holder);
+    if let Some(index) = ids.first_index_of(group_id) {
+        ids.remove(index);
+        if ids.is_empty() {
+            env.storage().persistent().remove(&key);
+        } else {
+            set_persistent(env, &key, &ids);
+        }
+    }
+}
+
+/// Returns an address's badges, in the order they were minted.
+pub fn load(env: &Env, holder: &Address) -> Vec<MembershipBadge> {
+    let mut badges = Vec::new(env);
+    for group_id in groups(env, holder).iter() {
+        let former_key = StorageKeyBuilder::member_former_profile(group_id, holder.clone());
+        let profile = MemberStore::load(env, group_id, holder)
+            .or_else(|| env.storage().persistent().get(&former_key));
+        if let Some(profile) = profile {
+            badges.push_back(MembershipBadge {
+                group_id,
+                joined_at: profile.joined_at,
+                member_status: profile.status,
+                group_status: status::load(env, group_id),
+            });
+        }
+    }
+    badges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+
+    #[test]
+    fn test_mint_and_burn_badges() {
+        let env = Env::default();
+        let contract_id = crate::testutils::register(&env);
+        let holder = Address::generate(&env);
+
+        env.as_contract(&contract_id, || {
+            mint(&env, &holder, 1);
+            mint(&env, &holder, 2);
+            mint(&env, &holder, 1);
+            assert_eq!(groups(&env, &holder), soroban_sdk::vec![&env, 1, 2]);
+            assert!(has_badge(&env, &holder, 2));
+
+            burn(&env, &holder, 1);
+            assert!(!has_badge(&env, &holder, 1));
+            burn(&env, &holder, 2);
+            assert!(!env
+                .storage()
+                .persistent()
+                .has(&StorageKeyBuilder::member_badges_index(holder.clone())));
+        });
+    }
+}
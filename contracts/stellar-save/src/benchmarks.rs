@@ -0,0 +1,303 @@
+//! Cost benchmarks for large groups.
+//!
+//! Measures the resources used by `assign_payout_positions` for every
+//! `AssignmentMode`, and by contributing and paying out, across a range of
+//! group sizes and prints comparison tables. Figures come from the host's
+//! invocation metering, so they cover contract logic and storage access but
+//! not Wasm VM overhead.
+//!
+//! The contribution and payout table also shows how much of each network
+//! transaction limit a call uses and the largest measured group size every
+//! call fits in, as a guide for the supported member-count ceiling.
+//!
+//! Only built with the `benchmarks` feature:
+//!
+//! ```text
+//! cargo test -p stellar-save --features benchmarks benchmarks -- --nocapture
+//! ```
+
+extern crate std;
+
+use crate::testutils::{self, TestGroupBuilder};
+use crate::units::{SECONDS_PER_WEEK, STROOPS_PER_XLM};
+use crate::{AssignmentMode, PayoutOutcome, StellarSaveContractClient};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env, Vec};
+use std::println;
+
+const GROUP_SIZES: [u32; 4] = [5, 10, 25, 50];
+
+const LARGE_GROUP_SIZES: [u32; 4] = [5, 25, 50, 100];
+
+/// Per-transaction resource limits on mainnet as of protocol 23. The network
+/// can change these by vote, so compare against the current settings before
+/// relying on the ceiling reported here.
+const TX_MAX_INSTRUCTIONS: i64 = 100_000_000;
+const TX_MAX_MEM_BYTES: i64 = 41_943_040;
+const TX_MAX_READ_ENTRIES: u32 = 100;
+const TX_MAX_WRITE_ENTRIES: u32 = 50;
+const TX_MAX_WRITE_BYTES: u32 = 132_096;
+
+#[derive(Clone, Copy, Debug)]
+struct Measurement {
+    instructions: i64,
+    mem_bytes: i64,
+    read_entries: u32,
+    write_entries: u32,
+    write_bytes: u32,
+}
+
+impl Measurement {
+    fn from_last_invocation(env: &Env) -> Self {
+        let resources = env.cost_estimate().resources();
+        Self {
+            instructions: resources.instructions,
+            mem_bytes: resources.mem_bytes,
+            read_entries: resources.disk_read_entries + resources.memory_read_entries,
+            write_entries: resources.write_entries,
+            write_bytes: resources.write_bytes,
+        }
+    }
+
+    /// Largest share of any transaction limit used, in percent.
+    fn peak_limit_pct(&self) -> i64 {
+        [
+            self.instructions * 100 / TX_MAX_INSTRUCTIONS,
+            self.mem_bytes * 100 / TX_MAX_MEM_BYTES,
+            self.read_entries as i64 * 100 / TX_MAX_READ_ENTRIES as i64,
+            self.write_entries as i64 * 100 / TX_MAX_WRITE_ENTRIES as i64,
+            self.write_bytes as i64 * 100 / TX_MAX_WRITE_BYTES as i64,
+        ]
+        .into_iter()
+        .max()
+        .unwrap_or(0)
+    }
+
+    fn fits_in_transaction(&self) -> bool {
+        self.instructions <= TX_MAX_INSTRUCTIONS
+            && self.mem_bytes <= TX_MAX_MEM_BYTES
+            && self.read_entries <= TX_MAX_READ_ENTRIES
+            && self.write_entries <= TX_MAX_WRITE_ENTRIES
+            && self.write_bytes <= TX_MAX_WRITE_BYTES
+    }
+}
+
+fn strategy_name(mode: &AssignmentMode) -> &'static str {
+    match mode {
+        AssignmentMode::Sequential => "sequential",
+        AssignmentMode::Random => "random",
+        AssignmentMode::Manual(_) => "manual",
+    }
+}
+
+fn strategies(env: &Env, size: u32) -> [AssignmentMode; 3] {
+    // Reverse order is the worst case for manual: every member moves.
+    let mut reversed = Vec::new(env);
+    for i in (0..size).rev() {
+        reversed.push_back(i);
+    }
+
+    [
+        AssignmentMode::Sequential,
+        AssignmentMode::Random,
+        AssignmentMode::Manual(reversed),
+    ]
+}
+
+fn measure(size: u32, strategy: usize) -> Measurement {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.cost_estimate().budget().reset_unlimited();
+
+    let contract_id = testutils::register(&env);
+    let client = StellarSaveContractClient::new(&env, &contract_id);
+
+    let creator = Address::generate(&env);
+    let group_id = client.create_group(&creator, &STROOPS_PER_XLM, &SECONDS_PER_WEEK, &size);
+    for _ in 0..size {
+        client.join_group(&group_id, &Address::generate(&env), &None);
+    }
+
+    let mode = strategies(&env, size)[strategy].clone();
+    client.assign_payout_positions(&group_id, &creator, &mode);
+
+    Measurement::from_last_invocation(&env)
+}
+
+/// Calls measured on a group of each size.
+#[derive(Clone, Copy, Debug)]
+enum Operation {
+    /// The last member's contribution to the first cycle
+    Contribute,
+    /// The first cycle's payout
+    Payout,
+    /// The last cycle's payout, which also settles the completed group
+    FinalPayout,
+}
+
+const OPERATIONS: [Operation; 3] = [
+    Operation::Contribute,
+    Operation::Payout,
+    Operation::FinalPayout,
+];
+
+impl Operation {
+    fn name(&self) -> &'static str {
+        match self {
+            Operation::Contribute => "contribute",
+            Operation::Payout => "payout",
+            Operation::FinalPayout => "final_payout",
+        }
+    }
+}
+
+fn measure_operation(size: u32, operation: Operation) -> Measurement {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+    let group = TestGroupBuilder::new(&env)
+        .members(size)
+        .contribution(STROOPS_PER_XLM)
+        .funded(STROOPS_PER_XLM)
+        .activated()
+        .build();
+    let client = group.client(&env).mock_all_auths();
+    let contract_id = &group.contract_id;
+    let batch = Vec::from_array(&env, [group.group_id]);
+
+    match operation {
+        Operation::Contribute => {
+            let last = group.members.last().unwrap();
+            for member in group.members.iter().take(size as usize - 1) {
+                testutils::contribute(&env, contract_id, group.group_id, &member).unwrap();
+            }
+            testutils::token(&env, contract_id)
+                .mock_all_auths()
+                .approve(
+                    &last,
+                    contract_id,
+                    &STROOPS_PER_XLM,
+                    &(env.ledger().sequence() + 100),
+                );
+            client.enable_auto_contribute(&group.group_id, &last);
+            client.pull_contribution(&group.group_id, &last);
+        }
+        Operation::Payout | Operation::FinalPayout => {
+            if let Operation::FinalPayout = operation {
+                testutils::fast_forward_cycles(&env, contract_id, group.group_id, size - 1);
+            }
+            testutils::contribute_all(&env, contract_id, group.group_id);
+            testutils::mint(
+                &env,
+                contract_id,
+                contract_id,
+                STROOPS_PER_XLM * size as i128,
+            );
+            let results = client.execute_payouts(&batch);
+            if let PayoutOutcome::Skipped(code) = results.get(0).unwrap().outcome {
+                panic!("{} at size {} skipped with {}", operation.name(), size, code);
+            }
+        }
+    }
+
+    Measurement::from_last_invocation(&env)
+}
+
+#[test]
+fn bench_payout_ordering_strategies() {
+    let env = Env::default();
+
+    println!(
+        "{:<12} {:>6} {:>14} {:>12} {:>8} {:>8} {:>10}",
+        "strategy", "size", "instructions", "mem_bytes", "reads", "writes", "write_bytes"
+    );
+
+    for (strategy, mode) in strategies(&env, 0).iter().enumerate() {
+        let mut previous: Option<Measurement> = None;
+
+        for size in GROUP_SIZES {
+            let m = measure(size, strategy);
+            println!(
+                "{:<12} {:>6} {:>14} {:>12} {:>8} {:>8} {:>10}",
+                strategy_name(mode),
+                size,
+                m.instructions,
+                m.mem_bytes,
+                m.read_entries,
+                m.write_entries,
+                m.write_bytes
+            );
+
+            // Each member's profile and position entry is rewritten once.
+            assert!(m.write_entries >= 2 * size);
+            if let Some(prev) = previous {
+                assert!(m.instructions > prev.instructions);
+            }
+            previous = Some(m);
+        }
+    }
+}
+
+#[test]
+fn bench_large_group_operations() {
+    println!(
+        "{:<13} {:>6} {:>14} {:>12} {:>8} {:>8} {:>11} {:>7}",
+        "operation",
+        "size",
+        "instructions",
+        "mem_bytes",
+        "reads",
+        "writes",
+        "write_bytes",
+        "limit%"
+    );
+
+    let mut ceiling = u32::MAX;
+    for operation in OPERATIONS {
+        // Largest measured size the operation fits in one transaction at
+        let mut largest_fitting = 0;
+        for size in LARGE_GROUP_SIZES {
+            if largest_fitting != size_before(size) {
+                // Costs only grow with the group, so larger sizes are not
+                // worth the time it takes to set them up
+                println!("{:<13} {:>6} exceeds limits", operation.name(), size);
+                continue;
+            }
+
+            let m = measure_operation(size, operation);
+            println!(
+                "{:<13} {:>6} {:>14} {:>12} {:>8} {:>8} {:>11} {:>7}",
+                operation.name(),
+                size,
+                m.instructions,
+                m.mem_bytes,
+                m.read_entries,
+                m.write_entries,
+                m.write_bytes,
+                m.peak_limit_pct()
+            );
+            if m.fits_in_transaction() {
+                largest_fitting = size;
+            }
+        }
+        ceiling = ceiling.min(largest_fitting);
+    }
+
+    println!(
+        "every operation fits in one transaction up to {} members",
+        ceiling
+    );
+    assert!(
+        ceiling >= LARGE_GROUP_SIZES[0],
+        "smallest group exceeds a limit"
+    );
+}
+
+/// Measured size preceding `size`, or 0 for the first.
+fn size_before(size: u32) -> u32 {
+    LARGE_GROUP_SIZES
+        .iter()
+        .take_while(|measured| **measured < size)
+        .last()
+        .copied()
+        .unwrap_or(0)
+}
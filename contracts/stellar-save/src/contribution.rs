@@ -1,4 +1,5 @@
-use soroban_sdk::{contracttype, Address};
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{contracttype, Address, BytesN, Env};
 
 /// Contribution Record structure for tracking individual member contributions.
 ///
@@ -28,6 +29,25 @@ pub struct ContributionRecord {
     /// Timestamp when the contribution was made (Unix timestamp in seconds).
     /// Used for tracking contribution timing and enforcing deadlines.
     pub timestamp: u64,
+
+    /// Rolling hash of the group's contributions up to and including this one.
+    /// Zero for records made before contributions were chained.
+    pub hash: BytesN<32>,
+}
+
+/// Head of a group's contribution hash chain at the end of a cycle, as
+/// returned by `get_cycle_checkpoint`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CycleCheckpoint {
+    pub group_id: u64,
+    pub cycle: u32,
+    /// Hash of the cycle's last chained contribution
+    pub hash: BytesN<32>,
+    /// Number of contributions chained in the cycle
+    pub contributions: u32,
+    /// Sum of the chained contributions' amounts
+    pub total: i128,
 }
 
 impl ContributionRecord {
@@ -53,15 +73,33 @@ impl ContributionRecord {
         // Validate amount
         assert!(amount > 0, "amount must be greater than 0");
 
+        let hash = BytesN::from_array(member_address.env(), &[0; 32]);
         Self {
             member_address,
             group_id,
             cycle_number,
             amount,
             timestamp,
+            hash,
         }
     }
 
+    /// Links the record to the group's hash chain by setting its hash to
+    /// `sha256(xdr((previous, member_address, group_id, cycle_number, amount,
+    /// timestamp)))`, which anyone holding the contribution events can
+    /// recompute.
+    pub fn chain(&mut self, env: &Env, previous: &BytesN<32>) {
+        let fields = (
+            previous.clone(),
+            self.member_address.clone(),
+            self.group_id,
+            self.cycle_number,
+            self.amount,
+            self.timestamp,
+        );
+        self.hash = env.crypto().sha256(&fields.to_xdr(env)).to_bytes();
+    }
+
     /// Validates that the contribution record is sound.
     /// Returns true if all constraints are met.
     pub fn validate(&self) -> bool {
@@ -89,6 +127,7 @@ impl ContributionRecord {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::units::{SECONDS_PER_WEEK, STROOPS_PER_XLM};
     use soroban_sdk::{testutils::Address as _, Address, Env};
 
     #[test]
@@ -98,16 +137,16 @@ mod tests {
 
         let contribution = ContributionRecord::new(
             member.clone(),
-            1,          // group_id
-            0,          // cycle_number
-            10_000_000, // 1 XLM
+            1, // group_id
+            0, // cycle_number
+            STROOPS_PER_XLM,
             1234567890, // timestamp
         );
 
         assert_eq!(contribution.member_address, member);
         assert_eq!(contribution.group_id, 1);
         assert_eq!(contribution.cycle_number, 0);
-        assert_eq!(contribution.amount, 10_000_000);
+        assert_eq!(contribution.amount, STROOPS_PER_XLM);
         assert_eq!(contribution.timestamp, 1234567890);
     }
 
@@ -125,7 +164,7 @@ mod tests {
         let env = Env::default();
         let member = Address::generate(&env);
 
-        let contribution = ContributionRecord::new(member, 1, 0, 10_000_000, 1234567890);
+        let contribution = ContributionRecord::new(member, 1, 0, STROOPS_PER_XLM, 1234567890);
 
         assert!(contribution.validate());
     }
@@ -135,7 +174,7 @@ mod tests {
         let env = Env::default();
         let member = Address::generate(&env);
 
-        let contribution = ContributionRecord::new(member, 1, 2, 10_000_000, 1234567890);
+        let contribution = ContributionRecord::new(member, 1, 2, STROOPS_PER_XLM, 1234567890);
 
         assert!(contribution.matches_group_and_cycle(1, 2));
         assert!(!contribution.matches_group_and_cycle(1, 3));
@@ -149,7 +188,8 @@ mod tests {
         let member1 = Address::generate(&env);
         let member2 = Address::generate(&env);
 
-        let contribution = ContributionRecord::new(member1.clone(), 1, 0, 10_000_000, 1234567890);
+        let contribution =
+            ContributionRecord::new(member1.clone(), 1, 0, STROOPS_PER_XLM, 1234567890);
 
         assert!(contribution.is_from_member(&member1));
         assert!(!contribution.is_from_member(&member2));
@@ -161,9 +201,11 @@ mod tests {
         let member1 = Address::generate(&env);
         let member2 = Address::generate(&env);
 
-        let contribution1 = ContributionRecord::new(member1.clone(), 1, 0, 10_000_000, 1234567890);
+        let contribution1 =
+            ContributionRecord::new(member1.clone(), 1, 0, STROOPS_PER_XLM, 1234567890);
 
-        let contribution2 = ContributionRecord::new(member2.clone(), 1, 0, 10_000_000, 1234567891);
+        let contribution2 =
+            ContributionRecord::new(member2.clone(), 1, 0, STROOPS_PER_XLM, 1234567891);
 
         assert_eq!(contribution1.group_id, contribution2.group_id);
         assert_eq!(contribution1.cycle_number, contribution2.cycle_number);
@@ -177,14 +219,14 @@ mod tests {
         let member = Address::generate(&env);
 
         let contribution_cycle_0 =
-            ContributionRecord::new(member.clone(), 1, 0, 10_000_000, 1234567890);
+            ContributionRecord::new(member.clone(), 1, 0, STROOPS_PER_XLM, 1234567890);
 
         let contribution_cycle_1 = ContributionRecord::new(
             member.clone(),
             1,
             1,
-            10_000_000,
-            1234567890 + 604800, // 1 week later
+            STROOPS_PER_XLM,
+            1234567890 + SECONDS_PER_WEEK, // 1 week later
         );
 
         assert_eq!(
@@ -197,4 +239,28 @@ mod tests {
             contribution_cycle_1.cycle_number
         );
     }
+
+    #[test]
+    fn test_chain_covers_previous_hash_and_fields() {
+        let env = Env::default();
+        let member = Address::generate(&env);
+        let genesis = BytesN::from_array(&env, &[0; 32]);
+
+        let mut first = ContributionRecord::new(member.clone(), 1, 0, STROOPS_PER_XLM, 1234567890);
+        assert_eq!(first.hash, genesis);
+        first.chain(&env, &genesis);
+        assert_ne!(first.hash, genesis);
+
+        let mut second = ContributionRecord::new(member.clone(), 1, 0, STROOPS_PER_XLM, 1234567890);
+        second.chain(&env, &first.hash);
+        assert_ne!(second.hash, first.hash);
+
+        // Same fields and link give the same hash; any change gives another
+        let mut replay = ContributionRecord::new(member.clone(), 1, 0, STROOPS_PER_XLM, 1234567890);
+        replay.chain(&env, &genesis);
+        assert_eq!(replay.hash, first.hash);
+        let mut altered = ContributionRecord::new(member, 1, 0, STROOPS_PER_XLM + 1, 1234567890);
+        altered.chain(&env, &genesis);
+        assert_ne!(altered.hash, first.hash);
+    }
 }
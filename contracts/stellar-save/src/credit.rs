@@ -0,0 +1,95 @@
+//! Contribution credit.
+//!
+//! A member who pays in more than a cycle's contribution keeps the excess as
+//! credit with the group. Credit pays the member's contributions as their
+//! cycles open: right after the deposit, each time a payout moves the group
+//! to its next cycle, and ahead of the allowance in `pull_contribution`.
+//! Whatever is left is refunded once the group has completed or been
+//! cancelled, or once the member has left it.
+
+use crate::error::StellarSaveError;
+use crate::storage::{set_persistent, StorageKeyBuilder};
+use soroban_sdk::{Address, Env};
+
+/// Returns the credit a group holds for a member.
+pub fn load(env: &Env, group_id: u64, member: Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&StorageKeyBuilder::member_credit(group_id, member))
+        .unwrap_or(0)
+}
+
+/// Adds `amount` to a member's credit.
+///
+/// # Returns
+/// The member's credit after the deposit.
+///
+/// # Errors
+/// * `InvalidAmount` - If the amount is not positive
+/// * `Overflow` - If the balance would overflow
+pub fn deposit(
+    env: &Env,
+    group_id: u64,
+    member: Address,
+    amount: i128,
+) -> Result<i128, StellarSaveError> {
+    if amount <= 0 {
+        return Err(StellarSaveError::InvalidAmount);
+    }
+    let balance = load(env, group_id, member.clone())
+        .checked_add(amount)
+        .ok_or(StellarSaveError::Overflow)?;
+    set_persistent(
+        env,
+        &StorageKeyBuilder::member_credit(group_id, member),
+        &balance,
+    );
+    Ok(balance)
+}
+
+/// Takes up to `amount` from a member's credit.
+///
+/// # Returns
+/// The amount taken, at most `amount`; 0 if the member has no credit.
+pub fn take(env: &Env, group_id: u64, member: Address, amount: i128) -> i128 {
+    let key = StorageKeyBuilder::member_credit(group_id, member);
+    let balance: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+    let taken = amount.min(balance);
+    if taken <= 0 {
+        return 0;
+    }
+    if taken == balance {
+        env.storage().persistent().remove(&key);
+    } else {
+        set_persistent(env, &key, &(balance - taken));
+    }
+    taken
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+
+    #[test]
+    fn test_deposit_and_take() {
+        let env = Env::default();
+        let contract_id = crate::testutils::register(&env);
+        let member = Address::generate(&env);
+
+        env.as_contract(&contract_id, || {
+            assert_eq!(
+                deposit(&env, 1, member.clone(), 0),
+                Err(StellarSaveError::InvalidAmount)
+            );
+            assert_eq!(deposit(&env, 1, member.clone(), 150), Ok(150));
+            assert_eq!(take(&env, 1, member.clone(), 100), 100);
+            assert_eq!(take(&env, 1, member.clone(), 100), 50);
+            assert_eq!(take(&env, 1, member.clone(), 100), 0);
+            assert!(!env
+                .storage()
+                .persistent()
+                .has(&StorageKeyBuilder::member_credit(1, member.clone())));
+        });
+    }
+}
@@ -3,7 +3,7 @@ use crate::{
     error::StellarSaveError,
     events::EventEmitter,
     group::Group,
-    storage::StorageKeyBuilder,
+    storage::GroupStore,
 };
 
 /// Helper function to advance a group to the next cycle after payout.
@@ -47,8 +47,7 @@ pub fn advance_group_to_next_cycle(
     group.advance_cycle();
 
     // Task 3: Update group storage
-    let group_key = StorageKeyBuilder::group_data(group_id);
-    env.storage().persistent().set(&group_key, group);
+    GroupStore::save(env, group);
 
     // Task 4: Emit event
     // Emit GroupStatusChanged event when transitioning to Completed state
@@ -0,0 +1,178 @@
+//! Debts of members who default after being paid out.
+//!
+//! A member who has received their payout and then misses a cycle has taken
+//! more out of the group than they put in. Each such miss adds the missed
+//! contribution to the member's `DebtRecord`. The debt is cleared by repaying
+//! it through `repay_debt`, or offset against funds the group still holds for
+//! the member when it completes: their share of the insurance surplus and of
+//! any yield reserve. Waiving the miss (see `waiver`) cancels its debt.
+
+use crate::error::StellarSaveError;
+use crate::storage::{set_persistent, StorageKeyBuilder};
+use soroban_sdk::{contracttype, Address, Env};
+
+/// What a member owes a group.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DebtRecord {
+    /// Contributions missed after the member's payout, in stroops
+    pub accrued: i128,
+    /// Amount the member has paid back
+    pub repaid: i128,
+    /// Amount offset against funds the group held for the member
+    pub offset: i128,
+    /// Cycles missed after the member's payout
+    pub missed_cycles: u32,
+    /// Cycle of the most recent miss
+    pub last_missed_cycle: u32,
+}
+
+impl DebtRecord {
+    /// Amount still owed.
+    pub fn outstanding(&self) -> i128 {
+        self.accrued - self.repaid - self.offset
+    }
+}
+
+/// Returns a member's debt record, or `None` if they never owed anything.
+pub fn load(env: &Env, group_id: u64, member: Address) -> Option<DebtRecord> {
+    env.storage()
+        .persistent()
+        .get(&StorageKeyBuilder::member_debt(group_id, member))
+}
+
+/// Returns the amount a member still owes the group.
+pub fn load_outstanding(env: &Env, group_id: u64, member: Address) -> i128 {
+    load(env, group_id, member)
+        .map(|debt| debt.outstanding())
+        .unwrap_or(0)
+}
+
+/// Adds a contribution of `amount` missed in `cycle` to a member's debt.
+pub fn accrue(
+    env: &Env,
+    group_id: u64,
+    member: Address,
+    cycle: u32,
+    amount: i128,
+) -> Result<DebtRecord, StellarSaveError> {
+    let mut debt = load(env, group_id, member.clone()).unwrap_or(DebtRecord {
+        accrued: 0,
+        repaid: 0,
+        offset: 0,
+        missed_cycles: 0,
+        last_missed_cycle: cycle,
+    });
+    debt.accrued = debt
+        .accrued
+        .checked_add(amount)
+        .ok_or(StellarSaveError::Overflow)?;
+    debt.missed_cycles += 1;
+    debt.last_missed_cycle = cycle;
+    set_persistent(env, &StorageKeyBuilder::member_debt(group_id, member), &debt);
+    Ok(debt)
+}
+
+/// Records a repayment of `amount` against a member's debt.
+///
+/// # Errors
+/// * `InvalidAmount` - If the amount is not positive or exceeds what is owed
+pub fn repay(
+    env: &Env,
+    group_id: u64,
+    member: Address,
+    amount: i128,
+) -> Result<DebtRecord, StellarSaveError> {
+    let mut debt = load(env, group_id, member.clone()).ok_or(StellarSaveError::InvalidAmount)?;
+    if amount <= 0 || amount > debt.outstanding() {
+        return Err(StellarSaveError::InvalidAmount);
+    }
+    debt.repaid += amount;
+    set_persistent(env, &StorageKeyBuilder::member_debt(group_id, member), &debt);
+    Ok(debt)
+}
+
+/// Offsets a member's debt against `available` funds the group holds for
+/// them.
+///
+/// # Returns
+/// The amount applied, at most `available`; 0 if the member owes nothing.
+pub fn offset(env: &Env, group_id: u64, member: Address, available: i128) -> i128 {
+    let Some(mut debt) = load(env, group_id, member.clone()) else {
+        return 0;
+    };
+    let applied = available.min(debt.outstanding());
+    if applied <= 0 {
+        return 0;
+    }
+    debt.offset += applied;
+    set_persistent(env, &StorageKeyBuilder::member_debt(group_id, member), &debt);
+    applied
+}
+
+/// Cancels up to `amount` of a member's outstanding debt for a waived miss.
+///
+/// # Returns
+/// The amount cancelled; 0 if the member owes nothing.
+pub fn forgive(env: &Env, group_id: u64, member: Address, amount: i128) -> i128 {
+    let Some(mut debt) = load(env, group_id, member.clone()) else {
+        return 0;
+    };
+    let forgiven = amount.min(debt.outstanding());
+    if forgiven <= 0 {
+        return 0;
+    }
+    debt.accrued -= forgiven;
+    debt.missed_cycles = debt.missed_cycles.saturating_sub(1);
+    set_persistent(env, &StorageKeyBuilder::member_debt(group_id, member), &debt);
+    forgiven
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+
+    #[test]
+    fn test_accrue_repay_and_offset() {
+        let env = Env::default();
+        let contract_id = crate::testutils::register(&env);
+        let member = Address::generate(&env);
+
+        env.as_contract(&contract_id, || {
+            assert_eq!(load(&env, 1, member.clone()), None);
+            assert_eq!(
+                repay(&env, 1, member.clone(), 10),
+                Err(StellarSaveError::InvalidAmount)
+            );
+            assert_eq!(offset(&env, 1, member.clone(), 10), 0);
+
+            accrue(&env, 1, member.clone(), 2, 100).unwrap();
+            let debt = accrue(&env, 1, member.clone(), 3, 100).unwrap();
+            assert_eq!((debt.missed_cycles, debt.last_missed_cycle), (2, 3));
+            assert_eq!(debt.outstanding(), 200);
+
+            for amount in [0, 201] {
+                assert_eq!(
+                    repay(&env, 1, member.clone(), amount),
+                    Err(StellarSaveError::InvalidAmount)
+                );
+            }
+            assert_eq!(repay(&env, 1, member.clone(), 150).unwrap().outstanding(), 50);
+
+            // Only what is still owed is taken
+            assert_eq!(offset(&env, 1, member.clone(), 80), 50);
+            let debt = load(&env, 1, member.clone()).unwrap();
+            assert_eq!((debt.repaid, debt.offset), (150, 50));
+            assert_eq!(load_outstanding(&env, 1, member.clone()), 0);
+            assert_eq!(forgive(&env, 1, member.clone(), 100), 0);
+
+            let debt = accrue(&env, 1, member.clone(), 4, 100).unwrap();
+            assert_eq!(debt.outstanding(), 100);
+            assert_eq!(forgive(&env, 1, member.clone(), 100), 100);
+            let debt = load(&env, 1, member).unwrap();
+            assert_eq!((debt.accrued, debt.missed_cycles), (200, 2));
+            assert_eq!(debt.outstanding(), 0);
+        });
+    }
+}
@@ -0,0 +1,60 @@
+//! Chunked default processing.
+//!
+//! A cycle that pays out short records a default against every member who
+//! missed it, which in a large group means more writes and events than fit
+//! in one transaction. Once a cycle's grace window has closed the defaults
+//! are final, so `process_defaults` can record them a slice of the member
+//! list at a time beforehand. The progress kept here lets each call resume
+//! where the last stopped, and lets the payout skip the members already
+//! handled. It is only needed until the cycle pays out, so it is kept in
+//! temporary storage.
+
+use crate::storage::{self, StorageKeyBuilder};
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+/// How far default processing has got through a cycle's member list.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DefaultsProgress {
+    /// Index in the member list of the next member to check
+    pub next_index: u32,
+    /// Members found to have defaulted so far
+    pub defaulters: Vec<Address>,
+}
+
+/// Returns the progress made on a cycle, empty if none was processed.
+pub fn load(env: &Env, group_id: u64, cycle: u32) -> DefaultsProgress {
+    let key = StorageKeyBuilder::group_defaults_progress(group_id, cycle);
+    storage::load(env, &key).unwrap_or(DefaultsProgress {
+        next_index: 0,
+        defaulters: Vec::new(env),
+    })
+}
+
+/// Stores the progress made on a cycle.
+pub fn store(env: &Env, group_id: u64, cycle: u32, progress: &DefaultsProgress) {
+    storage::save(
+        env,
+        &StorageKeyBuilder::group_defaults_progress(group_id, cycle),
+        progress,
+    );
+}
+
+/// Removes a cycle's progress once its defaults are all recorded.
+pub fn clear(env: &Env, group_id: u64, cycle: u32) {
+    env.storage()
+        .temporary()
+        .remove(&StorageKeyBuilder::group_defaults_progress(group_id, cycle));
+}
+
+/// Keeps a cycle's progress pointing at the same member after the member at
+/// `index` leaves the member list.
+pub fn member_removed(env: &Env, group_id: u64, cycle: u32, index: u32) {
+    let key = StorageKeyBuilder::group_defaults_progress(group_id, cycle);
+    if let Some(mut progress) = storage::load::<DefaultsProgress>(env, &key) {
+        if index < progress.next_index {
+            progress.next_index -= 1;
+            storage::save(env, &key, &progress);
+        }
+    }
+}
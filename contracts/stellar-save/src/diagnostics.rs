@@ -0,0 +1,72 @@
+//! Error diagnostics.
+//!
+//! A failed call rolls back everything it did, so a failed simulation tells
+//! an integrator little more than the error code: `InvalidState` could mean
+//! the group is paused or that its cycle has not started. With the
+//! protocol's debug flag on, the contract publishes an `ErrorDiagnostic`
+//! event on its main failure paths just before returning the error, naming
+//! the group, the cycle and the value that was rejected. Simulations report
+//! events published by a failed call, so the context reaches the integrator
+//! even though nothing is kept on the ledger.
+//!
+//! The flag is off by default and lives in instance storage.
+
+use crate::error::StellarSaveError;
+use crate::events::EventEmitter;
+use crate::storage::{set_instance, StorageKeyBuilder};
+use soroban_sdk::Env;
+
+/// Whether the contract publishes diagnostics on failure.
+pub fn is_enabled(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .has(&StorageKeyBuilder::debug_diagnostics())
+}
+
+/// Turns diagnostics on or off.
+pub fn set_enabled(env: &Env, enabled: bool) {
+    let key = StorageKeyBuilder::debug_diagnostics();
+    if enabled {
+        set_instance(env, &key, &true);
+    } else {
+        env.storage().instance().remove(&key);
+    }
+}
+
+/// Returns `error`, first publishing a diagnostic with the offending
+/// `value` if diagnostics are on.
+pub fn report(
+    env: &Env,
+    error: StellarSaveError,
+    group_id: u64,
+    cycle: u32,
+    value: i128,
+) -> StellarSaveError {
+    if is_enabled(env) {
+        EventEmitter::emit_error_diagnostic(env, error.code(), group_id, cycle, value);
+    }
+    error
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flag_defaults_off_and_report_returns_error() {
+        let env = Env::default();
+        let contract_id = crate::testutils::register(&env);
+
+        env.as_contract(&contract_id, || {
+            assert!(!is_enabled(&env));
+            set_enabled(&env, true);
+            assert!(is_enabled(&env));
+            assert_eq!(
+                report(&env, StellarSaveError::InvalidState, 1, 0, 5),
+                StellarSaveError::InvalidState
+            );
+            set_enabled(&env, false);
+            assert!(!is_enabled(&env));
+        });
+    }
+}
@@ -0,0 +1,115 @@
+//! Payout disputes.
+//!
+//! A group may name an arbiter while it is forming. Once it runs, any member
+//! can dispute the current cycle's payout, or a member being treated as in
+//! default for it. The cycle's pool then stays in the contract until the
+//! arbiter rules or the resolution period runs out, whichever comes first.
+//! Each cycle can be disputed once, so a payout cannot be held indefinitely.
+
+use crate::reason::ActionNote;
+use crate::storage::StorageKeyBuilder;
+use crate::units::days;
+use soroban_sdk::{contracttype, Address, Env};
+
+/// How long the arbiter has to rule on a dispute (7 days).
+pub const RESOLUTION_PERIOD: u64 = days(7);
+
+/// What a dispute is about.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DisputeSubject {
+    /// Who the cycle's payout goes to, or how much they receive.
+    Payout,
+    /// Treating this member as having defaulted on the cycle.
+    Default(Address),
+}
+
+/// Lifecycle of a dispute.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DisputeStatus {
+    /// Waiting for the arbiter; the cycle's payout is held.
+    Open,
+    /// The arbiter found for the member who opened it.
+    Upheld,
+    /// The arbiter found against the member who opened it.
+    Dismissed,
+    /// The resolution period ended without a ruling.
+    Expired,
+}
+
+/// A dispute over one cycle of a group.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Dispute {
+    pub group_id: u64,
+    pub cycle: u32,
+    pub opened_by: Address,
+    pub subject: DisputeSubject,
+    /// Why the member opened it
+    pub note: ActionNote,
+    pub opened_at: u64,
+    /// The arbiter can rule up to and including this timestamp
+    pub resolve_by: u64,
+    pub status: DisputeStatus,
+}
+
+impl Dispute {
+    /// Whether the dispute still holds the cycle's payout at `now`.
+    pub fn is_holding(&self, now: u64) -> bool {
+        self.status == DisputeStatus::Open && now <= self.resolve_by
+    }
+}
+
+/// Returns the group's arbiter, if it named one.
+pub fn load_arbiter(env: &Env, group_id: u64) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&StorageKeyBuilder::group_arbiter(group_id))
+}
+
+/// Returns the dispute opened over a cycle, if any.
+pub fn load_dispute(env: &Env, group_id: u64, cycle: u32) -> Option<Dispute> {
+    env.storage()
+        .persistent()
+        .get(&StorageKeyBuilder::group_dispute(group_id, cycle))
+}
+
+/// Whether a dispute is holding the cycle's payout right now.
+pub fn holds_payout(env: &Env, group_id: u64, cycle: u32) -> bool {
+    load_dispute(env, group_id, cycle)
+        .map(|dispute| dispute.is_holding(env.ledger().timestamp()))
+        .unwrap_or(false)
+}
+
+/// End of the resolution period for a dispute opened at `opened_at`.
+pub fn resolve_by(opened_at: u64) -> u64 {
+    opened_at.saturating_add(RESOLUTION_PERIOD)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+
+    #[test]
+    fn test_dispute_holds_until_ruling_or_timeout() {
+        let env = Env::default();
+        let mut dispute = Dispute {
+            group_id: 1,
+            cycle: 0,
+            opened_by: Address::generate(&env),
+            subject: DisputeSubject::Payout,
+            note: ActionNote::unstated(),
+            opened_at: 100,
+            resolve_by: resolve_by(100),
+            status: DisputeStatus::Open,
+        };
+        assert!(dispute.is_holding(100));
+        assert!(dispute.is_holding(100 + RESOLUTION_PERIOD));
+        assert!(!dispute.is_holding(101 + RESOLUTION_PERIOD));
+
+        dispute.status = DisputeStatus::Dismissed;
+        assert!(!dispute.is_holding(100));
+    }
+}
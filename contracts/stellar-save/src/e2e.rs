@@ -0,0 +1,267 @@
+//! End-to-end lifecycle tests.
+//!
+//! Drives groups through the contract's entry points against a Stellar asset
+//! contract registered in the test environment: members join, approve the
+//! contract and have each cycle's contribution pulled from their balance, and
+//! payouts are executed until the group completes. Token balances, events and
+//! stored state are checked after every step.
+//!
+//! There is no entry point that starts a rotation yet, so groups are started
+//! with [`testutils::start_group`]; everything else goes through the client.
+
+use crate::error::StellarSaveError;
+use crate::events::{ContributionPulled, GroupCreated, MemberJoined};
+use crate::group::GroupStatus;
+use crate::pool::PoolCalculator;
+use crate::testutils;
+use crate::units::{self, SECONDS_PER_WEEK};
+use crate::{PayoutOutcome, StellarSaveContract, StellarSaveContractClient};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{vec, Address, Env, Vec};
+
+const CONTRIBUTION: i128 = units::xlm(10);
+const FEE_BPS: u32 = 100;
+
+/// A freshly initialized contract whose contribution token is a Stellar
+/// asset contract the test can mint.
+struct Harness {
+    env: Env,
+    contract_id: Address,
+    client: StellarSaveContractClient<'static>,
+    token: TokenClient<'static>,
+    token_admin: StellarAssetClient<'static>,
+}
+
+impl Harness {
+    fn new(fee_bps: u32) -> Self {
+        let env = Env::default();
+        env.mock_all_auths();
+        testutils::set_time(&env, 1_700_000_000);
+
+        let contract_id = env.register(StellarSaveContract, ());
+        let asset = env.register_stellar_asset_contract_v2(Address::generate(&env));
+        let client = StellarSaveContractClient::new(&env, &contract_id);
+        client.initialize(&Address::generate(&env), &asset.address(), &fee_bps, &None);
+
+        Self {
+            token: TokenClient::new(&env, &asset.address()),
+            token_admin: StellarAssetClient::new(&env, &asset.address()),
+            env,
+            contract_id,
+            client,
+        }
+    }
+
+    /// Creates a group of `size` and joins it with fresh members, each funded
+    /// and approved for every cycle's contribution and opted in to having it
+    /// pulled.
+    fn create_group(&self, size: u32) -> (u64, Vec<Address>) {
+        let creator = Address::generate(&self.env);
+        let group_id = self
+            .client
+            .create_group(&creator, &CONTRIBUTION, &SECONDS_PER_WEEK, &size);
+        let created = testutils::expect_event::<GroupCreated>(&self.env);
+        assert_eq!(created.group_id, group_id);
+        assert_eq!(created.creator, creator);
+        assert_eq!(created.contribution_amount, CONTRIBUTION);
+        assert_eq!(self.client.get_member_count(&group_id), 0);
+
+        let obligation = CONTRIBUTION * size as i128;
+        let expiration = self.env.ledger().sequence() + 100_000;
+        let mut members = Vec::new(&self.env);
+        for joined in 1..=size {
+            let member = Address::generate(&self.env);
+            self.client.join_group(&group_id, &member, &None);
+            let event = testutils::expect_event::<MemberJoined>(&self.env);
+            assert_eq!(event.member, member);
+            assert_eq!(event.member_count, joined);
+            assert_eq!(self.client.get_member_count(&group_id), joined);
+            assert_eq!(
+                self.client.get_payout_position(&group_id, &member),
+                joined - 1
+            );
+
+            self.token_admin.mint(&member, &obligation);
+            self.token
+                .approve(&member, &self.contract_id, &obligation, &expiration);
+            self.client.enable_auto_contribute(&group_id, &member);
+            members.push_back(member);
+        }
+        (group_id, members)
+    }
+
+    /// Pulls the current cycle's contribution from each of `members`.
+    fn contribute(&self, group_id: u64, members: &Vec<Address>) {
+        let cycle = self.client.get_group(&group_id).current_cycle;
+        for member in members.iter() {
+            let before = self.token.balance(&member);
+            let pool_before = self.token.balance(&self.contract_id);
+
+            assert_eq!(
+                self.client.pull_contribution(&group_id, &member),
+                CONTRIBUTION
+            );
+            let made =
+                testutils::assert_contribution_event(&self.env, group_id, &member, CONTRIBUTION);
+            assert_eq!(made.cycle, cycle);
+            let pulled = testutils::expect_event::<ContributionPulled>(&self.env);
+            assert_eq!(pulled.member, member);
+            assert_eq!(pulled.cycle, cycle);
+
+            assert_eq!(self.token.balance(&member), before - CONTRIBUTION);
+            assert_eq!(
+                self.token.balance(&self.contract_id),
+                pool_before + CONTRIBUTION
+            );
+        }
+    }
+
+    /// Executes the group's payout, checks it went to `expected` and moves
+    /// the clock to the start of the next cycle.
+    fn pay_out(&self, group_id: u64, expected: &Address) -> i128 {
+        let group = self.client.get_group(&group_id);
+        let cycle = group.current_cycle;
+        let pool = CONTRIBUTION * group.member_count as i128;
+        let net = PoolCalculator::calculate_payout_amount(pool, self.client.get_fee_bps()).unwrap();
+        let before = self.token.balance(expected);
+        let held = self.token.balance(&self.contract_id);
+
+        let results = self.client.execute_payouts(&vec![&self.env, group_id]);
+        assert_eq!(
+            results.get(0).unwrap().outcome,
+            PayoutOutcome::Paid(expected.clone(), net)
+        );
+        let paid = testutils::assert_payout_event(&self.env, group_id, expected, net);
+        assert_eq!(paid.cycle, cycle);
+
+        assert_eq!(self.token.balance(expected), before + net);
+        assert_eq!(self.token.balance(&self.contract_id), held - net);
+        assert!(self.client.has_received_payout(&group_id, expected));
+        assert_eq!(self.client.get_payout(&group_id, &cycle).amount, net);
+
+        testutils::advance_past_deadline(&self.env, &self.contract_id, group_id, 0);
+        net
+    }
+}
+
+#[test]
+fn test_full_lifecycle_moves_tokens() {
+    let harness = Harness::new(FEE_BPS);
+    let client = &harness.client;
+    let size = 4;
+    let (group_id, members) = harness.create_group(size);
+    testutils::start_group(&harness.env, &harness.contract_id, group_id);
+
+    let mut paid_out = 0;
+    for cycle in 0..size {
+        assert_eq!(client.get_group(&group_id).current_cycle, cycle);
+        harness.contribute(group_id, &members);
+        assert!(client.is_cycle_complete(&group_id, &cycle));
+
+        paid_out += harness.pay_out(group_id, &members.get(cycle).unwrap());
+    }
+
+    // Every member paid in four contributions and was paid one pool, net of fee
+    let pool = CONTRIBUTION * size as i128;
+    let net = pool - PoolCalculator::calculate_fee(pool, FEE_BPS).unwrap();
+    for member in members.iter() {
+        assert_eq!(harness.token.balance(&member), net);
+    }
+
+    // Only the fees stay behind, held for the treasury
+    let fees = pool * size as i128 - paid_out;
+    assert_eq!(harness.token.balance(&harness.contract_id), fees);
+    assert_eq!(client.get_treasury_balance(), fees);
+
+    assert!(client.is_complete(&group_id));
+    assert_eq!(client.get_group(&group_id).status, GroupStatus::Completed);
+    assert_eq!(client.get_total_paid_out(&group_id), paid_out);
+    assert_eq!(client.get_payout_history(&group_id, &0, &10).len(), size);
+
+    // Finalizing finds nothing left unaccounted for
+    let summary = client.finalize_group(&group_id);
+    assert_eq!(summary.total_cycles, size);
+    assert_eq!(summary.total_contributed, pool * size as i128);
+    assert_eq!(summary.total_paid_out, paid_out);
+    assert_eq!(summary.total_fees, fees);
+    assert_eq!(summary.residual, 0);
+    assert_eq!(summary.missed_contributions, 0);
+    assert_eq!(harness.token.balance(&harness.contract_id), fees);
+}
+
+#[test]
+fn test_payout_waits_for_every_contribution() {
+    let harness = Harness::new(0);
+    let client = &harness.client;
+    let (group_id, members) = harness.create_group(3);
+    testutils::start_group(&harness.env, &harness.contract_id, group_id);
+
+    let mut early = members.clone();
+    let straggler = early.pop_back().unwrap();
+    harness.contribute(group_id, &early);
+
+    let results = client.execute_payouts(&vec![&harness.env, group_id]);
+    assert_eq!(
+        results.get(0).unwrap().outcome,
+        PayoutOutcome::Skipped(StellarSaveError::CycleNotComplete.code())
+    );
+    assert_eq!(
+        harness.token.balance(&harness.contract_id),
+        CONTRIBUTION * 2
+    );
+    assert!(!client.has_received_payout(&group_id, &members.get(0).unwrap()));
+
+    harness.contribute(group_id, &vec![&harness.env, straggler]);
+    harness.pay_out(group_id, &members.get(0).unwrap());
+    assert_eq!(client.get_group(&group_id).current_cycle, 1);
+    assert_eq!(harness.token.balance(&harness.contract_id), 0);
+}
+
+#[test]
+fn test_batch_payout_across_groups() {
+    let harness = Harness::new(FEE_BPS);
+    let client = &harness.client;
+    let (first, first_members) = harness.create_group(2);
+    let (second, second_members) = harness.create_group(3);
+    let (idle, _) = harness.create_group(2);
+    for group_id in [first, second, idle] {
+        testutils::start_group(&harness.env, &harness.contract_id, group_id);
+    }
+    harness.contribute(first, &first_members);
+    harness.contribute(second, &second_members);
+
+    let results = client.execute_payouts(&vec![&harness.env, first, idle, second]);
+    let net =
+        |size: i128| PoolCalculator::calculate_payout_amount(CONTRIBUTION * size, FEE_BPS).unwrap();
+    let first_recipient = first_members.get(0).unwrap();
+    let second_recipient = second_members.get(0).unwrap();
+    assert_eq!(
+        results.get(0).unwrap().outcome,
+        PayoutOutcome::Paid(first_recipient.clone(), net(2))
+    );
+    assert_eq!(
+        results.get(1).unwrap().outcome,
+        PayoutOutcome::Skipped(StellarSaveError::CycleNotComplete.code())
+    );
+    assert_eq!(
+        results.get(2).unwrap().outcome,
+        PayoutOutcome::Paid(second_recipient.clone(), net(3))
+    );
+
+    assert_eq!(
+        harness.token.balance(&first_recipient),
+        CONTRIBUTION + net(2)
+    );
+    assert_eq!(
+        harness.token.balance(&second_recipient),
+        CONTRIBUTION * 2 + net(3)
+    );
+    assert_eq!(
+        harness.token.balance(&harness.contract_id),
+        CONTRIBUTION * 5 - net(2) - net(3)
+    );
+    assert_eq!(client.get_group(&first).current_cycle, 1);
+    assert_eq!(client.get_group(&second).current_cycle, 1);
+    assert_eq!(client.get_group(&idle).current_cycle, 0);
+}
@@ -22,6 +22,30 @@ pub enum StellarSaveError {
     /// Error Code: 1003
     InvalidState = 1003,
 
+    /// The group has been halted and all of its flows are frozen.
+    /// Error Code: 1004
+    GroupHalted = 1004,
+
+    /// The proposal does not exist in this group.
+    /// Error Code: 1005
+    ProposalNotFound = 1005,
+
+    /// The creator already runs as many groups as the protocol allows.
+    /// Error Code: 1006
+    TooManyGroups = 1006,
+
+    /// The group is not Active, so no payout can be made.
+    /// Error Code: 1007
+    GroupNotActive = 1007,
+
+    /// No dispute has been opened for this cycle of the group.
+    /// Error Code: 1008
+    DisputeNotFound = 1008,
+
+    /// No group template has this ID.
+    /// Error Code: 1009
+    TemplateNotFound = 1009,
+
     // Member-related errors (2000-2999)
     /// The address is already a member of this group.
     /// Error Code: 2001
@@ -35,6 +59,37 @@ pub enum StellarSaveError {
     /// Error Code: 2003
     Unauthorized = 2003,
 
+    /// The member's household would exceed its seat cap or hold adjacent
+    /// payout positions.
+    /// Error Code: 2004
+    HouseholdLimitExceeded = 2004,
+
+    /// The member has already voted on this proposal.
+    /// Error Code: 2005
+    AlreadyVoted = 2005,
+
+    /// The group has started, so members can no longer leave it.
+    /// Error Code: 2006
+    CannotLeaveActiveGroup = 2006,
+
+    /// The member has not accepted the group's updated terms, or the payout
+    /// position assigned to them.
+    /// Error Code: 2007
+    TermsNotAccepted = 2007,
+
+    /// The referrer is not a member of the group, or is the joining member.
+    /// Error Code: 2008
+    InvalidReferrer = 2008,
+
+    /// The address is on the protocol blacklist and cannot create or join
+    /// groups.
+    /// Error Code: 2009
+    AddressBlacklisted = 2009,
+
+    /// The group's attestation contract does not vouch for the address.
+    /// Error Code: 2010
+    NotAttested = 2010,
+
     // Contribution-related errors (3000-3999)
     /// The contribution amount is invalid (zero, negative, or incorrect).
     /// Error Code: 3001
@@ -52,6 +107,20 @@ pub enum StellarSaveError {
     /// Error Code: 3004
     ContributionNotFound = 3004,
 
+    /// The cycle's grace period has ended and it no longer accepts contributions.
+    /// Error Code: 3005
+    ContributionWindowClosed = 3005,
+
+    /// The member's token allowance or balance no longer covers a
+    /// contribution.
+    /// Error Code: 3006
+    AllowanceExhausted = 3006,
+
+    /// The swap router could not buy the contribution within the member's
+    /// spending limit.
+    /// Error Code: 3007
+    SwapFailed = 3007,
+
     // Payout-related errors (4000-4999)
     /// The payout operation failed due to insufficient funds or transfer error.
     /// Error Code: 4001
@@ -65,6 +134,67 @@ pub enum StellarSaveError {
     /// Error Code: 4003
     InvalidRecipient = 4003,
 
+    /// No member holds the payout position for the current cycle.
+    /// Error Code: 4004
+    NoRecipientForCycle = 4004,
+
+    /// More than one member holds the payout position for the current cycle.
+    /// Error Code: 4005
+    DuplicatePayoutPosition = 4005,
+
+    /// The group's member list does not match its recorded member count.
+    /// Error Code: 4006
+    MemberCountMismatch = 4006,
+
+    /// The cycle's payout is held while a dispute about it is open.
+    /// Error Code: 4007
+    PayoutDisputed = 4007,
+
+    /// The payout is above the group's approval threshold and lacks enough
+    /// unexpired approvals.
+    /// Error Code: 4008
+    PayoutNotApproved = 4008,
+
+    /// The members' payout positions repeat, skip or exceed a position, so
+    /// they do not give each member exactly one turn.
+    /// Error Code: 4009
+    InvalidPayoutOrder = 4009,
+
+    // Validation-related errors (5000-5999)
+    /// A user-supplied string exceeds its maximum byte length.
+    /// Error Code: 5001
+    StringTooLong = 5001,
+
+    /// A user-supplied string is not valid UTF-8.
+    /// Error Code: 5002
+    InvalidUtf8 = 5002,
+
+    /// A required user-supplied string is empty.
+    /// Error Code: 5003
+    EmptyString = 5003,
+
+    /// A single transfer exceeds the protocol's per-transaction cap.
+    /// Error Code: 5004
+    TransferLimitExceeded = 5004,
+
+    /// The yield strategy is not on the protocol's whitelist.
+    /// Error Code: 5005
+    StrategyNotAllowed = 5005,
+
+    /// No usable oracle price: the oracle failed, has no price, or its price
+    /// is too old.
+    /// Error Code: 5006
+    PriceUnavailable = 5006,
+
+    /// The oracle price moved further from the previous cycle's than the
+    /// group allows.
+    /// Error Code: 5007
+    PriceSlippageExceeded = 5007,
+
+    /// The caller already used this idempotency nonce in the group.
+    /// Error Code: 5008
+    DuplicateOperation = 5008,
+
     // System-related errors (9000-9999)
     /// An internal contract error occurred.
     /// Error Code: 9001
@@ -77,6 +207,14 @@ pub enum StellarSaveError {
     /// Added for ID Generation: The counter has reached its maximum limit.
     /// Error Code: 9003
     Overflow = 9003,
+
+    /// The contract has not been initialized with a protocol admin.
+    /// Error Code: 9004
+    NotInitialized = 9004,
+
+    /// The contract has already been initialized.
+    /// Error Code: 9005
+    AlreadyInitialized = 9005,
 }
 
 impl StellarSaveError {
@@ -96,6 +234,24 @@ impl StellarSaveError {
             StellarSaveError::InvalidState => {
                 "The group is not in a valid state for this operation. Check group status."
             }
+            StellarSaveError::GroupHalted => {
+                "The group has been halted by its guardian. No further actions are possible."
+            }
+            StellarSaveError::ProposalNotFound => {
+                "The specified proposal does not exist in this group. Verify the proposal ID."
+            }
+            StellarSaveError::TooManyGroups => {
+                "The creator has reached the limit of open groups. Finish or cancel one first."
+            }
+            StellarSaveError::GroupNotActive => {
+                "The group is not active. Payouts are only made while a group is running."
+            }
+            StellarSaveError::DisputeNotFound => {
+                "No dispute has been opened for this cycle. Verify the group ID and cycle."
+            }
+            StellarSaveError::TemplateNotFound => {
+                "The specified group template does not exist. Verify the template ID."
+            }
 
             // Member-related errors
             StellarSaveError::AlreadyMember => {
@@ -107,6 +263,27 @@ impl StellarSaveError {
             StellarSaveError::Unauthorized => {
                 "You are not authorized to perform this operation. Check permissions."
             }
+            StellarSaveError::HouseholdLimitExceeded => {
+                "This household has reached its seat cap or would hold adjacent payout positions."
+            }
+            StellarSaveError::AlreadyVoted => {
+                "You have already voted on this proposal. Each member has one vote."
+            }
+            StellarSaveError::CannotLeaveActiveGroup => {
+                "Members can only leave a group before it starts."
+            }
+            StellarSaveError::TermsNotAccepted => {
+                "The group's terms have changed. Accept the new terms before continuing."
+            }
+            StellarSaveError::InvalidReferrer => {
+                "The referrer must already be a member of the group and cannot be yourself."
+            }
+            StellarSaveError::AddressBlacklisted => {
+                "This address is blacklisted by the protocol and cannot create or join groups."
+            }
+            StellarSaveError::NotAttested => {
+                "This group only admits addresses its attestation contract vouches for."
+            }
 
             // Contribution-related errors
             StellarSaveError::InvalidAmount => {
@@ -121,6 +298,15 @@ impl StellarSaveError {
             StellarSaveError::ContributionNotFound => {
                 "The contribution record was not found for the specified member and cycle."
             }
+            StellarSaveError::ContributionWindowClosed => {
+                "The grace period for this cycle has ended. Contributions are no longer accepted."
+            }
+            StellarSaveError::AllowanceExhausted => {
+                "Your token allowance or balance no longer covers a contribution. Approve more funds."
+            }
+            StellarSaveError::SwapFailed => {
+                "The swap could not buy your contribution within your spending limit. Raise the limit or try again."
+            }
 
             // Payout-related errors
             StellarSaveError::PayoutFailed => {
@@ -132,6 +318,50 @@ impl StellarSaveError {
             StellarSaveError::InvalidRecipient => {
                 "The specified recipient is not eligible for payout in this cycle."
             }
+            StellarSaveError::NoRecipientForCycle => {
+                "No member is due the payout for this cycle."
+            }
+            StellarSaveError::DuplicatePayoutPosition => {
+                "More than one member holds this cycle's payout position."
+            }
+            StellarSaveError::PayoutDisputed => {
+                "This cycle's payout is on hold until its dispute is resolved or times out."
+            }
+            StellarSaveError::PayoutNotApproved => {
+                "This payout needs more approvals from the group's approvers before it can be made."
+            }
+            StellarSaveError::InvalidPayoutOrder => {
+                "The payout positions must give every member exactly one distinct turn."
+            }
+            StellarSaveError::MemberCountMismatch => {
+                "The group's member list does not match its member count."
+            }
+
+            // Validation-related errors
+            StellarSaveError::StringTooLong => {
+                "The text is too long. Limits are measured in UTF-8 bytes, not characters."
+            }
+            StellarSaveError::InvalidUtf8 => {
+                "The text is not valid UTF-8 and cannot be stored."
+            }
+            StellarSaveError::EmptyString => {
+                "The text must not be empty."
+            }
+            StellarSaveError::TransferLimitExceeded => {
+                "The amount exceeds the maximum allowed for a single transfer."
+            }
+            StellarSaveError::StrategyNotAllowed => {
+                "The yield strategy has not been approved by the protocol admin."
+            }
+            StellarSaveError::PriceUnavailable => {
+                "No fresh price is available from the group's oracle."
+            }
+            StellarSaveError::PriceSlippageExceeded => {
+                "The oracle price moved more than the group allows since the last cycle."
+            }
+            StellarSaveError::DuplicateOperation => {
+                "This operation was already processed with the same nonce."
+            }
 
             // System-related errors
             StellarSaveError::InternalError => {
@@ -143,6 +373,12 @@ impl StellarSaveError {
             StellarSaveError::Overflow => {
                 "The ID counter has reached its maximum limit. No more IDs can be generated."
             }
+            StellarSaveError::NotInitialized => {
+                "The contract has not been initialized. Call initialize with a protocol admin first."
+            }
+            StellarSaveError::AlreadyInitialized => {
+                "The contract has already been initialized and cannot be initialized again."
+            }
         }
     }
 
@@ -161,6 +397,7 @@ impl StellarSaveError {
             2000..=2999 => ErrorCategory::Member,
             3000..=3999 => ErrorCategory::Contribution,
             4000..=4999 => ErrorCategory::Payout,
+            5000..=5999 => ErrorCategory::Validation,
             9000..=9999 => ErrorCategory::System,
             _ => ErrorCategory::Unknown,
         }
@@ -183,6 +420,9 @@ pub enum ErrorCategory {
     /// Errors related to payout operations.
     Payout,
 
+    /// Errors related to validation of user-supplied input.
+    Validation,
+
     /// System-level errors and internal failures.
     System,
 
@@ -206,21 +446,54 @@ mod tests {
         assert_eq!(StellarSaveError::GroupNotFound.code(), 1001);
         assert_eq!(StellarSaveError::GroupFull.code(), 1002);
         assert_eq!(StellarSaveError::InvalidState.code(), 1003);
+        assert_eq!(StellarSaveError::GroupHalted.code(), 1004);
+        assert_eq!(StellarSaveError::ProposalNotFound.code(), 1005);
+        assert_eq!(StellarSaveError::TooManyGroups.code(), 1006);
+        assert_eq!(StellarSaveError::GroupNotActive.code(), 1007);
+        assert_eq!(StellarSaveError::DisputeNotFound.code(), 1008);
+        assert_eq!(StellarSaveError::TemplateNotFound.code(), 1009);
 
         assert_eq!(StellarSaveError::AlreadyMember.code(), 2001);
         assert_eq!(StellarSaveError::NotMember.code(), 2002);
         assert_eq!(StellarSaveError::Unauthorized.code(), 2003);
+        assert_eq!(StellarSaveError::HouseholdLimitExceeded.code(), 2004);
+        assert_eq!(StellarSaveError::AlreadyVoted.code(), 2005);
+        assert_eq!(StellarSaveError::CannotLeaveActiveGroup.code(), 2006);
+        assert_eq!(StellarSaveError::TermsNotAccepted.code(), 2007);
+        assert_eq!(StellarSaveError::InvalidReferrer.code(), 2008);
+        assert_eq!(StellarSaveError::AddressBlacklisted.code(), 2009);
+        assert_eq!(StellarSaveError::NotAttested.code(), 2010);
 
         assert_eq!(StellarSaveError::InvalidAmount.code(), 3001);
         assert_eq!(StellarSaveError::AlreadyContributed.code(), 3002);
         assert_eq!(StellarSaveError::CycleNotComplete.code(), 3003);
+        assert_eq!(StellarSaveError::ContributionWindowClosed.code(), 3005);
+        assert_eq!(StellarSaveError::AllowanceExhausted.code(), 3006);
+        assert_eq!(StellarSaveError::SwapFailed.code(), 3007);
 
         assert_eq!(StellarSaveError::PayoutFailed.code(), 4001);
         assert_eq!(StellarSaveError::PayoutAlreadyProcessed.code(), 4002);
         assert_eq!(StellarSaveError::InvalidRecipient.code(), 4003);
+        assert_eq!(StellarSaveError::NoRecipientForCycle.code(), 4004);
+        assert_eq!(StellarSaveError::DuplicatePayoutPosition.code(), 4005);
+        assert_eq!(StellarSaveError::MemberCountMismatch.code(), 4006);
+        assert_eq!(StellarSaveError::PayoutDisputed.code(), 4007);
+        assert_eq!(StellarSaveError::PayoutNotApproved.code(), 4008);
+        assert_eq!(StellarSaveError::InvalidPayoutOrder.code(), 4009);
+
+        assert_eq!(StellarSaveError::StringTooLong.code(), 5001);
+        assert_eq!(StellarSaveError::InvalidUtf8.code(), 5002);
+        assert_eq!(StellarSaveError::EmptyString.code(), 5003);
+        assert_eq!(StellarSaveError::TransferLimitExceeded.code(), 5004);
+        assert_eq!(StellarSaveError::StrategyNotAllowed.code(), 5005);
+        assert_eq!(StellarSaveError::PriceUnavailable.code(), 5006);
+        assert_eq!(StellarSaveError::PriceSlippageExceeded.code(), 5007);
+        assert_eq!(StellarSaveError::DuplicateOperation.code(), 5008);
 
         assert_eq!(StellarSaveError::InternalError.code(), 9001);
         assert_eq!(StellarSaveError::DataCorruption.code(), 9002);
+        assert_eq!(StellarSaveError::NotInitialized.code(), 9004);
+        assert_eq!(StellarSaveError::AlreadyInitialized.code(), 9005);
     }
 
     #[test]
@@ -259,6 +532,11 @@ mod tests {
             ErrorCategory::Payout
         );
 
+        assert_eq!(
+            StellarSaveError::StringTooLong.category(),
+            ErrorCategory::Validation
+        );
+
         assert_eq!(
             StellarSaveError::InternalError.category(),
             ErrorCategory::System
@@ -276,17 +554,49 @@ mod tests {
             StellarSaveError::GroupNotFound,
             StellarSaveError::GroupFull,
             StellarSaveError::InvalidState,
+            StellarSaveError::GroupHalted,
+            StellarSaveError::ProposalNotFound,
+            StellarSaveError::TooManyGroups,
+            StellarSaveError::GroupNotActive,
+            StellarSaveError::DisputeNotFound,
+            StellarSaveError::TemplateNotFound,
             StellarSaveError::AlreadyMember,
             StellarSaveError::NotMember,
             StellarSaveError::Unauthorized,
+            StellarSaveError::HouseholdLimitExceeded,
+            StellarSaveError::AlreadyVoted,
+            StellarSaveError::CannotLeaveActiveGroup,
+            StellarSaveError::TermsNotAccepted,
+            StellarSaveError::InvalidReferrer,
+            StellarSaveError::AddressBlacklisted,
+            StellarSaveError::NotAttested,
             StellarSaveError::InvalidAmount,
             StellarSaveError::AlreadyContributed,
             StellarSaveError::CycleNotComplete,
+            StellarSaveError::ContributionWindowClosed,
+            StellarSaveError::AllowanceExhausted,
+            StellarSaveError::SwapFailed,
             StellarSaveError::PayoutFailed,
             StellarSaveError::PayoutAlreadyProcessed,
             StellarSaveError::InvalidRecipient,
+            StellarSaveError::NoRecipientForCycle,
+            StellarSaveError::DuplicatePayoutPosition,
+            StellarSaveError::MemberCountMismatch,
+            StellarSaveError::PayoutDisputed,
+            StellarSaveError::PayoutNotApproved,
+            StellarSaveError::InvalidPayoutOrder,
+            StellarSaveError::StringTooLong,
+            StellarSaveError::InvalidUtf8,
+            StellarSaveError::EmptyString,
+            StellarSaveError::TransferLimitExceeded,
+            StellarSaveError::StrategyNotAllowed,
+            StellarSaveError::PriceUnavailable,
+            StellarSaveError::PriceSlippageExceeded,
+            StellarSaveError::DuplicateOperation,
             StellarSaveError::InternalError,
             StellarSaveError::DataCorruption,
+            StellarSaveError::NotInitialized,
+            StellarSaveError::AlreadyInitialized,
         ];
 
         for error in &errors {
@@ -0,0 +1,84 @@
+//! Join escrow.
+//!
+//! Early recipients are exposed to members who stop paying once they have
+//! been paid out, and that risk peaks in the last cycle. A group can require
+//! members to pay their first and last contributions on joining: the first
+//! is recorded against cycle 0 straight away, and the last is held here
+//! until the final cycle opens, when it moves into the member's credit and
+//! pays that cycle. Escrow still held when a member leaves or the group
+//! ends is refunded along with their credit.
+
+use crate::storage::{set_persistent, StorageKeyBuilder};
+use soroban_sdk::{Address, Env};
+
+/// Whether a group requires members to escrow their last contribution.
+pub fn is_required(env: &Env, group_id: u64) -> bool {
+    env.storage()
+        .persistent()
+        .has(&StorageKeyBuilder::group_join_escrow(group_id))
+}
+
+/// Turns the join escrow requirement on or off for a group.
+pub fn set_required(env: &Env, group_id: u64, required: bool) {
+    let key = StorageKeyBuilder::group_join_escrow(group_id);
+    if required {
+        set_persistent(env, &key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Returns the escrow a group holds for a member.
+pub fn load(env: &Env, group_id: u64, member: Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&StorageKeyBuilder::member_escrow(group_id, member))
+        .unwrap_or(0)
+}
+
+/// Holds `amount` in escrow for a member.
+pub fn deposit(env: &Env, group_id: u64, member: Address, amount: i128) {
+    set_persistent(
+        env,
+        &StorageKeyBuilder::member_escrow(group_id, member),
+        &amount,
+    );
+}
+
+/// Takes a member's whole escrow.
+///
+/// # Returns
+/// The amount taken; 0 if the member has none.
+pub fn take(env: &Env, group_id: u64, member: Address) -> i128 {
+    let key = StorageKeyBuilder::member_escrow(group_id, member);
+    let amount: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+    env.storage().persistent().remove(&key);
+    amount
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+
+    #[test]
+    fn test_deposit_and_take() {
+        let env = Env::default();
+        let contract_id = crate::testutils::register(&env);
+        let member = Address::generate(&env);
+
+        env.as_contract(&contract_id, || {
+            assert!(!is_required(&env, 1));
+            set_required(&env, 1, true);
+            assert!(is_required(&env, 1));
+            set_required(&env, 1, false);
+            assert!(!is_required(&env, 1));
+
+            deposit(&env, 1, member.clone(), 100);
+            assert_eq!(load(&env, 1, member.clone()), 100);
+            assert_eq!(load(&env, 2, member.clone()), 0);
+            assert_eq!(take(&env, 1, member.clone()), 100);
+            assert_eq!(take(&env, 1, member.clone()), 0);
+        });
+    }
+}
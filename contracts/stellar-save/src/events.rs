@@ -1,4 +1,153 @@
-use soroban_sdk::{contracttype, Address, Env};
+//! Contract events.
+//!
+//! Every event is published with two topics: the event name, then the version
+//! of its payload layout. Indexers match on the name and decode the payload by
+//! version, so a payload can change shape under a new version without breaking
+//! consumers of the old one. When a payload changes incompatibly, bump its
+//! version in the catalogue below; never reuse a version number.
+//!
+//! Events about a group carry a third topic: the group's event sequence
+//! number, starting at 1 and rising by one with every event the group
+//! publishes. A gap tells an indexer it missed an event, and the number
+//! orders a group's events when state is rebuilt from them. Protocol-wide
+//! events, marked `protocol` in the catalogue, have no sequence.
+
+use crate::dispute::{DisputeStatus, DisputeSubject};
+use crate::group::GroupMetadata;
+use crate::pending::PendingDeposit;
+use crate::reason::ActionNote;
+use crate::roles::GroupRole;
+use crate::savings::GoalKind;
+use crate::storage::{set_persistent, StorageKeyBuilder};
+use crate::swap::ContributionSwap;
+use crate::waiver::PenaltyWaiver;
+use crate::SafetyLimits;
+use soroban_sdk::{contracttype, Address, BytesN, Env, IntoVal, String, Symbol, Val, Vec};
+
+/// An event payload with its published name and layout version.
+pub trait ContractEvent: IntoVal<Env, Val> {
+    /// First topic of the event
+    const NAME: &'static str;
+    /// Second topic of the event: version of the payload layout
+    const VERSION: u32;
+
+    /// Group the event is about, or `None` for a protocol-wide event.
+    fn group_id(&self) -> Option<u64>;
+}
+
+macro_rules! event_catalogue {
+    ($($event:ident => ($name:literal, $version:literal $(, $scope:ident)?),)*) => {
+        $(
+            impl ContractEvent for $event {
+                const NAME: &'static str = $name;
+                const VERSION: u32 = $version;
+                event_catalogue!(@group_id $($scope)?);
+            }
+        )*
+    };
+    (@group_id) => {
+        fn group_id(&self) -> Option<u64> {
+            Some(self.group_id)
+        }
+    };
+    (@group_id protocol) => {
+        fn group_id(&self) -> Option<u64> {
+            None
+        }
+    };
+}
+
+event_catalogue! {
+    GroupCreated => ("group_created", 2),
+    GroupMetadataUpdated => ("group_metadata_updated", 1),
+    MemberJoined => ("member_joined", 1),
+    MemberLeft => ("member_left", 1),
+    ContributionMade => ("contribution_made", 1),
+    ContributionLate => ("contribution_late", 1),
+    ContributionReminder => ("contribution_reminder", 1),
+    PayoutExecuted => ("payout_executed", 1),
+    GroupCompleted => ("group_completed", 1),
+    CycleAdvanced => ("cycle_advanced", 1),
+    GroupSettled => ("group_settled", 1),
+    GroupFinalized => ("group_finalized", 1),
+    GroupStatusChanged => ("group_status_changed", 1),
+    GroupTermsUpdated => ("group_terms_updated", 1),
+    MemberTermsAccepted => ("member_terms_accepted", 1),
+    PositionAccepted => ("position_accepted", 1),
+    PositionsSwapped => ("positions_swapped", 1),
+    GroupActivated => ("group_activated", 1),
+    GroupPaused => ("group_paused", 2),
+    GroupResumed => ("group_resumed", 1),
+    GroupCancelled => ("group_cancelled", 2),
+    GroupExpired => ("group_expired", 1),
+    GroupCompletedEarly => ("group_completed_early", 1),
+    AdminTransferStarted => ("admin_transfer_started", 1),
+    AdminTransferred => ("admin_transferred", 1),
+    MemberRemoved => ("member_removed", 2),
+    EmergencyWithdrawal => ("emergency_withdrawal", 1),
+    MemberDefaulted => ("member_defaulted", 1),
+    MemberSubstituted => ("member_substituted", 1),
+    ProtocolFeeUpdated => ("protocol_fee_updated", 1, protocol),
+    ProtocolFeeCollected => ("protocol_fee_collected", 1),
+    InsuranceSettled => ("insurance_settled", 1),
+    LatePayoutCompensated => ("late_payout_compensated", 1),
+    CycleShortfall => ("cycle_shortfall", 1),
+    DebtAccrued => ("debt_accrued", 1),
+    DebtRepaid => ("debt_repaid", 1),
+    DebtOffset => ("debt_offset", 1),
+    CreditDeposited => ("credit_deposited", 1),
+    CreditApplied => ("credit_applied", 1),
+    CreditRefunded => ("credit_refunded", 1),
+    PenaltyWaived => ("penalty_waived", 2),
+    InsuranceSurplusDistributed => ("insurance_surplus_distributed", 1),
+    YieldDeposited => ("yield_deposited", 1),
+    YieldWithdrawn => ("yield_withdrawn", 1),
+    YieldDistributed => ("yield_distributed", 1),
+    PayoutHookFailed => ("payout_hook_failed", 1),
+    CyclePriced => ("cycle_priced", 1),
+    GroupArchived => ("group_archived", 1),
+    TemplateSaved => ("template_saved", 1),
+    GroupRestarted => ("group_restarted", 1),
+    RoleGranted => ("role_granted", 1),
+    RoleRevoked => ("role_revoked", 1),
+    ObserverInvited => ("observer_invited", 1),
+    ObserverRevoked => ("observer_revoked", 1),
+    AutoContributeChanged => ("auto_contribute_changed", 1),
+    ContributionPulled => ("contribution_pulled", 1),
+    ProposalCreated => ("proposal_created", 1),
+    ProposalVoteCast => ("proposal_vote_cast", 1),
+    ProposalClosed => ("proposal_closed", 1),
+    DisputeOpened => ("dispute_opened", 2),
+    DisputeClosed => ("dispute_closed", 1),
+    TreasuryWithdrawn => ("treasury_withdrawn", 1, protocol),
+    ReferralRewardUpdated => ("referral_reward_updated", 1, protocol),
+    ReferralBonusFunded => ("referral_bonus_funded", 1),
+    SavingsGoalSet => ("savings_goal_set", 1),
+    SavingsYieldAdded => ("savings_yield_added", 1),
+    SavingsWithdrawn => ("savings_withdrawn", 1),
+    ReferralRewarded => ("referral_rewarded", 1),
+    ReferralRewardsClaimed => ("referral_rewards_claimed", 1, protocol),
+    RiskLimitsUpdated => ("risk_limits_updated", 1, protocol),
+    SafetyLimitsUpdated => ("safety_limits_updated", 1, protocol),
+    HaltVoteCast => ("halt_vote_cast", 1),
+    GroupHalted => ("group_halted", 1),
+    ContractUpgraded => ("contract_upgraded", 1, protocol),
+    SchemaMigrated => ("schema_migrated", 1, protocol),
+    PayoutAddressSet => ("payout_address_set", 1),
+    EscrowDeposited => ("escrow_deposited", 1),
+    EscrowReleased => ("escrow_released", 1),
+    AddressBlacklisted => ("address_blacklisted", 1, protocol),
+    AddressUnblacklisted => ("address_unblacklisted", 1, protocol),
+    RecoveryInitiated => ("recovery_initiated", 1),
+    RecoveryCancelled => ("recovery_cancelled", 1),
+    RecoveryExecuted => ("recovery_executed", 1),
+    ContributionSwapped => ("contribution_swapped", 1),
+    PayoutApproved => ("payout_approved", 1),
+    DepositParked => ("deposit_parked", 1),
+    DepositApplied => ("deposit_applied", 1),
+    DepositReclaimed => ("deposit_reclaimed", 1),
+    ErrorDiagnostic => ("error_diagnostic", 1, protocol),
+}
 
 /// Event emitted when a new savings group is created.
 #[contracttype]
@@ -9,208 +158,2432 @@ pub struct GroupCreated {
     pub contribution_amount: i128,
     pub cycle_duration: u64,
     pub max_members: u32,
+    /// Display name; empty for groups created without metadata
+    pub name: String,
+    pub description: String,
+    pub category: Symbol,
     pub created_at: u64,
 }
 
-/// Event emitted when a new member joins a group.
-#[contracttype]
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub struct MemberJoined {
-    pub group_id: u64,
-    pub member: Address,
-    pub member_count: u32,
-    pub joined_at: u64,
-}
+/// Event emitted when a new member joins a group.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MemberJoined {
+    pub group_id: u64,
+    pub member: Address,
+    pub member_count: u32,
+    pub joined_at: u64,
+}
+
+/// Event emitted when a member leaves a group before activation.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MemberLeft {
+    pub group_id: u64,
+    pub member: Address,
+    pub member_count: u32,
+    pub left_at: u64,
+}
+
+/// Event emitted when a member makes a contribution.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContributionMade {
+    pub group_id: u64,
+    pub contributor: Address,
+    pub amount: i128,
+    pub cycle: u32,
+    pub cycle_total: i128,
+    pub contributed_at: u64,
+}
+
+/// Event emitted alongside `ContributionMade` when a contribution arrives
+/// after its cycle's deadline.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContributionLate {
+    pub group_id: u64,
+    pub contributor: Address,
+    pub cycle: u32,
+    /// Seconds past the cycle's contribution deadline
+    pub late_by: u64,
+    /// Whether the contribution fell inside the group's grace period
+    pub in_grace: bool,
+    pub contributed_at: u64,
+}
+
+/// Event emitted when a cycle's deadline is within one of the group's
+/// reminder thresholds and members have yet to contribute.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContributionReminder {
+    pub group_id: u64,
+    pub cycle: u32,
+    pub deadline: u64,
+    /// Threshold that was reached, in seconds before the deadline
+    pub threshold_secs: u64,
+    /// Members who have not contributed to the cycle
+    pub pending: Vec<Address>,
+    pub reminded_at: u64,
+}
+
+/// Event emitted when a payout is executed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutExecuted {
+    pub group_id: u64,
+    pub recipient: Address,
+    pub amount: i128,
+    pub cycle: u32,
+    pub executed_at: u64,
+}
+
+/// Event emitted when a group completes all cycles.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupCompleted {
+    pub group_id: u64,
+    pub creator: Address,
+    pub total_cycles: u32,
+    pub total_distributed: i128,
+    pub completed_at: u64,
+}
+
+/// Event emitted when a group moves on to its next cycle after a payout.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CycleAdvanced {
+    pub group_id: u64,
+    pub completed_cycle: u32,
+    pub next_cycle: u32,
+    pub advanced_at: u64,
+}
+
+/// Final settlement report emitted once when a group completes, so indexers
+/// can build completion analytics without replaying the group's history.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupSettled {
+    pub group_id: u64,
+    pub total_cycles: u32,
+    /// Sum of all contributions received
+    pub total_contributed: i128,
+    /// Sum of all payouts, net of protocol fees
+    pub total_paid_out: i128,
+    /// Protocol fees withheld from the group's payouts
+    pub total_fees: i128,
+    /// Yield earned on pooled funds
+    pub total_yield: i128,
+    /// Reserve funds returned to members at completion
+    pub reserve_returned: i128,
+    /// Contributions members failed to make across all cycles
+    pub missed_contributions: u32,
+    pub settled_at: u64,
+}
+
+/// Event emitted when a completed group is reconciled and closed out with
+/// `finalize_group`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupFinalized {
+    pub group_id: u64,
+    /// Unaccounted funds found by the reconciliation and split among members
+    pub residual: i128,
+    /// Everything returned to members at completion, residual included
+    pub total_returned: i128,
+    pub finalized_at: u64,
+}
+
+/// Event emitted when a group's status changes.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupStatusChanged {
+    pub group_id: u64,
+    pub old_status: u32,
+    pub new_status: u32,
+    pub changed_by: Address,
+    pub changed_at: u64,
+}
+
+/// Event emitted when a group's admin changes its listing metadata.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupMetadataUpdated {
+    pub group_id: u64,
+    pub name: String,
+    pub description: String,
+    pub category: Symbol,
+    pub updated_by: Address,
+    pub updated_at: u64,
+}
+
+/// Event emitted when a Forming group's terms change. Members who joined
+/// under the old terms must accept the new ones.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupTermsUpdated {
+    pub group_id: u64,
+    /// Version of the new terms, counting from 1 for the first change
+    pub terms_version: u32,
+    pub old_contribution_amount: i128,
+    pub new_contribution_amount: i128,
+    pub old_cycle_duration: u64,
+    pub new_cycle_duration: u64,
+    pub old_max_members: u32,
+    pub new_max_members: u32,
+    /// Members whose join is pending until they accept
+    pub pending_members: u32,
+    pub updated_at: u64,
+}
+
+/// Event emitted when a member accepts a group's updated terms.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MemberTermsAccepted {
+    pub group_id: u64,
+    pub member: Address,
+    pub terms_version: u32,
+    pub accepted_at: u64,
+}
+
+/// Event emitted when a member accepts the payout position the admin
+/// assigned them.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PositionAccepted {
+    pub group_id: u64,
+    pub member: Address,
+    pub position: u32,
+    pub accepted_at: u64,
+}
+
+/// Event emitted when a group starts its rotation.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupActivated {
+    pub group_id: u64,
+    /// Members the rotation starts with; also its number of payouts
+    pub member_count: u32,
+    pub activated_at: u64,
+}
+
+/// Event emitted when two members trade payout positions.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PositionsSwapped {
+    pub group_id: u64,
+    /// Member who proposed the swap; now holds `proposer_position`
+    pub proposer: Address,
+    pub proposer_position: u32,
+    /// Member who accepted the swap; now holds `accepter_position`
+    pub accepter: Address,
+    pub accepter_position: u32,
+    pub swapped_at: u64,
+}
+
+/// Event emitted when a group is paused.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupPaused {
+    pub group_id: u64,
+    pub paused_by: Address,
+    pub note: ActionNote,
+    pub paused_at: u64,
+}
+
+/// Event emitted when a paused group resumes.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupResumed {
+    pub group_id: u64,
+    pub resumed_by: Address,
+    pub resumed_at: u64,
+}
+
+/// Event emitted when a group is cancelled.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupCancelled {
+    pub group_id: u64,
+    pub cancelled_by: Address,
+    pub note: ActionNote,
+    pub cancelled_at: u64,
+}
+
+/// Event emitted when a Pending group passes its join deadline without
+/// starting.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupExpired {
+    pub group_id: u64,
+    pub expired_by: Address,
+    /// Total of the prepaid contributions returned to members
+    pub refunded: i128,
+    pub expired_at: u64,
+}
+
+/// Event emitted when a running group ends early on a unanimous member vote.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupCompletedEarly {
+    pub group_id: u64,
+    /// Cycles paid out before the group ended
+    pub cycles_paid: u32,
+    /// Total shared among the members still owed a payout
+    pub distributed: i128,
+    /// Members who received a share
+    pub recipients: u32,
+    pub completed_at: u64,
+}
+
+/// Event emitted when a group admin nominates a successor.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminTransferStarted {
+    pub group_id: u64,
+    pub current_admin: Address,
+    pub pending_admin: Address,
+    pub started_at: u64,
+}
+
+/// Event emitted when a nominated admin accepts the admin role.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminTransferred {
+    pub group_id: u64,
+    pub old_admin: Address,
+    pub new_admin: Address,
+    pub transferred_at: u64,
+}
+
+/// Event emitted when the group admin removes a member mid-group.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MemberRemoved {
+    pub group_id: u64,
+    pub member: Address,
+    pub payout_position: u32,
+    pub forfeited_amount: i128,
+    pub note: ActionNote,
+    pub removed_at: u64,
+}
+
+/// Event emitted when a member exits a running group through
+/// `emergency_withdraw`. `penalty` is the part of their contributions left
+/// to the remaining members.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmergencyWithdrawal {
+    pub group_id: u64,
+    pub member: Address,
+    pub refunded: i128,
+    pub penalty: i128,
+    pub withdrawn_at: u64,
+}
+
+/// Event emitted when a cycle is paid out without a member's contribution,
+/// the shortfall having been covered by the insurance reserve.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MemberDefaulted {
+    pub group_id: u64,
+    pub member: Address,
+    pub cycle: u32,
+    /// The member's missed contributions in the group, this one included
+    pub missed_contributions: u32,
+    pub defaulted_at: u64,
+}
+
+/// Event emitted when a replacement takes over a removed member's payout position.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MemberSubstituted {
+    pub group_id: u64,
+    pub removed_member: Address,
+    pub new_member: Address,
+    pub payout_position: u32,
+    pub substituted_at: u64,
+}
+
+/// Event emitted when the protocol fee rate changes.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProtocolFeeUpdated {
+    pub old_fee_bps: u32,
+    pub new_fee_bps: u32,
+    pub updated_at: u64,
+}
+
+/// Event emitted when a payout's protocol fee is credited to the treasury.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProtocolFeeCollected {
+    pub group_id: u64,
+    pub cycle: u32,
+    pub amount: i128,
+    pub collected_at: u64,
+}
+
+/// Event emitted when a payout credits an insurance premium to the group's
+/// reserve or draws on it to cover missing contributions.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InsuranceSettled {
+    pub group_id: u64,
+    pub cycle: u32,
+    pub premium: i128,
+    pub coverage: i128,
+    pub reserve_balance: i128,
+    pub settled_at: u64,
+}
+
+/// Event emitted when a recipient is compensated from the insurance reserve
+/// for a payout executed after it fell due.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LatePayoutCompensated {
+    pub group_id: u64,
+    pub cycle: u32,
+    pub recipient: Address,
+    /// Seconds between the payout falling due and its execution
+    pub delay_secs: u64,
+    pub compensation: i128,
+    pub compensated_at: u64,
+}
+
+/// Event emitted when a cycle pays out short under a best-effort payout
+/// policy and the missing contributions are charged to its defaulters.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CycleShortfall {
+    pub group_id: u64,
+    pub cycle: u32,
+    /// Contributions missing from the pool
+    pub shortfall: i128,
+    /// Members who did not contribute, each charged one contribution
+    pub defaulters: Vec<Address>,
+    pub recorded_at: u64,
+}
+
+/// Event emitted when a member who has been paid out misses a cycle and the
+/// missed contribution is added to their debt.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DebtAccrued {
+    pub group_id: u64,
+    pub member: Address,
+    pub cycle: u32,
+    pub amount: i128,
+    /// Debt still owed after the accrual
+    pub outstanding: i128,
+    pub accrued_at: u64,
+}
+
+/// Event emitted when a member pays back part or all of their debt.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DebtRepaid {
+    pub group_id: u64,
+    pub member: Address,
+    pub amount: i128,
+    /// Debt still owed after the repayment
+    pub outstanding: i128,
+    pub repaid_at: u64,
+}
+
+/// Event emitted when funds a completed group held for a member are kept to
+/// offset their debt.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DebtOffset {
+    pub group_id: u64,
+    pub member: Address,
+    pub amount: i128,
+    /// Debt still owed after the offset
+    pub outstanding: i128,
+    pub offset_at: u64,
+}
+
+/// Event emitted when a member pays in more than their contribution and the
+/// excess is kept as credit.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CreditDeposited {
+    pub group_id: u64,
+    pub member: Address,
+    pub amount: i128,
+    /// Credit held for the member after the deposit
+    pub balance: i128,
+    pub deposited_at: u64,
+}
+
+/// Event emitted when a member's credit pays (part of) a contribution.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CreditApplied {
+    pub group_id: u64,
+    pub member: Address,
+    pub cycle: u32,
+    pub amount: i128,
+    /// Credit left after paying the contribution
+    pub balance: i128,
+    pub applied_at: u64,
+}
+
+/// Event emitted when a member's leftover credit is paid back to them.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CreditRefunded {
+    pub group_id: u64,
+    pub member: Address,
+    pub amount: i128,
+    pub refunded_at: u64,
+}
+
+/// Event emitted when a completed group's leftover insurance reserve is
+/// split among its members.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InsuranceSurplusDistributed {
+    pub group_id: u64,
+    pub total: i128,
+    pub share_per_member: i128,
+    pub member_count: u32,
+    pub distributed_at: u64,
+}
+
+/// Event emitted when a complete pool is deposited into a yield strategy.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct YieldDeposited {
+    pub group_id: u64,
+    pub cycle: u32,
+    pub strategy: Address,
+    pub principal: i128,
+    pub deposited_at: u64,
+}
+
+/// Event emitted when a pool is withdrawn from its yield strategy before payout.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct YieldWithdrawn {
+    pub group_id: u64,
+    pub cycle: u32,
+    pub principal: i128,
+    pub earned: i128,
+    pub withdrawn_at: u64,
+}
+
+/// Event emitted when a completed group's held-back yield is split among its
+/// members.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct YieldDistributed {
+    pub group_id: u64,
+    pub total: i128,
+    pub share_per_member: i128,
+    pub member_count: u32,
+    pub distributed_at: u64,
+}
+
+/// Event emitted when a member opts in to or out of auto-contribution.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AutoContributeChanged {
+    pub group_id: u64,
+    pub member: Address,
+    pub enabled: bool,
+    pub changed_at: u64,
+}
+
+/// Event emitted when a keeper pulls a member's contribution.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContributionPulled {
+    pub group_id: u64,
+    pub member: Address,
+    pub cycle: u32,
+    pub amount: i128,
+    pub pulled_at: u64,
+}
+
+/// Event emitted when a member opens a governance proposal.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalCreated {
+    pub group_id: u64,
+    pub proposal_id: u32,
+    pub proposer: Address,
+    pub voting_ends_at: u64,
+    pub created_at: u64,
+}
+
+/// Event emitted when a member votes on a proposal.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalVoteCast {
+    pub group_id: u64,
+    pub proposal_id: u32,
+    pub voter: Address,
+    pub support: bool,
+    pub voted_at: u64,
+}
+
+/// Event emitted when a proposal is settled.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalClosed {
+    pub group_id: u64,
+    pub proposal_id: u32,
+    /// True if the proposal passed and its action was applied
+    pub executed: bool,
+    pub votes_for: u32,
+    pub votes_against: u32,
+    pub closed_at: u64,
+}
+
+/// Event emitted when a group's payout hook rejects or fails to handle a
+/// payout notification. The payout itself still went through.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutHookFailed {
+    pub group_id: u64,
+    pub cycle: u32,
+    pub hook: Address,
+    pub failed_at: u64,
+}
+
+/// Event emitted when a fiat-priced group sets a cycle's contribution from
+/// its oracle.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CyclePriced {
+    pub group_id: u64,
+    pub cycle: u32,
+    pub price: i128,
+    pub amount: i128,
+    pub priced_at: u64,
+}
+
+/// Event emitted when a finalized group's records are condensed and its
+/// storage entries removed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupArchived {
+    pub group_id: u64,
+    pub entries_removed: u32,
+    pub archived_at: u64,
+}
+
+/// Event emitted when the group admin appoints a treasurer or moderator.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoleGranted {
+    pub group_id: u64,
+    pub member: Address,
+    pub role: GroupRole,
+    pub granted_at: u64,
+}
+
+/// Event emitted when the group admin removes an address's role.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoleRevoked {
+    pub group_id: u64,
+    pub member: Address,
+    pub role: GroupRole,
+    pub revoked_at: u64,
+}
+
+/// Event emitted when the group admin invites an observer.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ObserverInvited {
+    pub group_id: u64,
+    pub observer: Address,
+    pub invited_at: u64,
+}
+
+/// Event emitted when the group admin removes an observer.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ObserverRevoked {
+    pub group_id: u64,
+    pub observer: Address,
+    pub revoked_at: u64,
+}
+
+/// Event emitted when a member's late contribution or default is waived.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PenaltyWaived {
+    pub group_id: u64,
+    pub member: Address,
+    pub cycle: u32,
+    pub waiver: PenaltyWaiver,
+}
+
+/// Event emitted when a group's configuration is saved as a template.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TemplateSaved {
+    pub template_id: u64,
+    /// Group the configuration was taken from
+    pub group_id: u64,
+    pub owner: Address,
+    pub saved_at: u64,
+}
+
+/// Event emitted when a completed group is restarted as a new group.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupRestarted {
+    /// The new group
+    pub group_id: u64,
+    pub previous_group_id: u64,
+    /// Previous members whose seats are held for them
+    pub invited: Vec<Address>,
+    pub restarted_at: u64,
+}
+
+/// Event emitted when a member disputes a cycle, holding its payout.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeOpened {
+    pub group_id: u64,
+    pub cycle: u32,
+    pub opened_by: Address,
+    pub subject: DisputeSubject,
+    pub note: ActionNote,
+    pub resolve_by: u64,
+}
+
+/// Event emitted when the arbiter rules on a dispute or it expires,
+/// releasing the cycle's payout.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeClosed {
+    pub group_id: u64,
+    pub cycle: u32,
+    pub status: DisputeStatus,
+    pub closed_at: u64,
+}
+
+/// Event emitted when the protocol admin withdraws from the treasury.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TreasuryWithdrawn {
+    pub to: Address,
+    pub amount: i128,
+    pub remaining: i128,
+    pub withdrawn_at: u64,
+}
+
+/// Event emitted when the protocol admin changes the per-referral reward.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReferralRewardUpdated {
+    pub old_reward: i128,
+    pub new_reward: i128,
+    pub updated_at: u64,
+}
+
+/// Event emitted when a group's creator adds to its referral bonus.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReferralBonusFunded {
+    pub group_id: u64,
+    pub funder: Address,
+    pub amount: i128,
+    pub total_bonus: i128,
+    pub funded_at: u64,
+}
+
+/// Event emitted when a savings-only group is created with its goal.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SavingsGoalSet {
+    pub group_id: u64,
+    pub kind: GoalKind,
+    pub target_amount: i128,
+    pub unlock_at: u64,
+    pub set_at: u64,
+}
+
+/// Event emitted when yield is added to a savings-only group.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SavingsYieldAdded {
+    pub group_id: u64,
+    pub from: Address,
+    pub amount: i128,
+    pub total_yield: i128,
+    pub added_at: u64,
+}
+
+/// Event emitted when a member withdraws their savings.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SavingsWithdrawn {
+    pub group_id: u64,
+    pub member: Address,
+    pub principal: i128,
+    pub yield_amount: i128,
+    pub withdrawn_at: u64,
+}
+
+/// Event emitted when a completed group credits a referrer for a member
+/// they referred.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReferralRewarded {
+    pub group_id: u64,
+    pub referrer: Address,
+    pub member: Address,
+    pub amount: i128,
+    pub rewarded_at: u64,
+}
+
+/// Event emitted when a referrer claims their accrued rewards.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReferralRewardsClaimed {
+    pub referrer: Address,
+    pub amount: i128,
+    pub claimed_at: u64,
+}
+
+/// Event emitted when the protocol admin changes the per-transaction caps.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RiskLimitsUpdated {
+    pub max_contribution: i128,
+    pub max_payout: i128,
+    pub updated_at: u64,
+}
+
+/// Event emitted when the protocol admin changes the caps on group parameters.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SafetyLimitsUpdated {
+    pub max_members: u32,
+    pub max_contribution: i128,
+    pub min_cycle_duration: u64,
+    pub max_cycle_duration: u64,
+    pub max_groups_per_creator: u32,
+    pub updated_at: u64,
+}
+
+/// Event emitted when a member asks the guardian to halt a group.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HaltVoteCast {
+    pub group_id: u64,
+    pub member: Address,
+    pub votes: u32,
+    pub voted_at: u64,
+}
+
+/// Event emitted when a group is halted under dual control.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupHalted {
+    pub group_id: u64,
+    pub guardian: Address,
+    /// True if a member quorum co-signed instead of the creator
+    pub by_quorum: bool,
+    pub halted_at: u64,
+}
+
+/// Event emitted when the protocol admin replaces the contract code.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractUpgraded {
+    pub new_wasm_hash: BytesN<32>,
+    pub upgraded_at: u64,
+}
+
+/// Event emitted when stored data is migrated to a newer schema version.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SchemaMigrated {
+    pub from_version: u32,
+    pub to_version: u32,
+    pub migrated_at: u64,
+}
+
+/// Event emitted when a member chooses where their payout is sent.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutAddressSet {
+    pub group_id: u64,
+    pub member: Address,
+    /// The member's own address when the override is cleared
+    pub destination: Address,
+    pub set_at: u64,
+}
+
+/// Event emitted when a member escrows their last contribution on joining.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowDeposited {
+    pub group_id: u64,
+    pub member: Address,
+    pub amount: i128,
+    pub deposited_at: u64,
+}
+
+/// Event emitted when a member's escrow moves into their credit to pay the
+/// group's final cycle.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowReleased {
+    pub group_id: u64,
+    pub member: Address,
+    pub cycle: u32,
+    pub amount: i128,
+    pub released_at: u64,
+}
+
+/// Event emitted when the protocol admin bars an address from creating or
+/// joining groups.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AddressBlacklisted {
+    pub address: Address,
+    pub blacklisted_at: u64,
+}
+
+/// Event emitted when the protocol admin lifts an address's blacklisting.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AddressUnblacklisted {
+    pub address: Address,
+    pub unblacklisted_at: u64,
+}
+
+/// Event emitted when the protocol admin announces the recovery of an
+/// inactive group's funds.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecoveryInitiated {
+    pub group_id: u64,
+    pub last_activity: u64,
+    pub initiated_at: u64,
+    pub executable_at: u64,
+}
+
+/// Event emitted when the protocol admin withdraws an announced recovery.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecoveryCancelled {
+    pub group_id: u64,
+    pub cancelled_at: u64,
+}
+
+/// Event emitted when an inactive group is cancelled and its funds are
+/// refunded to its members.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecoveryExecuted {
+    pub group_id: u64,
+    pub refunded: i128,
+    pub executed_at: u64,
+}
+
+/// Event emitted when a member pays their contribution in another asset,
+/// swapped into the contribution token.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContributionSwapped {
+    pub group_id: u64,
+    pub member: Address,
+    pub cycle: u32,
+    pub pay_token: Address,
+    pub amount_in: i128,
+    pub amount_out: i128,
+    pub swapped_at: u64,
+}
+
+/// Event emitted when an approver signs off on a cycle's payout.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutApproved {
+    pub group_id: u64,
+    pub cycle: u32,
+    pub approver: Address,
+    /// Approvals of the payout that count, including this one
+    pub approvals: u32,
+    pub approved_at: u64,
+}
+
+/// Event emitted when a contribution is parked for a member until its cycle
+/// opens.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DepositParked {
+    pub group_id: u64,
+    pub member: Address,
+    pub depositor: Address,
+    pub cycle: u32,
+    pub amount: i128,
+    pub parked_at: u64,
+}
+
+/// Event emitted when a parked deposit pays its cycle's contribution.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DepositApplied {
+    pub group_id: u64,
+    pub member: Address,
+    pub cycle: u32,
+    pub amount: i128,
+    pub applied_at: u64,
+}
+
+/// Event emitted when a depositor takes back a deposit not yet applied.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DepositReclaimed {
+    pub group_id: u64,
+    pub member: Address,
+    pub depositor: Address,
+    pub cycle: u32,
+    pub amount: i128,
+    pub reclaimed_at: u64,
+}
+
+/// Event emitted just before a call fails, while the protocol's debug flag
+/// is on, giving the context the error code alone does not. It carries no
+/// sequence number, as the failed call keeps nothing it publishes.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ErrorDiagnostic {
+    /// Code of the error returned
+    pub code: u32,
+    pub group_id: u64,
+    pub cycle: u32,
+    /// Value that was rejected; what it measures depends on the failure
+    pub value: i128,
+}
+
+/// Utility functions for emitting events.
+pub struct EventEmitter;
+
+impl EventEmitter {
+    /// Publishes an event under its catalogued name and version, followed
+    /// by the group's next sequence number for an event about a group.
+    pub fn publish<E: ContractEvent>(env: &Env, event: E) {
+        match event.group_id() {
+            Some(group_id) => {
+                let sequence = Self::next_sequence(env, group_id);
+                env.events().publish((E::NAME, E::VERSION, sequence), event);
+            }
+            None => env.events().publish((E::NAME, E::VERSION), event),
+        }
+    }
+
+    /// Returns the sequence number of the last event published about a
+    /// group, 0 if there was none.
+    pub fn last_sequence(env: &Env, group_id: u64) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&StorageKeyBuilder::event_sequence(group_id))
+            .unwrap_or(0)
+    }
+
+    /// Claims the sequence number for a group's next event.
+    fn next_sequence(env: &Env, group_id: u64) -> u64 {
+        let sequence = Self::last_sequence(env, group_id) + 1;
+        set_persistent(
+            env,
+            &StorageKeyBuilder::event_sequence(group_id),
+            &sequence,
+        );
+        sequence
+    }
+
+    pub fn emit_group_created(env: &Env, event: GroupCreated) {
+        Self::publish(env, event);
+    }
+
+    pub fn emit_group_metadata_updated(
+        env: &Env,
+        group_id: u64,
+        metadata: GroupMetadata,
+        updated_by: Address,
+        updated_at: u64,
+    ) {
+        let event = GroupMetadataUpdated {
+            group_id,
+            name: metadata.name,
+            description: metadata.description,
+            category: metadata.category,
+            updated_by,
+            updated_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_member_joined(
+        env: &Env,
+        group_id: u64,
+        member: Address,
+        member_count: u32,
+        joined_at: u64,
+    ) {
+        let event = MemberJoined {
+            group_id,
+            member,
+            member_count,
+            joined_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_member_left(
+        env: &Env,
+        group_id: u64,
+        member: Address,
+        member_count: u32,
+        left_at: u64,
+    ) {
+        let event = MemberLeft {
+            group_id,
+            member,
+            member_count,
+            left_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_contribution_made(
+        env: &Env,
+        group_id: u64,
+        contributor: Address,
+        amount: i128,
+        cycle: u32,
+        cycle_total: i128,
+        contributed_at: u64,
+    ) {
+        let event = ContributionMade {
+            group_id,
+            contributor,
+            amount,
+            cycle,
+            cycle_total,
+            contributed_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_contribution_late(
+        env: &Env,
+        group_id: u64,
+        contributor: Address,
+        cycle: u32,
+        late_by: u64,
+        in_grace: bool,
+        contributed_at: u64,
+    ) {
+        let event = ContributionLate {
+            group_id,
+            contributor,
+            cycle,
+            late_by,
+            in_grace,
+            contributed_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_contribution_reminder(
+        env: &Env,
+        group_id: u64,
+        cycle: u32,
+        deadline: u64,
+        threshold_secs: u64,
+        pending: Vec<Address>,
+        reminded_at: u64,
+    ) {
+        let event = ContributionReminder {
+            group_id,
+            cycle,
+            deadline,
+            threshold_secs,
+            pending,
+            reminded_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_payout_executed(
+        env: &Env,
+        group_id: u64,
+        recipient: Address,
+        amount: i128,
+        cycle: u32,
+        executed_at: u64,
+    ) {
+        let event = PayoutExecuted {
+            group_id,
+            recipient,
+            amount,
+            cycle,
+            executed_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_group_completed(
+        env: &Env,
+        group_id: u64,
+        creator: Address,
+        total_cycles: u32,
+        total_distributed: i128,
+        completed_at: u64,
+    ) {
+        let event = GroupCompleted {
+            group_id,
+            creator,
+            total_cycles,
+            total_distributed,
+            completed_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_cycle_advanced(
+        env: &Env,
+        group_id: u64,
+        completed_cycle: u32,
+        next_cycle: u32,
+        advanced_at: u64,
+    ) {
+        let event = CycleAdvanced {
+            group_id,
+            completed_cycle,
+            next_cycle,
+            advanced_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_group_settled(env: &Env, report: GroupSettled) {
+        Self::publish(env, report);
+    }
+
+    pub fn emit_group_finalized(
+        env: &Env,
+        group_id: u64,
+        residual: i128,
+        total_returned: i128,
+        finalized_at: u64,
+    ) {
+        let event = GroupFinalized {
+            group_id,
+            residual,
+            total_returned,
+            finalized_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_group_status_changed(
+        env: &Env,
+        group_id: u64,
+        old_status: u32,
+        new_status: u32,
+        changed_by: Address,
+        changed_at: u64,
+    ) {
+        let event = GroupStatusChanged {
+            group_id,
+            old_status,
+            new_status,
+            changed_by,
+            changed_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_group_terms_updated(env: &Env, event: GroupTermsUpdated) {
+        Self::publish(env, event);
+    }
+
+    pub fn emit_member_terms_accepted(
+        env: &Env,
+        group_id: u64,
+        member: Address,
+        terms_version: u32,
+        accepted_at: u64,
+    ) {
+        let event = MemberTermsAccepted {
+            group_id,
+            member,
+            terms_version,
+            accepted_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_position_accepted(
+        env: &Env,
+        group_id: u64,
+        member: Address,
+        position: u32,
+        accepted_at: u64,
+    ) {
+        let event = PositionAccepted {
+            group_id,
+            member,
+            position,
+            accepted_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_group_activated(env: &Env, group_id: u64, member_count: u32, activated_at: u64) {
+        let event = GroupActivated {
+            group_id,
+            member_count,
+            activated_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_positions_swapped(
+        env: &Env,
+        group_id: u64,
+        proposer: Address,
+        proposer_position: u32,
+        accepter: Address,
+        accepter_position: u32,
+        swapped_at: u64,
+    ) {
+        let event = PositionsSwapped {
+            group_id,
+            proposer,
+            proposer_position,
+            accepter,
+            accepter_position,
+            swapped_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_group_paused(
+        env: &Env,
+        group_id: u64,
+        paused_by: Address,
+        note: ActionNote,
+        paused_at: u64,
+    ) {
+        let event = GroupPaused {
+            group_id,
+            paused_by,
+            note,
+            paused_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_group_resumed(env: &Env, group_id: u64, resumed_by: Address, resumed_at: u64) {
+        let event = GroupResumed {
+            group_id,
+            resumed_by,
+            resumed_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_group_cancelled(
+        env: &Env,
+        group_id: u64,
+        cancelled_by: Address,
+        note: ActionNote,
+        cancelled_at: u64,
+    ) {
+        let event = GroupCancelled {
+            group_id,
+            cancelled_by,
+            note,
+            cancelled_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_group_expired(
+        env: &Env,
+        group_id: u64,
+        expired_by: Address,
+        refunded: i128,
+        expired_at: u64,
+    ) {
+        let event = GroupExpired {
+            group_id,
+            expired_by,
+            refunded,
+            expired_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_group_completed_early(
+        env: &Env,
+        group_id: u64,
+        cycles_paid: u32,
+        distributed: i128,
+        recipients: u32,
+        completed_at: u64,
+    ) {
+        let event = GroupCompletedEarly {
+            group_id,
+            cycles_paid,
+            distributed,
+            recipients,
+            completed_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_admin_transfer_started(
+        env: &Env,
+        group_id: u64,
+        current_admin: Address,
+        pending_admin: Address,
+        started_at: u64,
+    ) {
+        let event = AdminTransferStarted {
+            group_id,
+            current_admin,
+            pending_admin,
+            started_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_admin_transferred(
+        env: &Env,
+        group_id: u64,
+        old_admin: Address,
+        new_admin: Address,
+        transferred_at: u64,
+    ) {
+        let event = AdminTransferred {
+            group_id,
+            old_admin,
+            new_admin,
+            transferred_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_member_removed(
+        env: &Env,
+        group_id: u64,
+        member: Address,
+        payout_position: u32,
+        forfeited_amount: i128,
+        note: ActionNote,
+        removed_at: u64,
+    ) {
+        let event = MemberRemoved {
+            group_id,
+            member,
+            payout_position,
+            forfeited_amount,
+            note,
+            removed_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_emergency_withdrawal(
+        env: &Env,
+        group_id: u64,
+        member: Address,
+        refunded: i128,
+        penalty: i128,
+        withdrawn_at: u64,
+    ) {
+        let event = EmergencyWithdrawal {
+            group_id,
+            member,
+            refunded,
+            penalty,
+            withdrawn_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_member_defaulted(
+        env: &Env,
+        group_id: u64,
+        member: Address,
+        cycle: u32,
+        missed_contributions: u32,
+        defaulted_at: u64,
+    ) {
+        let event = MemberDefaulted {
+            group_id,
+            member,
+            cycle,
+            missed_contributions,
+            defaulted_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_member_substituted(
+        env: &Env,
+        group_id: u64,
+        removed_member: Address,
+        new_member: Address,
+        payout_position: u32,
+        substituted_at: u64,
+    ) {
+        let event = MemberSubstituted {
+            group_id,
+            removed_member,
+            new_member,
+            payout_position,
+            substituted_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_protocol_fee_updated(env: &Env, old_fee_bps: u32, new_fee_bps: u32, updated_at: u64) {
+        let event = ProtocolFeeUpdated {
+            old_fee_bps,
+            new_fee_bps,
+            updated_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_protocol_fee_collected(
+        env: &Env,
+        group_id: u64,
+        cycle: u32,
+        amount: i128,
+        collected_at: u64,
+    ) {
+        let event = ProtocolFeeCollected {
+            group_id,
+            cycle,
+            amount,
+            collected_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_insurance_settled(
+        env: &Env,
+        group_id: u64,
+        cycle: u32,
+        premium: i128,
+        coverage: i128,
+        reserve_balance: i128,
+        settled_at: u64,
+    ) {
+        let event = InsuranceSettled {
+            group_id,
+            cycle,
+            premium,
+            coverage,
+            reserve_balance,
+            settled_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_late_payout_compensated(
+        env: &Env,
+        group_id: u64,
+        cycle: u32,
+        recipient: Address,
+        delay_secs: u64,
+        compensation: i128,
+        compensated_at: u64,
+    ) {
+        let event = LatePayoutCompensated {
+            group_id,
+            cycle,
+            recipient,
+            delay_secs,
+            compensation,
+            compensated_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_cycle_shortfall(
+        env: &Env,
+        group_id: u64,
+        cycle: u32,
+        shortfall: i128,
+        defaulters: Vec<Address>,
+        recorded_at: u64,
+    ) {
+        let event = CycleShortfall {
+            group_id,
+            cycle,
+            shortfall,
+            defaulters,
+            recorded_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_debt_accrued(
+        env: &Env,
+        group_id: u64,
+        member: Address,
+        cycle: u32,
+        amount: i128,
+        outstanding: i128,
+        accrued_at: u64,
+    ) {
+        let event = DebtAccrued {
+            group_id,
+            member,
+            cycle,
+            amount,
+            outstanding,
+            accrued_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_debt_repaid(
+        env: &Env,
+        group_id: u64,
+        member: Address,
+        amount: i128,
+        outstanding: i128,
+        repaid_at: u64,
+    ) {
+        let event = DebtRepaid {
+            group_id,
+            member,
+            amount,
+            outstanding,
+            repaid_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_debt_offset(
+        env: &Env,
+        group_id: u64,
+        member: Address,
+        amount: i128,
+        outstanding: i128,
+        offset_at: u64,
+    ) {
+        let event = DebtOffset {
+            group_id,
+            member,
+            amount,
+            outstanding,
+            offset_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_credit_deposited(
+        env: &Env,
+        group_id: u64,
+        member: Address,
+        amount: i128,
+        balance: i128,
+        deposited_at: u64,
+    ) {
+        let event = CreditDeposited {
+            group_id,
+            member,
+            amount,
+            balance,
+            deposited_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_credit_applied(
+        env: &Env,
+        group_id: u64,
+        member: Address,
+        cycle: u32,
+        amount: i128,
+        balance: i128,
+        applied_at: u64,
+    ) {
+        let event = CreditApplied {
+            group_id,
+            member,
+            cycle,
+            amount,
+            balance,
+            applied_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_credit_refunded(
+        env: &Env,
+        group_id: u64,
+        member: Address,
+        amount: i128,
+        refunded_at: u64,
+    ) {
+        let event = CreditRefunded {
+            group_id,
+            member,
+            amount,
+            refunded_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_role_granted(
+        env: &Env,
+        group_id: u64,
+        member: Address,
+        role: GroupRole,
+        granted_at: u64,
+    ) {
+        let event = RoleGranted {
+            group_id,
+            member,
+            role,
+            granted_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_role_revoked(
+        env: &Env,
+        group_id: u64,
+        member: Address,
+        role: GroupRole,
+        revoked_at: u64,
+    ) {
+        let event = RoleRevoked {
+            group_id,
+            member,
+            role,
+            revoked_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_observer_invited(env: &Env, group_id: u64, observer: Address, invited_at: u64) {
+        let event = ObserverInvited {
+            group_id,
+            observer,
+            invited_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_observer_revoked(env: &Env, group_id: u64, observer: Address, revoked_at: u64) {
+        let event = ObserverRevoked {
+            group_id,
+            observer,
+            revoked_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_penalty_waived(
+        env: &Env,
+        group_id: u64,
+        member: Address,
+        cycle: u32,
+        waiver: PenaltyWaiver,
+    ) {
+        let event = PenaltyWaived {
+            group_id,
+            member,
+            cycle,
+            waiver,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_template_saved(
+        env: &Env,
+        template_id: u64,
+        group_id: u64,
+        owner: Address,
+        saved_at: u64,
+    ) {
+        let event = TemplateSaved {
+            template_id,
+            group_id,
+            owner,
+            saved_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_group_restarted(
+        env: &Env,
+        group_id: u64,
+        previous_group_id: u64,
+        invited: Vec<Address>,
+        restarted_at: u64,
+    ) {
+        let event = GroupRestarted {
+            group_id,
+            previous_group_id,
+            invited,
+            restarted_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_insurance_surplus_distributed(
+        env: &Env,
+        group_id: u64,
+        total: i128,
+        share_per_member: i128,
+        member_count: u32,
+        distributed_at: u64,
+    ) {
+        let event = InsuranceSurplusDistributed {
+            group_id,
+            total,
+            share_per_member,
+            member_count,
+            distributed_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_yield_deposited(
+        env: &Env,
+        group_id: u64,
+        cycle: u32,
+        strategy: Address,
+        principal: i128,
+        deposited_at: u64,
+    ) {
+        let event = YieldDeposited {
+            group_id,
+            cycle,
+            strategy,
+            principal,
+            deposited_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_yield_withdrawn(
+        env: &Env,
+        group_id: u64,
+        cycle: u32,
+        principal: i128,
+        earned: i128,
+        withdrawn_at: u64,
+    ) {
+        let event = YieldWithdrawn {
+            group_id,
+            cycle,
+            principal,
+            earned,
+            withdrawn_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_yield_distributed(
+        env: &Env,
+        group_id: u64,
+        total: i128,
+        share_per_member: i128,
+        member_count: u32,
+        distributed_at: u64,
+    ) {
+        let event = YieldDistributed {
+            group_id,
+            total,
+            share_per_member,
+            member_count,
+            distributed_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_auto_contribute_changed(
+        env: &Env,
+        group_id: u64,
+        member: Address,
+        enabled: bool,
+        changed_at: u64,
+    ) {
+        let event = AutoContributeChanged {
+            group_id,
+            member,
+            enabled,
+            changed_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_contribution_pulled(
+        env: &Env,
+        group_id: u64,
+        member: Address,
+        cycle: u32,
+        amount: i128,
+        pulled_at: u64,
+    ) {
+        let event = ContributionPulled {
+            group_id,
+            member,
+            cycle,
+            amount,
+            pulled_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_proposal_created(
+        env: &Env,
+        group_id: u64,
+        proposal_id: u32,
+        proposer: Address,
+        voting_ends_at: u64,
+        created_at: u64,
+    ) {
+        let event = ProposalCreated {
+            group_id,
+            proposal_id,
+            proposer,
+            voting_ends_at,
+            created_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_proposal_vote_cast(
+        env: &Env,
+        group_id: u64,
+        proposal_id: u32,
+        voter: Address,
+        support: bool,
+        voted_at: u64,
+    ) {
+        let event = ProposalVoteCast {
+            group_id,
+            proposal_id,
+            voter,
+            support,
+            voted_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_proposal_closed(
+        env: &Env,
+        group_id: u64,
+        proposal_id: u32,
+        executed: bool,
+        votes_for: u32,
+        votes_against: u32,
+        closed_at: u64,
+    ) {
+        let event = ProposalClosed {
+            group_id,
+            proposal_id,
+            executed,
+            votes_for,
+            votes_against,
+            closed_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_payout_hook_failed(
+        env: &Env,
+        group_id: u64,
+        cycle: u32,
+        hook: Address,
+        failed_at: u64,
+    ) {
+        let event = PayoutHookFailed {
+            group_id,
+            cycle,
+            hook,
+            failed_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_cycle_priced(
+        env: &Env,
+        group_id: u64,
+        cycle: u32,
+        price: i128,
+        amount: i128,
+        priced_at: u64,
+    ) {
+        let event = CyclePriced {
+            group_id,
+            cycle,
+            price,
+            amount,
+            priced_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_group_archived(env: &Env, group_id: u64, entries_removed: u32, archived_at: u64) {
+        let event = GroupArchived {
+            group_id,
+            entries_removed,
+            archived_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_dispute_opened(
+        env: &Env,
+        group_id: u64,
+        cycle: u32,
+        opened_by: Address,
+        subject: DisputeSubject,
+        note: ActionNote,
+        resolve_by: u64,
+    ) {
+        let event = DisputeOpened {
+            group_id,
+            cycle,
+            opened_by,
+            subject,
+            note,
+            resolve_by,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_dispute_closed(
+        env: &Env,
+        group_id: u64,
+        cycle: u32,
+        status: DisputeStatus,
+        closed_at: u64,
+    ) {
+        let event = DisputeClosed {
+            group_id,
+            cycle,
+            status,
+            closed_at,
+        };
+        Self::publish(env, event);
+    }
 
-/// Event emitted when a member leaves a group before activation.
-#[contracttype]
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub struct MemberLeft {
-    pub group_id: u64,
-    pub member: Address,
-    pub member_count: u32,
-    pub left_at: u64,
-}
+    pub fn emit_treasury_withdrawn(
+        env: &Env,
+        to: Address,
+        amount: i128,
+        remaining: i128,
+        withdrawn_at: u64,
+    ) {
+        let event = TreasuryWithdrawn {
+            to,
+            amount,
+            remaining,
+            withdrawn_at,
+        };
+        Self::publish(env, event);
+    }
 
-/// Event emitted when a member makes a contribution.
-#[contracttype]
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub struct ContributionMade {
-    pub group_id: u64,
-    pub contributor: Address,
-    pub amount: i128,
-    pub cycle: u32,
-    pub cycle_total: i128,
-    pub contributed_at: u64,
-}
+    pub fn emit_referral_reward_updated(
+        env: &Env,
+        old_reward: i128,
+        new_reward: i128,
+        updated_at: u64,
+    ) {
+        let event = ReferralRewardUpdated {
+            old_reward,
+            new_reward,
+            updated_at,
+        };
+        Self::publish(env, event);
+    }
 
-/// Event emitted when a payout is executed.
-#[contracttype]
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub struct PayoutExecuted {
-    pub group_id: u64,
-    pub recipient: Address,
-    pub amount: i128,
-    pub cycle: u32,
-    pub executed_at: u64,
-}
+    pub fn emit_referral_bonus_funded(
+        env: &Env,
+        group_id: u64,
+        funder: Address,
+        amount: i128,
+        total_bonus: i128,
+        funded_at: u64,
+    ) {
+        let event = ReferralBonusFunded {
+            group_id,
+            funder,
+            amount,
+            total_bonus,
+            funded_at,
+        };
+        Self::publish(env, event);
+    }
 
-/// Event emitted when a group completes all cycles.
-#[contracttype]
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub struct GroupCompleted {
-    pub group_id: u64,
-    pub creator: Address,
-    pub total_cycles: u32,
-    pub total_distributed: i128,
-    pub completed_at: u64,
-}
+    pub fn emit_savings_goal_set(
+        env: &Env,
+        group_id: u64,
+        kind: GoalKind,
+        target_amount: i128,
+        unlock_at: u64,
+        set_at: u64,
+    ) {
+        let event = SavingsGoalSet {
+            group_id,
+            kind,
+            target_amount,
+            unlock_at,
+            set_at,
+        };
+        Self::publish(env, event);
+    }
 
-/// Event emitted when a group's status changes.
-#[contracttype]
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub struct GroupStatusChanged {
-    pub group_id: u64,
-    pub old_status: u32,
-    pub new_status: u32,
-    pub changed_by: Address,
-    pub changed_at: u64,
-}
+    pub fn emit_savings_yield_added(
+        env: &Env,
+        group_id: u64,
+        from: Address,
+        amount: i128,
+        total_yield: i128,
+        added_at: u64,
+    ) {
+        let event = SavingsYieldAdded {
+            group_id,
+            from,
+            amount,
+            total_yield,
+            added_at,
+        };
+        Self::publish(env, event);
+    }
 
-/// Utility functions for emitting events.
-pub struct EventEmitter;
+    pub fn emit_savings_withdrawn(
+        env: &Env,
+        group_id: u64,
+        member: Address,
+        principal: i128,
+        yield_amount: i128,
+        withdrawn_at: u64,
+    ) {
+        let event = SavingsWithdrawn {
+            group_id,
+            member,
+            principal,
+            yield_amount,
+            withdrawn_at,
+        };
+        Self::publish(env, event);
+    }
 
-impl EventEmitter {
-    pub fn emit_group_created(
+    pub fn emit_referral_rewarded(
         env: &Env,
         group_id: u64,
-        creator: Address,
-        contribution_amount: i128,
-        cycle_duration: u64,
-        max_members: u32,
-        created_at: u64,
+        referrer: Address,
+        member: Address,
+        amount: i128,
+        rewarded_at: u64,
     ) {
-        let event = GroupCreated {
+        let event = ReferralRewarded {
             group_id,
-            creator,
-            contribution_amount,
-            cycle_duration,
-            max_members,
-            created_at,
+            referrer,
+            member,
+            amount,
+            rewarded_at,
         };
-        env.events().publish(("group_created",), event);
+        Self::publish(env, event);
     }
 
-    pub fn emit_member_joined(
+    pub fn emit_referral_rewards_claimed(
+        env: &Env,
+        referrer: Address,
+        amount: i128,
+        claimed_at: u64,
+    ) {
+        let event = ReferralRewardsClaimed {
+            referrer,
+            amount,
+            claimed_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_risk_limits_updated(
+        env: &Env,
+        max_contribution: i128,
+        max_payout: i128,
+        updated_at: u64,
+    ) {
+        let event = RiskLimitsUpdated {
+            max_contribution,
+            max_payout,
+            updated_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_safety_limits_updated(env: &Env, limits: SafetyLimits, updated_at: u64) {
+        let event = SafetyLimitsUpdated {
+            max_members: limits.max_members,
+            max_contribution: limits.max_contribution,
+            min_cycle_duration: limits.min_cycle_duration,
+            max_cycle_duration: limits.max_cycle_duration,
+            max_groups_per_creator: limits.max_groups_per_creator,
+            updated_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_halt_vote_cast(
         env: &Env,
         group_id: u64,
         member: Address,
-        member_count: u32,
-        joined_at: u64,
+        votes: u32,
+        voted_at: u64,
     ) {
-        let event = MemberJoined {
+        let event = HaltVoteCast {
             group_id,
             member,
-            member_count,
-            joined_at,
+            votes,
+            voted_at,
         };
-        env.events().publish(("member_joined",), event);
+        Self::publish(env, event);
     }
 
-    pub fn emit_member_left(
+    pub fn emit_group_halted(
+        env: &Env,
+        group_id: u64,
+        guardian: Address,
+        by_quorum: bool,
+        halted_at: u64,
+    ) {
+        let event = GroupHalted {
+            group_id,
+            guardian,
+            by_quorum,
+            halted_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_contract_upgraded(env: &Env, new_wasm_hash: BytesN<32>, upgraded_at: u64) {
+        let event = ContractUpgraded {
+            new_wasm_hash,
+            upgraded_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_schema_migrated(env: &Env, from_version: u32, to_version: u32, migrated_at: u64) {
+        let event = SchemaMigrated {
+            from_version,
+            to_version,
+            migrated_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_payout_address_set(
         env: &Env,
         group_id: u64,
         member: Address,
-        member_count: u32,
-        left_at: u64,
+        destination: Address,
+        set_at: u64,
     ) {
-        let event = MemberLeft {
+        let event = PayoutAddressSet {
             group_id,
             member,
-            member_count,
-            left_at,
+            destination,
+            set_at,
         };
-        env.events().publish(("member_left",), event);
+        Self::publish(env, event);
     }
 
-    pub fn emit_contribution_made(
+    pub fn emit_escrow_deposited(
         env: &Env,
         group_id: u64,
-        contributor: Address,
+        member: Address,
         amount: i128,
-        cycle: u32,
-        cycle_total: i128,
-        contributed_at: u64,
+        deposited_at: u64,
     ) {
-        let event = ContributionMade {
+        let event = EscrowDeposited {
             group_id,
-            contributor,
+            member,
             amount,
-            cycle,
-            cycle_total,
-            contributed_at,
+            deposited_at,
         };
-        env.events().publish(("contribution_made",), event);
+        Self::publish(env, event);
     }
 
-    pub fn emit_payout_executed(
+    pub fn emit_escrow_released(
         env: &Env,
         group_id: u64,
-        recipient: Address,
-        amount: i128,
+        member: Address,
         cycle: u32,
-        executed_at: u64,
+        amount: i128,
+        released_at: u64,
     ) {
-        let event = PayoutExecuted {
+        let event = EscrowReleased {
             group_id,
-            recipient,
-            amount,
+            member,
             cycle,
+            amount,
+            released_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_address_blacklisted(env: &Env, address: Address, blacklisted_at: u64) {
+        let event = AddressBlacklisted {
+            address,
+            blacklisted_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_address_unblacklisted(env: &Env, address: Address, unblacklisted_at: u64) {
+        let event = AddressUnblacklisted {
+            address,
+            unblacklisted_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_recovery_initiated(
+        env: &Env,
+        group_id: u64,
+        last_activity: u64,
+        initiated_at: u64,
+        executable_at: u64,
+    ) {
+        let event = RecoveryInitiated {
+            group_id,
+            last_activity,
+            initiated_at,
+            executable_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_recovery_cancelled(env: &Env, group_id: u64, cancelled_at: u64) {
+        let event = RecoveryCancelled {
+            group_id,
+            cancelled_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_recovery_executed(env: &Env, group_id: u64, refunded: i128, executed_at: u64) {
+        let event = RecoveryExecuted {
+            group_id,
+            refunded,
             executed_at,
         };
-        env.events().publish(("payout_executed",), event);
+        Self::publish(env, event);
     }
 
-    pub fn emit_group_completed(
+    pub fn emit_contribution_swapped(env: &Env, swap: &ContributionSwap) {
+        let event = ContributionSwapped {
+            group_id: swap.group_id,
+            member: swap.member.clone(),
+            cycle: swap.cycle,
+            pay_token: swap.pay_token.clone(),
+            amount_in: swap.amount_in,
+            amount_out: swap.amount_out,
+            swapped_at: swap.swapped_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_payout_approved(
         env: &Env,
         group_id: u64,
-        creator: Address,
-        total_cycles: u32,
-        total_distributed: i128,
-        completed_at: u64,
+        cycle: u32,
+        approver: Address,
+        approvals: u32,
+        approved_at: u64,
     ) {
-        let event = GroupCompleted {
+        let event = PayoutApproved {
             group_id,
-            creator,
-            total_cycles,
-            total_distributed,
-            completed_at,
+            cycle,
+            approver,
+            approvals,
+            approved_at,
         };
-        env.events().publish(("group_completed",), event);
+        Self::publish(env, event);
     }
 
-    pub fn emit_group_status_changed(
+    pub fn emit_deposit_parked(env: &Env, group_id: u64, deposit: &PendingDeposit) {
+        let event = DepositParked {
+            group_id,
+            member: deposit.member.clone(),
+            depositor: deposit.depositor.clone(),
+            cycle: deposit.cycle,
+            amount: deposit.amount,
+            parked_at: deposit.parked_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_deposit_applied(
         env: &Env,
         group_id: u64,
-        old_status: u32,
-        new_status: u32,
-        changed_by: Address,
-        changed_at: u64,
+        deposit: &PendingDeposit,
+        applied_at: u64,
     ) {
-        let event = GroupStatusChanged {
+        let event = DepositApplied {
             group_id,
-            old_status,
-            new_status,
-            changed_by,
-            changed_at,
+            member: deposit.member.clone(),
+            cycle: deposit.cycle,
+            amount: deposit.amount,
+            applied_at,
+        };
+        Self::publish(env, event);
+    }
+
+    pub fn emit_deposit_reclaimed(
+        env: &Env,
+        group_id: u64,
+        deposit: &PendingDeposit,
+        reclaimed_at: u64,
+    ) {
+        let event = DepositReclaimed {
+            group_id,
+            member: deposit.member.clone(),
+            depositor: deposit.depositor.clone(),
+            cycle: deposit.cycle,
+            amount: deposit.amount,
+            reclaimed_at,
         };
-        env.events().publish(("group_status_changed",), event);
+        Self::publish(env, event);
+    }
+
+    pub fn emit_error_diagnostic(env: &Env, code: u32, group_id: u64, cycle: u32, value: i128) {
+        let event = ErrorDiagnostic {
+            code,
+            group_id,
+            cycle,
+            value,
+        };
+        Self::publish(env, event);
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::units::{SECONDS_PER_WEEK, STROOPS_PER_XLM};
     use soroban_sdk::{testutils::Address as _, Address, Env};
 
     #[test]
@@ -221,9 +2594,12 @@ mod tests {
         let event = GroupCreated {
             group_id: 1,
             creator: creator.clone(),
-            contribution_amount: 10_000_000,
-            cycle_duration: 604800,
+            contribution_amount: STROOPS_PER_XLM,
+            cycle_duration: SECONDS_PER_WEEK,
             max_members: 5,
+            name: String::from_str(&env, "Market Women Circle"),
+            description: String::from_str(&env, ""),
+            category: Symbol::new(&env, "market"),
             created_at: 1234567890,
         };
 
@@ -250,8 +2626,69 @@ mod tests {
     #[test]
     fn test_event_emitter_group_created() {
         let env = Env::default();
+        let contract_id = env.register(crate::StellarSaveContract, ());
         let creator = Address::generate(&env);
 
-        EventEmitter::emit_group_created(&env, 1, creator, 10_000_000, 604800, 5, 1234567890);
+        let metadata = GroupMetadata::unnamed(&env);
+        env.as_contract(&contract_id, || {
+            EventEmitter::emit_group_created(
+                &env,
+                GroupCreated {
+                    group_id: 1,
+                    creator,
+                    contribution_amount: STROOPS_PER_XLM,
+                    cycle_duration: SECONDS_PER_WEEK,
+                    max_members: 5,
+                    name: metadata.name,
+                    description: metadata.description,
+                    category: metadata.category,
+                    created_at: 1234567890,
+                },
+            );
+        });
+    }
+
+    #[test]
+    fn test_events_publish_name_and_version_topics() {
+        use soroban_sdk::testutils::Events as _;
+        use soroban_sdk::{String, TryFromVal};
+
+        let env = Env::default();
+        let contract_id = env.register(crate::StellarSaveContract, ());
+        let member = Address::generate(&env);
+
+        env.as_contract(&contract_id, || {
+            EventEmitter::emit_cycle_advanced(&env, 1, 0, 1, 1234567890);
+            EventEmitter::emit_member_defaulted(&env, 1, member.clone(), 0, 1, 1234567890);
+        });
+
+        let events = env.events().all();
+        assert_eq!(events.len(), 2);
+        for (i, name) in [CycleAdvanced::NAME, MemberDefaulted::NAME].iter().enumerate() {
+            let (_, topics, _) = events.get(i as u32).unwrap();
+            assert_eq!(topics.len(), 3);
+            assert_eq!(
+                String::try_from_val(&env, &topics.get(0).unwrap()).unwrap(),
+                String::from_str(&env, name)
+            );
+            assert_eq!(u32::try_from_val(&env, &topics.get(1).unwrap()).unwrap(), 1);
+            assert_eq!(
+                u64::try_from_val(&env, &topics.get(2).unwrap()).unwrap(),
+                i as u64 + 1
+            );
+        }
+
+        let (_, _, data) = events.get(1).unwrap();
+        let event = MemberDefaulted::try_from_val(&env, &data).unwrap();
+        assert_eq!(event.member, member);
+        assert_eq!(event.missed_contributions, 1);
+
+        // Protocol-wide events have no sequence
+        env.as_contract(&contract_id, || {
+            EventEmitter::emit_schema_migrated(&env, 1, 2, 1234567890);
+            assert_eq!(EventEmitter::last_sequence(&env, 1), 2);
+        });
+        let (_, topics, _) = env.events().all().get(0).unwrap();
+        assert_eq!(topics.len(), 2);
     }
 }
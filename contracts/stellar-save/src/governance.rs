@@ -0,0 +1,341 @@
+//! Member governance for a single group.
+//!
+//! Any member can open a proposal and every current member has one vote.
+//! A proposal is executed automatically by the vote that makes its outcome
+//! certain, meaning the remaining members could no longer defeat it. Proposals
+//! still undecided when their voting period ends are settled with
+//! `close_proposal` against the votes actually cast.
+//!
+//! Ending a running group early is the exception: it needs every member's
+//! vote, whatever the group's quorum and threshold.
+
+use crate::storage::StorageKeyBuilder;
+use crate::units::{days, BPS_DENOMINATOR};
+use crate::waiver::WaiverReason;
+use crate::VacancyPolicy;
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+/// Default voting period (3 days).
+pub const DEFAULT_VOTING_PERIOD: u64 = days(3);
+
+/// What a proposal does once it passes.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ProposalAction {
+    /// Cancel the group.
+    CancelGroup,
+    /// Push the current and every later deadline back by this many seconds.
+    ExtendDeadline(u64),
+    /// Remove a member, handling their position with the given policy.
+    RemoveMember(Address, VacancyPolicy),
+    /// Give the members still owed a payout their remaining positions in
+    /// this order.
+    ReorderPayouts(Vec<Address>),
+    /// Waive a member's late contribution or default in a cycle.
+    WaivePenalty(Address, u32, WaiverReason),
+    /// End the running group now: the members still owed a payout share
+    /// what it holds. Needs every member's vote.
+    CompleteEarly,
+}
+
+impl ProposalAction {
+    /// Whether the action needs every member to vote for it, rather than the
+    /// group's quorum and threshold.
+    pub fn needs_unanimity(&self) -> bool {
+        matches!(self, ProposalAction::CompleteEarly)
+    }
+}
+
+/// Lifecycle of a proposal.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ProposalStatus {
+    /// Accepting votes.
+    Open,
+    /// Passed and its action applied.
+    Executed,
+    /// Defeated, or short of quorum when voting closed.
+    Rejected,
+}
+
+/// A group proposal and its running tally.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Proposal {
+    pub id: u32,
+    pub group_id: u64,
+    pub proposer: Address,
+    pub action: ProposalAction,
+    pub votes_for: u32,
+    pub votes_against: u32,
+    pub created_at: u64,
+    /// Votes are accepted up to and including this timestamp
+    pub voting_ends_at: u64,
+    pub status: ProposalStatus,
+}
+
+/// Quorum and approval rules for a group's proposals.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GovernanceConfig {
+    /// Share of members, in basis points, who must vote for a result to count
+    pub quorum_bps: u32,
+    /// Share of the votes cast, in basis points, that approval must exceed
+    pub threshold_bps: u32,
+    /// Seconds a proposal stays open for voting
+    pub voting_period: u64,
+}
+
+impl GovernanceConfig {
+    /// Half the members must vote and a simple majority of them approve.
+    pub fn default_config() -> Self {
+        Self {
+            quorum_bps: 5_000,
+            threshold_bps: 5_000,
+            voting_period: DEFAULT_VOTING_PERIOD,
+        }
+    }
+
+    /// Whether the rules are usable: both shares within 100% and a voting
+    /// period of at least one second.
+    pub fn is_valid(&self) -> bool {
+        self.quorum_bps > 0
+            && self.quorum_bps <= BPS_DENOMINATOR
+            && self.threshold_bps < BPS_DENOMINATOR
+            && self.voting_period > 0
+    }
+}
+
+/// Returns the group's governance rules, or the defaults if none were set.
+pub fn load_config(env: &Env, group_id: u64) -> GovernanceConfig {
+    env.storage()
+        .persistent()
+        .get(&StorageKeyBuilder::group_governance_config(group_id))
+        .unwrap_or(GovernanceConfig::default_config())
+}
+
+/// Whether `count` out of `total` exceeds `bps`, or meets it when `inclusive`.
+fn share_reaches(count: u32, total: u32, bps: u32, inclusive: bool) -> bool {
+    let lhs = count as u64 * BPS_DENOMINATOR as u64;
+    let rhs = total as u64 * bps as u64;
+    if inclusive {
+        lhs >= rhs
+    } else {
+        lhs > rhs
+    }
+}
+
+/// Settles a proposal whose outcome no remaining vote can change, or returns
+/// `Open` while it is still undecided.
+///
+/// It has passed once quorum is met and its approvals exceed the threshold
+/// even if every member who has not voted votes against. It has failed once
+/// approval could not exceed the threshold even if every remaining member
+/// votes for it. A proposal needing unanimity passes on the last member's
+/// vote for it and fails on the first vote against.
+pub fn decided_outcome(
+    proposal: &Proposal,
+    config: &GovernanceConfig,
+    member_count: u32,
+) -> ProposalStatus {
+    if proposal.action.needs_unanimity() {
+        return if proposal.votes_against > 0 {
+            ProposalStatus::Rejected
+        } else if proposal.votes_for >= member_count {
+            ProposalStatus::Executed
+        } else {
+            ProposalStatus::Open
+        };
+    }
+
+    let cast = proposal.votes_for + proposal.votes_against;
+    let remaining = member_count.saturating_sub(cast);
+
+    let quorum_met = share_reaches(cast, member_count, config.quorum_bps, true);
+    if quorum_met
+        && share_reaches(
+            proposal.votes_for,
+            member_count,
+            config.threshold_bps,
+            false,
+        )
+    {
+        return ProposalStatus::Executed;
+    }
+    if !share_reaches(
+        proposal.votes_for + remaining,
+        member_count,
+        config.threshold_bps,
+        false,
+    ) {
+        return ProposalStatus::Rejected;
+    }
+    ProposalStatus::Open
+}
+
+/// Final outcome of a proposal once voting has closed, judged on the votes
+/// actually cast. A proposal needing unanimity fails unless every member
+/// voted for it.
+pub fn closed_outcome(
+    proposal: &Proposal,
+    config: &GovernanceConfig,
+    member_count: u32,
+) -> ProposalStatus {
+    let cast = proposal.votes_for + proposal.votes_against;
+    if proposal.action.needs_unanimity() {
+        return if proposal.votes_for >= member_count {
+            ProposalStatus::Executed
+        } else {
+            ProposalStatus::Rejected
+        };
+    }
+    if share_reaches(cast, member_count, config.quorum_bps, true)
+        && share_reaches(proposal.votes_for, cast, config.threshold_bps, false)
+    {
+        ProposalStatus::Executed
+    } else {
+        ProposalStatus::Rejected
+    }
+}
+
+/// End of the voting period for a proposal created at `created_at`.
+pub fn voting_ends_at(config: &GovernanceConfig, created_at: u64) -> u64 {
+    created_at.saturating_add(config.voting_period)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+
+    fn proposal(env: &Env, votes_for: u32, votes_against: u32) -> Proposal {
+        Proposal {
+            id: 0,
+            group_id: 1,
+            proposer: Address::generate(env),
+            action: ProposalAction::CancelGroup,
+            votes_for,
+            votes_against,
+            created_at: 0,
+            voting_ends_at: DEFAULT_VOTING_PERIOD,
+            status: ProposalStatus::Open,
+        }
+    }
+
+    #[test]
+    fn test_config_validation() {
+        assert!(GovernanceConfig::default_config().is_valid());
+
+        let mut config = GovernanceConfig::default_config();
+        config.quorum_bps = 0;
+        assert!(!config.is_valid());
+
+        let mut config = GovernanceConfig::default_config();
+        config.threshold_bps = BPS_DENOMINATOR;
+        assert!(!config.is_valid());
+
+        let mut config = GovernanceConfig::default_config();
+        config.voting_period = 0;
+        assert!(!config.is_valid());
+    }
+
+    #[test]
+    fn test_decided_outcome_needs_certain_majority() {
+        let env = Env::default();
+        let config = GovernanceConfig::default_config();
+
+        // 2 of 5 for: the other 3 could still defeat it
+        assert_eq!(
+            decided_outcome(&proposal(&env, 2, 0), &config, 5),
+            ProposalStatus::Open
+        );
+        // 3 of 5 for is a majority of the whole group
+        assert_eq!(
+            decided_outcome(&proposal(&env, 3, 0), &config, 5),
+            ProposalStatus::Executed
+        );
+        // 2 of 4 is not more than half
+        assert_eq!(
+            decided_outcome(&proposal(&env, 2, 2), &config, 4),
+            ProposalStatus::Rejected
+        );
+        assert_eq!(
+            decided_outcome(&proposal(&env, 1, 2), &config, 5),
+            ProposalStatus::Open
+        );
+        assert_eq!(
+            decided_outcome(&proposal(&env, 0, 3), &config, 5),
+            ProposalStatus::Rejected
+        );
+    }
+
+    #[test]
+    fn test_decided_outcome_respects_quorum() {
+        let env = Env::default();
+        let config = GovernanceConfig {
+            quorum_bps: 8_000,
+            threshold_bps: 2_000,
+            voting_period: DEFAULT_VOTING_PERIOD,
+        };
+
+        // 2 of 5 approve, which exceeds the threshold, but only 40% voted
+        assert_eq!(
+            decided_outcome(&proposal(&env, 2, 0), &config, 5),
+            ProposalStatus::Open
+        );
+        assert_eq!(
+            decided_outcome(&proposal(&env, 2, 2), &config, 5),
+            ProposalStatus::Executed
+        );
+    }
+
+    #[test]
+    fn test_closed_outcome_counts_votes_cast() {
+        let env = Env::default();
+        let config = GovernanceConfig::default_config();
+
+        // 3 of 6 voted, all in favour
+        assert_eq!(
+            closed_outcome(&proposal(&env, 3, 0), &config, 6),
+            ProposalStatus::Executed
+        );
+        // Below quorum
+        assert_eq!(
+            closed_outcome(&proposal(&env, 2, 0), &config, 6),
+            ProposalStatus::Rejected
+        );
+        // Tied
+        assert_eq!(
+            closed_outcome(&proposal(&env, 2, 2), &config, 6),
+            ProposalStatus::Rejected
+        );
+    }
+
+    #[test]
+    fn test_early_completion_needs_every_member() {
+        let env = Env::default();
+        let config = GovernanceConfig::default_config();
+        let unanimous = |votes_for, votes_against| Proposal {
+            action: ProposalAction::CompleteEarly,
+            ..proposal(&env, votes_for, votes_against)
+        };
+
+        // A majority is not enough
+        assert_eq!(
+            decided_outcome(&unanimous(4, 0), &config, 5),
+            ProposalStatus::Open
+        );
+        assert_eq!(
+            decided_outcome(&unanimous(5, 0), &config, 5),
+            ProposalStatus::Executed
+        );
+        assert_eq!(
+            decided_outcome(&unanimous(4, 1), &config, 5),
+            ProposalStatus::Rejected
+        );
+        assert_eq!(
+            closed_outcome(&unanimous(4, 0), &config, 5),
+            ProposalStatus::Rejected
+        );
+    }
+}
@@ -0,0 +1,68 @@
+//! Idempotency nonces for client-submitted operations.
+//!
+//! Wallets retry submissions whose outcome they never saw. `contribute` and
+//! `execute_payout` accept an optional nonce, chosen by the caller, which is
+//! recorded per group and address once the operation succeeds. Submitting
+//! the same nonce again fails with `DuplicateOperation` before any other
+//! check runs, so a retry can be told apart from a genuine error. A failed
+//! operation records nothing, and its nonce can be used again.
+
+use crate::error::StellarSaveError;
+use crate::storage::{set_persistent, StorageKeyBuilder};
+use soroban_sdk::{Address, Env};
+
+/// Records `nonce` as used by `caller` in the group. Does nothing without a
+/// nonce.
+///
+/// # Errors
+/// * `DuplicateOperation` - If `caller` already used the nonce in the group
+pub fn claim(
+    env: &Env,
+    group_id: u64,
+    caller: &Address,
+    nonce: Option<u64>,
+) -> Result<(), StellarSaveError> {
+    let Some(nonce) = nonce else {
+        return Ok(());
+    };
+    let key = StorageKeyBuilder::member_nonce(group_id, caller.clone(), nonce);
+    if env.storage().persistent().has(&key) {
+        return Err(StellarSaveError::DuplicateOperation);
+    }
+    set_persistent(env, &key, &env.ledger().timestamp());
+    Ok(())
+}
+
+/// Whether `caller` has used `nonce` in the group.
+pub fn is_used(env: &Env, group_id: u64, caller: Address, nonce: u64) -> bool {
+    env.storage()
+        .persistent()
+        .has(&StorageKeyBuilder::member_nonce(group_id, caller, nonce))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+
+    #[test]
+    fn test_nonce_is_claimed_once_per_caller() {
+        let env = Env::default();
+        let contract_id = crate::testutils::register(&env);
+        let caller = Address::generate(&env);
+        let other = Address::generate(&env);
+
+        env.as_contract(&contract_id, || {
+            assert_eq!(claim(&env, 1, &caller, None), Ok(()));
+            assert_eq!(claim(&env, 1, &caller, Some(7)), Ok(()));
+            assert_eq!(
+                claim(&env, 1, &caller, Some(7)),
+                Err(StellarSaveError::DuplicateOperation)
+            );
+            assert!(is_used(&env, 1, caller.clone(), 7));
+            // Nonces are scoped to the caller and the group
+            assert_eq!(claim(&env, 1, &other, Some(7)), Ok(()));
+            assert_eq!(claim(&env, 2, &caller, Some(7)), Ok(()));
+        });
+    }
+}
@@ -0,0 +1,177 @@
+//! Property tests for cycle and pool arithmetic.
+//!
+//! Whole rotations are run with random group sizes, contribution amounts and
+//! protocol fees, checking after each one that the contract never paid out
+//! more than it took in, that every member was paid exactly once and that the
+//! rotation lasted one cycle per member. The pool arithmetic is checked on its
+//! own across the full `i128` range, where it must report an overflow rather
+//! than panic or wrap.
+
+extern crate std;
+
+use crate::error::StellarSaveError;
+use crate::pool::{CyclePool, PoolCalculator, MAX_EMERGENCY_PENALTY_BPS, MAX_FEE_BPS};
+use crate::schedule;
+use crate::testutils::{self, TestGroupBuilder};
+use crate::units::{self, SECONDS_PER_DAY};
+use crate::PayoutOutcome;
+use proptest::prelude::*;
+use soroban_sdk::{Env, Vec};
+
+/// Largest group the rotation properties run; each case executes one
+/// transaction per member per cycle, so this bounds the test's run time.
+const MAX_ROTATION_MEMBERS: u32 = 8;
+
+/// Runs a full rotation, funding the contract with each cycle's pool as the
+/// contributions are recorded, and checks the rotation's invariants.
+fn run_rotation(size: u32, contribution: i128, fee_bps: u32) {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+    let group = TestGroupBuilder::new(&env)
+        .members(size)
+        .contribution(contribution)
+        .cycle_duration(SECONDS_PER_DAY)
+        .activated()
+        .build();
+    let client = group.client(&env);
+    client.mock_all_auths().set_fee_bps(&fee_bps);
+    let token = testutils::token(&env, &group.contract_id);
+
+    let mut contributed = 0i128;
+    let mut recipients = Vec::new(&env);
+    let mut cycles = 0u32;
+    while !client.is_complete(&group.group_id) {
+        assert!(cycles < size, "rotation outlasted its members");
+        testutils::contribute_all(&env, &group.contract_id, group.group_id);
+        let pool = contribution * size as i128;
+        testutils::mint(&env, &group.contract_id, &group.contract_id, pool);
+        contributed += pool;
+
+        testutils::advance_past_deadline(&env, &group.contract_id, group.group_id, 0);
+        let results = client.execute_payouts(&Vec::from_array(&env, [group.group_id]));
+        match results.get(0).unwrap().outcome {
+            PayoutOutcome::Paid(recipient, _) => {
+                assert!(!recipients.contains(&recipient), "member paid twice");
+                recipients.push_back(recipient);
+            }
+            PayoutOutcome::Skipped(code) => panic!("cycle {} skipped with {}", cycles, code),
+        }
+        cycles += 1;
+    }
+
+    // One cycle per member
+    assert_eq!(cycles, size);
+    assert_eq!(client.get_group(&group.group_id).current_cycle, size);
+
+    // Every member paid exactly once
+    assert_eq!(recipients.len(), size);
+    for member in group.members.iter() {
+        assert!(recipients.contains(&member));
+        assert!(client.has_received_payout(&group.group_id, &member));
+    }
+
+    // Payouts and fees together account for what came in, and the fees are
+    // still held by the contract
+    let paid_out = client.get_total_paid_out(&group.group_id);
+    let fees = client.get_treasury_balance();
+    assert!(paid_out <= contributed);
+    assert_eq!(paid_out + fees, contributed);
+    assert_eq!(token.balance(&group.contract_id), fees);
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(24))]
+
+    #[test]
+    fn prop_rotation_invariants(
+        size in 2..=MAX_ROTATION_MEMBERS,
+        contribution in 1..=units::xlm(1_000_000),
+        fee_bps in 0..=MAX_FEE_BPS,
+    ) {
+        run_rotation(size, contribution, fee_bps);
+    }
+}
+
+proptest! {
+    #[test]
+    fn prop_total_pool_reports_overflow(
+        contribution in 1..=i128::MAX,
+        members in 1..=u32::MAX,
+    ) {
+        let result = PoolCalculator::calculate_total_pool(contribution, members);
+        match contribution.checked_mul(members as i128) {
+            Some(pool) => prop_assert_eq!(result, Ok(pool)),
+            None => prop_assert_eq!(result, Err(StellarSaveError::InternalError)),
+        }
+    }
+
+    #[test]
+    fn prop_payout_and_fee_split_pool(pool in 0..=i128::MAX, fee_bps in 0..=MAX_FEE_BPS) {
+        match PoolCalculator::calculate_payout_amount(pool, fee_bps) {
+            Ok(net) => {
+                let fee = PoolCalculator::calculate_fee(pool, fee_bps).unwrap();
+                prop_assert_eq!(net + fee, pool);
+                prop_assert!(fee >= 0 && net >= 0);
+                prop_assert!(fee <= pool / 10);
+            }
+            Err(error) => {
+                prop_assert_eq!(error, StellarSaveError::Overflow);
+                prop_assert!(pool.checked_mul(fee_bps as i128).is_none());
+            }
+        }
+    }
+
+    #[test]
+    fn prop_penalty_never_exceeds_contributions(
+        contributed in 0..=i128::MAX,
+        penalty_bps in 0..=MAX_EMERGENCY_PENALTY_BPS,
+    ) {
+        if let Some(penalty) = units::bps_of(contributed, penalty_bps) {
+            let refund = contributed - penalty;
+            prop_assert!(penalty >= 0 && penalty <= contributed / 2);
+            prop_assert!(refund >= penalty);
+        }
+    }
+
+    #[test]
+    fn prop_cycle_pool_stays_consistent(
+        contribution in 1..=i128::MAX,
+        contributors in 0..=MAX_ROTATION_MEMBERS * 4,
+    ) {
+        let mut pool = CyclePool::default();
+        let mut overflowed = false;
+        for _ in 0..contributors {
+            if pool.add(contribution).is_err() {
+                overflowed = true;
+                break;
+            }
+        }
+        if overflowed {
+            prop_assert!(contribution.checked_mul(contributors as i128).is_none());
+        } else {
+            prop_assert!(pool.is_consistent(contribution, contributors));
+            prop_assert_eq!(pool.total, contribution * contributors as i128);
+        }
+    }
+
+    #[test]
+    fn prop_schedule_never_wraps(
+        started_at in any::<u64>(),
+        cycle_duration in 1..=u64::MAX,
+        cycle in any::<u32>(),
+    ) {
+        let start = schedule::cycle_start_at(started_at, cycle_duration, cycle);
+        let deadline = schedule::deadline_at(started_at, cycle_duration, cycle);
+        if let Some(deadline) = deadline {
+            let start = start.unwrap();
+            prop_assert!(start >= started_at);
+            prop_assert_eq!(deadline - start, cycle_duration);
+        }
+        if let Some(start) = start {
+            prop_assert_eq!(
+                deadline.is_some(),
+                start.checked_add(cycle_duration).is_some()
+            );
+        }
+    }
+}
@@ -0,0 +1,61 @@
+//! Group observers.
+//!
+//! NGOs and cooperatives running a group often need an auditor who watches
+//! it without taking part. Observers are recorded on-chain for transparency
+//! and can open disputes on the members' behalf, but they never contribute,
+//! never count toward `member_count` and are never paid out.
+
+use crate::storage::{set_persistent, StorageKeyBuilder};
+use soroban_sdk::{Address, Env, Vec};
+
+/// Maximum number of observers a group can have.
+pub const MAX_OBSERVERS: u32 = 10;
+
+/// Returns the observers of a group, in the order they were invited.
+pub fn load(env: &Env, group_id: u64) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&StorageKeyBuilder::group_observers(group_id))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Whether `address` observes the group.
+pub fn is_observer(env: &Env, group_id: u64, address: &Address) -> bool {
+    load(env, group_id).contains(address)
+}
+
+/// Stores a group's observers, removing the entry once none are left.
+pub fn store(env: &Env, group_id: u64, observers: &Vec<Address>) {
+    let key = StorageKeyBuilder::group_observers(group_id);
+    if observers.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        set_persistent(env, &key, observers);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+
+    #[test]
+    fn test_store_and_load_observers() {
+        let env = Env::default();
+        let contract_id = crate::testutils::register(&env);
+        let observer = Address::generate(&env);
+
+        env.as_contract(&contract_id, || {
+            assert!(!is_observer(&env, 1, &observer));
+            store(&env, 1, &soroban_sdk::vec![&env, observer.clone()]);
+            assert!(is_observer(&env, 1, &observer));
+            assert!(!is_observer(&env, 2, &observer));
+
+            store(&env, 1, &Vec::new(&env));
+            assert!(!env
+                .storage()
+                .persistent()
+                .has(&StorageKeyBuilder::group_observers(1)));
+        });
+    }
+}
@@ -0,0 +1,221 @@
+//! Contributions priced in fiat.
+//!
+//! A group can define its contribution as a fiat amount, such as ten dollars
+//! a week, instead of a token amount. The token amount is then set once per
+//! cycle from a price oracle with a Reflector-style interface, before the
+//! cycle's first contribution, and kept as that cycle's price snapshot so
+//! every member pays the same and the pool stays consistent. A price that is
+//! stale, or that moved further from the previous cycle's than the group
+//! allows, is refused rather than used.
+
+use crate::error::StellarSaveError;
+use crate::events::EventEmitter;
+use crate::storage::{set_persistent, StorageKeyBuilder};
+use crate::units::BPS_DENOMINATOR;
+use soroban_sdk::{contractclient, contracttype, Address, Env, Symbol};
+
+/// Asset identifier used by the oracle.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Asset {
+    /// A Stellar asset, identified by its token contract.
+    Stellar(Address),
+    /// Any other asset, identified by its ticker.
+    Other(Symbol),
+}
+
+/// A price as reported by the oracle.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PriceData {
+    /// Price of one whole asset unit in the quote currency, scaled by the
+    /// oracle's `decimals`
+    pub price: i128,
+    /// When the price was recorded
+    pub timestamp: u64,
+}
+
+/// Interface a price oracle contract must implement.
+#[contractclient(name = "PriceOracleClient")]
+pub trait PriceOracle {
+    /// Number of decimals prices are scaled by.
+    fn decimals(env: Env) -> u32;
+
+    /// Most recent price of `asset`, if the oracle has one.
+    fn lastprice(env: Env, asset: Asset) -> Option<PriceData>;
+}
+
+/// How a group's contribution is priced in fiat.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FiatPricing {
+    /// Oracle quoting the contribution token in the fiat currency
+    pub oracle: Address,
+    /// The contribution token as the oracle identifies it
+    pub asset: Asset,
+    /// Contribution per cycle in the fiat currency, scaled by the oracle's
+    /// `decimals` like its prices
+    pub fiat_amount: i128,
+    /// Oldest price, in seconds, that may be used to price a cycle
+    pub max_price_age: u64,
+    /// Largest move, in basis points, from the previous cycle's price
+    pub max_deviation_bps: u32,
+}
+
+impl FiatPricing {
+    /// Whether the settings are usable: a positive amount, a non-zero price
+    /// age and a deviation bound of at most 100%.
+    pub fn is_valid(&self) -> bool {
+        self.fiat_amount > 0 && self.max_price_age > 0 && self.max_deviation_bps <= BPS_DENOMINATOR
+    }
+}
+
+/// The price a cycle's contribution was set from.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PriceSnapshot {
+    /// Oracle price used, scaled by the oracle's `decimals`
+    pub price: i128,
+    /// When the oracle recorded the price
+    pub price_timestamp: u64,
+    /// Contribution for the cycle in the token's smallest unit
+    pub amount: i128,
+    /// When the snapshot was taken
+    pub taken_at: u64,
+}
+
+/// Returns the group's fiat pricing, or `None` for a fixed token amount.
+pub fn load_pricing(env: &Env, group_id: u64) -> Option<FiatPricing> {
+    env.storage()
+        .persistent()
+        .get(&StorageKeyBuilder::group_fiat_pricing(group_id))
+}
+
+/// Returns the price snapshot taken for a cycle, if any.
+pub fn load_snapshot(env: &Env, group_id: u64, cycle: u32) -> Option<PriceSnapshot> {
+    env.storage()
+        .persistent()
+        .get(&StorageKeyBuilder::group_price_snapshot(group_id, cycle))
+}
+
+/// Token amount worth `fiat_amount` at `price`, for a token with
+/// `token_decimals` decimals. `fiat_amount` and `price` must share a scale.
+///
+/// Returns `None` on overflow or a non-positive price.
+pub fn token_amount(fiat_amount: i128, price: i128, token_decimals: u32) -> Option<i128> {
+    if price <= 0 {
+        return None;
+    }
+    10i128
+        .checked_pow(token_decimals)
+        .and_then(|unit| fiat_amount.checked_mul(unit))
+        .map(|scaled| scaled / price)
+}
+
+/// Whether `price` is within `max_bps` of `previous`.
+pub fn within_deviation(previous: i128, price: i128, max_bps: u32) -> bool {
+    let moved = (price - previous).unsigned_abs();
+    let allowed = previous.unsigned_abs() * max_bps as u128;
+    moved * BPS_DENOMINATOR as u128 <= allowed
+}
+
+/// Prices a cycle from the oracle without storing anything.
+///
+/// # Errors
+/// - `PriceUnavailable` - The oracle failed, has no price, or its price is
+///   older than `max_price_age` or prices the contribution at nothing
+/// - `PriceSlippageExceeded` - The price moved further from the previous
+///   cycle's than `max_deviation_bps`
+pub(crate) fn price_cycle(
+    env: &Env,
+    group_id: u64,
+    cycle: u32,
+    pricing: &FiatPricing,
+    token_decimals: u32,
+) -> Result<PriceSnapshot, StellarSaveError> {
+    let quote = PriceOracleClient::new(env, &pricing.oracle)
+        .try_lastprice(&pricing.asset)
+        .ok()
+        .and_then(|result| result.ok())
+        .flatten()
+        .ok_or(StellarSaveError::PriceUnavailable)?;
+
+    let now = env.ledger().timestamp();
+    if quote.price <= 0 || now.saturating_sub(quote.timestamp) > pricing.max_price_age {
+        return Err(StellarSaveError::PriceUnavailable);
+    }
+    let previous = cycle
+        .checked_sub(1)
+        .and_then(|previous| load_snapshot(env, group_id, previous));
+    if let Some(previous) = previous {
+        if !within_deviation(previous.price, quote.price, pricing.max_deviation_bps) {
+            return Err(StellarSaveError::PriceSlippageExceeded);
+        }
+    }
+
+    let amount = token_amount(pricing.fiat_amount, quote.price, token_decimals)
+        .filter(|amount| *amount > 0)
+        .ok_or(StellarSaveError::PriceUnavailable)?;
+    Ok(PriceSnapshot {
+        price: quote.price,
+        price_timestamp: quote.timestamp,
+        amount,
+        taken_at: now,
+    })
+}
+
+/// Prices a cycle from the oracle and stores the snapshot.
+///
+/// # Errors
+/// Same as `price_cycle`.
+pub(crate) fn take_snapshot(
+    env: &Env,
+    group_id: u64,
+    cycle: u32,
+    pricing: &FiatPricing,
+    token_decimals: u32,
+) -> Result<PriceSnapshot, StellarSaveError> {
+    let snapshot = price_cycle(env, group_id, cycle, pricing, token_decimals)?;
+    set_persistent(
+        env,
+        &StorageKeyBuilder::group_price_snapshot(group_id, cycle),
+        &snapshot,
+    );
+
+    EventEmitter::emit_cycle_priced(
+        env,
+        group_id,
+        cycle,
+        snapshot.price,
+        snapshot.amount,
+        snapshot.taken_at,
+    );
+    Ok(snapshot)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_token_amount() {
+        // $10 at $0.25 per token, oracle and token both at 7 decimals
+        assert_eq!(token_amount(100_000_000, 2_500_000, 7), Some(400_000_000));
+        // $10 at $2 per token with a 14-decimal oracle
+        assert_eq!(
+            token_amount(10 * 10i128.pow(14), 2 * 10i128.pow(14), 7),
+            Some(50_000_000)
+        );
+        assert_eq!(token_amount(100, 0, 7), None);
+        assert_eq!(token_amount(i128::MAX, 1, 7), None);
+    }
+
+    #[test]
+    fn test_within_deviation() {
+        assert!(within_deviation(1_000, 1_050, 500));
+        assert!(within_deviation(1_000, 950, 500));
+        assert!(!within_deviation(1_000, 1_051, 500));
+        assert!(!within_deviation(1_000, 949, 500));
+        assert!(within_deviation(1_000, 1_000, 0));
+    }
+}
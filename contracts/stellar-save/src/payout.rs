@@ -1,3 +1,4 @@
+use crate::units;
 use soroban_sdk::{contracttype, Address};
 
 /// Payout Record structure for tracking payout events in rotational savings groups.
@@ -28,6 +29,11 @@ pub struct PayoutRecord {
     /// Timestamp when the payout was executed (Unix timestamp in seconds).
     /// Used for tracking payout timing and audit purposes.
     pub timestamp: u64,
+
+    /// Compensation paid on top of `amount` because the payout was executed
+    /// after it fell due, drawn from the group's insurance reserve.
+    /// 0 for a payout made on time or in a group that pays no compensation.
+    pub compensation: i128,
 }
 
 impl PayoutRecord {
@@ -59,6 +65,7 @@ impl PayoutRecord {
             cycle_number,
             amount,
             timestamp,
+            compensation: 0,
         }
     }
 
@@ -96,13 +103,14 @@ impl PayoutRecord {
     /// Returns the payout amount in XLM (converted from stroops).
     /// Note: This is a helper for display purposes; actual amount is in stroops.
     pub fn amount_in_xlm(&self) -> i128 {
-        self.amount / 10_000_000
+        units::stroops_to_xlm(self.amount)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::units::SECONDS_PER_WEEK;
     use soroban_sdk::{testutils::Address as _, Address, Env};
 
     #[test]
@@ -123,6 +131,7 @@ mod tests {
         assert_eq!(payout.cycle_number, 0);
         assert_eq!(payout.amount, 50_000_000);
         assert_eq!(payout.timestamp, 1234567890);
+        assert_eq!(payout.compensation, 0);
     }
 
     #[test]
@@ -206,7 +215,7 @@ mod tests {
             1,
             1,
             50_000_000,
-            1234567890 + 604800, // 1 week later
+            1234567890 + SECONDS_PER_WEEK, // 1 week later
         );
 
         assert_eq!(payout1.group_id, payout2.group_id);
@@ -222,8 +231,13 @@ mod tests {
 
         let payout_cycle_0 = PayoutRecord::new(recipient.clone(), 1, 0, 50_000_000, 1234567890);
 
-        let payout_cycle_1 =
-            PayoutRecord::new(recipient.clone(), 1, 1, 50_000_000, 1234567890 + 604800);
+        let payout_cycle_1 = PayoutRecord::new(
+            recipient.clone(),
+            1,
+            1,
+            50_000_000,
+            1234567890 + SECONDS_PER_WEEK,
+        );
 
         assert_eq!(payout_cycle_0.group_id, payout_cycle_1.group_id);
         assert_eq!(payout_cycle_0.recipient, payout_cycle_1.recipient);
@@ -17,65 +17,330 @@
 //! The design follows a permissionless execution model where any address can trigger
 //! payout execution once preconditions are met.
 
+use crate::approval;
+use crate::contribution::ContributionRecord;
+use crate::dispute;
+use crate::diagnostics;
+use crate::escrow;
 use crate::error::StellarSaveError;
 use crate::events::EventEmitter;
 use crate::group::{Group, GroupStatus};
 use crate::payout::PayoutRecord;
-use crate::pool::PoolCalculator;
-use crate::storage::StorageKeyBuilder;
-use crate::MemberProfile;
-use soroban_sdk::{Address, Env};
+use crate::payout_hook;
+use crate::payout_order;
+use crate::pool::{Allocation, CyclePool, InsuranceSettlement, PoolCalculator, RoundingPolicy};
+use crate::reason::ActionNote;
+use crate::recovery;
+use crate::savings;
+use crate::schedule;
+use crate::stats;
+use crate::status;
+use crate::storage::{set_persistent, GroupStore, MemberStore, StorageKeyBuilder};
+use crate::yield_strategy;
+use crate::{MemberProfile, PayoutBlocker, PayoutQuote, StellarSaveContract};
+use soroban_sdk::token::TokenClient;
+use soroban_sdk::{Address, Env, Vec};
 
 /// Validates that the current cycle is complete and ready for payout.
 ///
 /// This function verifies that all members have contributed to the current cycle
 /// and that the total contributions equal the expected pool amount.
 ///
+/// Insured groups may also pay out a short pool once the cycle's grace window
+/// has closed: the group's insurance reserve covers the missing contributions,
+/// provided it holds enough to do so. Failing that, a group with a best-effort
+/// payout policy pays out what was collected once enough members contributed.
+///
 /// # Arguments
 /// * `env` - Soroban environment for storage access
-/// * `group_id` - Unique identifier of the group
+/// * `group` - The group being paid out
 /// * `current_cycle` - The current cycle number to validate
 ///
 /// # Returns
-/// * `Ok(PoolInfo)` - Pool information if cycle is complete and valid
+/// * `Ok((PoolInfo, InsuranceSettlement))` - Pool information and how it is
+///   settled against the insurance reserve
 /// * `Err(StellarSaveError)` - If cycle is not complete or validation fails
 ///
 /// # Errors
-/// - `CycleNotComplete` - Not all members have contributed
+/// - `CycleNotComplete` - Not all members have contributed, and neither the
+///   reserve nor the payout policy allows (or yet allows) the cycle to pay out
 /// - `InvalidAmount` - Contribution totals don't match expected pool
 /// - `GroupNotFound` - Group does not exist
 /// - `InvalidState` - Invalid group configuration
+/// - `DataCorruption` - The cycle's running totals disagree with the
+///   contribution amount or exceed the member count
 ///
 /// # Requirements
 /// Validates Requirements 1.1, 1.2, 1.3, 1.4, 1.5
 fn validate_cycle_complete(
     env: &Env,
-    group_id: u64,
+    group: &Group,
     current_cycle: u32,
-) -> Result<crate::pool::PoolInfo, StellarSaveError> {
+) -> Result<(crate::pool::PoolInfo, InsuranceSettlement), StellarSaveError> {
     // Call PoolCalculator to retrieve comprehensive cycle data
-    let pool_info = PoolCalculator::get_pool_info(env, group_id, current_cycle)?;
+    let pool_info = PoolCalculator::get_pool_info(env, group.id, current_cycle)?;
+
+    // The running totals must agree with the group's terms; anything else
+    // means they were written outside record_contribution
+    if !pool_info
+        .cycle_pool()
+        .is_consistent(pool_info.contribution_amount, pool_info.member_count)
+    {
+        return Err(StellarSaveError::DataCorruption);
+    }
+    let reserve = load_insurance_reserve(env, group.id);
+
+    // A defaulted cycle is covered by the reserve of an insured group, or
+    // paid out short under a best-effort policy, but only once late
+    // contributions are no longer accepted
+    let quorum = group.payout_policy.quorum(pool_info.member_count);
+    if !pool_info.is_cycle_complete && (group.insurance_bps > 0 || quorum.is_some()) {
+        let grace_deadline =
+            schedule::grace_deadline(group, current_cycle).ok_or(StellarSaveError::Overflow)?;
+        if env.ledger().timestamp() <= grace_deadline {
+            return Err(diagnostics::report(
+                env,
+                StellarSaveError::CycleNotComplete,
+                group.id,
+                current_cycle,
+                grace_deadline as i128,
+            ));
+        }
+        if group.insurance_bps > 0 {
+            match PoolCalculator::settle_insurance(&pool_info, group.insurance_bps, reserve) {
+                Ok(settlement) => return Ok((pool_info, settlement)),
+                Err(StellarSaveError::CycleNotComplete) => {}
+                Err(error) => return Err(error),
+            }
+        }
+        if quorum.is_some_and(|quorum| pool_info.contributors_count >= quorum) {
+            let settlement = PoolCalculator::settle_partial(&pool_info, group.insurance_bps)?;
+            return Ok((pool_info, settlement));
+        }
+        return Err(diagnostics::report(
+            env,
+            StellarSaveError::CycleNotComplete,
+            group.id,
+            current_cycle,
+            pool_info.contributors_count as i128,
+        ));
+    }
 
     // Validate that the pool is ready for payout
     // This checks:
     // - All members have contributed (contributors_count >= member_count)
     // - Total contributions equal expected pool amount
-    PoolCalculator::validate_pool_ready_for_payout(&pool_info)?;
+    PoolCalculator::validate_pool_ready_for_payout(&pool_info).map_err(|error| {
+        let value = match error {
+            StellarSaveError::CycleNotComplete => pool_info.contributors_count as i128,
+            _ => pool_info.current_contributions,
+        };
+        diagnostics::report(env, error, group.id, current_cycle, value)
+    })?;
 
     // Return pool info for use in subsequent payout calculations
-    Ok(pool_info)
+    let settlement = PoolCalculator::settle_insurance(&pool_info, group.insurance_bps, reserve)?;
+    Ok((pool_info, settlement))
+}
+
+/// Reads a group's insurance reserve balance.
+pub(crate) fn load_insurance_reserve(env: &Env, group_id: u64) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&StorageKeyBuilder::group_insurance_reserve(group_id))
+        .unwrap_or(0)
 }
 
-/// Identifies the member who should receive the payout for the current cycle.
+/// Credits a cycle's insurance premium to the group's reserve and draws the
+/// coverage for any missing contributions, recording a default against each
+/// member whose contribution was covered.
 ///
-/// This function iterates through all members in the group to find the member
-/// whose payout_position matches the current cycle number. It verifies that
-/// exactly one member has the matching position.
+/// Does nothing for uninsured groups, so they produce no reserve entries or
+/// events.
+pub(crate) fn apply_insurance_settlement(
+    env: &Env,
+    group_id: u64,
+    cycle: u32,
+    settlement: &InsuranceSettlement,
+    timestamp: u64,
+) -> Result<(), StellarSaveError> {
+    if settlement.premium == 0 && settlement.coverage == 0 {
+        return Ok(());
+    }
+
+    let reserve = settlement.reserve_after(load_insurance_reserve(env, group_id))?;
+    set_persistent(
+        env,
+        &StorageKeyBuilder::group_insurance_reserve(group_id),
+        &reserve,
+    );
+    if settlement.coverage > 0 {
+        let group = StellarSaveContract::get_group(env.clone(), group_id)?;
+        StellarSaveContract::record_missed_contributions(env, &group, cycle)?;
+    }
+
+    EventEmitter::emit_insurance_settled(
+        env,
+        group_id,
+        cycle,
+        settlement.premium,
+        settlement.coverage,
+        reserve,
+        timestamp,
+    );
+    Ok(())
+}
+
+/// Reads the compensation a group pays on late payouts, in basis points of
+/// the payout per day; 0 unless the admin has set one.
+pub(crate) fn load_late_compensation_bps(env: &Env, group_id: u64) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&StorageKeyBuilder::group_late_payout_compensation_bps(
+            group_id,
+        ))
+        .unwrap_or(0)
+}
+
+/// Reads where a group's payout remainders go; the treasury unless the
+/// admin has chosen otherwise.
+pub(crate) fn load_rounding_policy(env: &Env, group_id: u64) -> RoundingPolicy {
+    env.storage()
+        .persistent()
+        .get(&StorageKeyBuilder::group_rounding_policy(group_id))
+        .unwrap_or(RoundingPolicy::RemainderToTreasury)
+}
+
+/// Reads the payout remainder a group carried from its last cycle.
+pub(crate) fn load_carried_remainder(env: &Env, group_id: u64) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&StorageKeyBuilder::group_carried_remainder(group_id))
+        .unwrap_or(0)
+}
+
+/// Draws the compensation paid to each of a cycle's recipients from the
+/// group's insurance reserve, announcing each payment.
+fn draw_late_compensation(
+    env: &Env,
+    group: &Group,
+    recipients: &Vec<Address>,
+    compensations: &Vec<i128>,
+    delay_secs: u64,
+    timestamp: u64,
+) {
+    let total: i128 = compensations.iter().sum();
+    if total == 0 {
+        return;
+    }
+
+    let reserve = load_insurance_reserve(env, group.id);
+    set_persistent(
+        env,
+        &StorageKeyBuilder::group_insurance_reserve(group.id),
+        &(reserve - total),
+    );
+    for (recipient, compensation) in recipients.iter().zip(compensations.iter()) {
+        if compensation > 0 {
+            EventEmitter::emit_late_payout_compensated(
+                env,
+                group.id,
+                group.current_cycle,
+                recipient,
+                delay_secs,
+                compensation,
+                timestamp,
+            );
+        }
+    }
+}
+
+/// When a cycle's payout fell due: the cycle's contribution deadline, or the
+/// last contribution if it came in after that. A cycle that pays out without
+/// every contribution cannot do so before its grace window closes, so it
+/// falls due then. Returns `None` on overflow.
+fn payout_due_at(env: &Env, group: &Group, cycle: u32) -> Option<u64> {
+    let owed = group
+        .member_count
+        .saturating_sub(PoolCalculator::get_exempt_count(env, group, cycle));
+    if CyclePool::load(env, group.id, cycle).contributors < owed {
+        return schedule::grace_deadline(group, cycle);
+    }
+
+    let mut due_at = schedule::contribution_deadline(group, cycle)?;
+    for contributor in StellarSaveContract::load_cycle_contributors(env, group.id, cycle).iter() {
+        let key = StorageKeyBuilder::contribution_individual(group.id, cycle, contributor);
+        if let Some(record) = env
+            .storage()
+            .persistent()
+            .get::<_, ContributionRecord>(&key)
+        {
+            due_at = due_at.max(record.timestamp);
+        }
+    }
+    Some(due_at)
+}
+
+/// How long past due a payout executed at `timestamp` is, for a group
+/// compensating late payouts at `compensation_bps`; 0 for any other group.
+fn payout_delay(env: &Env, group: &Group, compensation_bps: u32, timestamp: u64) -> u64 {
+    match compensation_bps {
+        0 => 0,
+        _ => payout_due_at(env, group, group.current_cycle)
+            .map_or(0, |due_at| timestamp.saturating_sub(due_at)),
+    }
+}
+
+/// Charges the contributions missing from a partially paid cycle to the
+/// members who defaulted on it, recording a default against each.
+///
+/// Does nothing for a cycle that paid out in full or was covered.
+pub(crate) fn record_shortfall(
+    env: &Env,
+    group: &Group,
+    cycle: u32,
+    shortfall: i128,
+    timestamp: u64,
+) -> Result<(), StellarSaveError> {
+    if shortfall == 0 {
+        return Ok(());
+    }
+
+    let defaulters = StellarSaveContract::record_missed_contributions(env, group, cycle)?;
+    for member in defaulters.iter() {
+        let key = StorageKeyBuilder::member_shortfall(group.id, member);
+        let owed = env
+            .storage()
+            .persistent()
+            .get::<_, i128>(&key)
+            .unwrap_or(0)
+            .checked_add(group.contribution_amount)
+            .ok_or(StellarSaveError::Overflow)?;
+        set_persistent(env, &key, &owed);
+    }
+
+    EventEmitter::emit_cycle_shortfall(env, group.id, cycle, shortfall, defaulters, timestamp);
+    Ok(())
+}
+
+/// Identifies the member who should receive the payout for a payout position.
+///
+/// A cycle pays the positions `Group::cycle_positions` returns: just the
+/// position numbered after the cycle, unless the group pays several members
+/// per cycle.
+///
+/// Groups with a dynamic payout order (random, need-based or auction) have
+/// the recipient chosen by their strategy among the members still owed a
+/// payout. For the others, the recipient is read from the group's position → address index with a
+/// single lookup, and the member's own profile must agree with the index.
+/// Groups whose members joined before the index existed fall back to
+/// scanning all members for the one whose payout_position matches,
+/// verifying that exactly one member has it.
 ///
 /// # Arguments
 /// * `env` - Soroban environment for storage access
 /// * `group_id` - Unique identifier of the group
-/// * `current_cycle` - The current cycle number to match against payout positions
+/// * `position` - The payout position to find the holder of
 /// * `member_count` - Total number of members in the group (for validation)
 ///
 /// # Returns
@@ -83,16 +348,44 @@ fn validate_cycle_complete(
 /// * `Err(StellarSaveError)` - If no member found or multiple members with same position
 ///
 /// # Errors
-/// - `InvalidState` - No member found with matching payout position
-/// - `InvalidState` - Multiple members have the same payout position
+/// - `NoRecipientForCycle` - No member found with matching payout position, or
+///   no unpaid member left under a dynamic payout order
+/// - `DuplicatePayoutPosition` - Multiple members have the same payout position
+/// - `MemberCountMismatch` - The member list disagrees with the group's count
+/// - `InvalidState` - The indexed member's profile disagrees with the index
 /// - `GroupNotFound` - Group members list not found in storage
 ///
 /// # Requirements
 /// Validates Requirements 2.1, 2.2, 2.5
-fn identify_recipient(
+pub(crate) fn identify_recipient(
     env: &Env,
     group_id: u64,
-    current_cycle: u32,
+    position: u32,
+    member_count: u32,
+) -> Result<Address, StellarSaveError> {
+    // Dynamic payout orders pick the recipient among the unpaid members
+    if let Some(recipient) = payout_order::select_recipient(env, group_id, position)? {
+        return Ok(recipient);
+    }
+
+    let index_key = StorageKeyBuilder::group_position_member(group_id, position);
+    if let Some(recipient) = env.storage().persistent().get::<_, Address>(&index_key) {
+        let profile: MemberProfile =
+            MemberStore::load(env, group_id, &recipient).ok_or(StellarSaveError::InvalidState)?;
+        if profile.payout_position != position {
+            return Err(StellarSaveError::InvalidState);
+        }
+        return Ok(recipient);
+    }
+
+    scan_for_recipient(env, group_id, position, member_count)
+}
+
+/// Finds the holder of a payout position by checking every member's profile.
+fn scan_for_recipient(
+    env: &Env,
+    group_id: u64,
+    position: u32,
     member_count: u32,
 ) -> Result<Address, StellarSaveError> {
     // Load the list of all members in the group
@@ -105,7 +398,7 @@ fn identify_recipient(
 
     // Validate that we have the expected number of members
     if members.len() != member_count {
-        return Err(StellarSaveError::InvalidState);
+        return Err(StellarSaveError::MemberCountMismatch);
     }
 
     // Track the recipient and count of matches
@@ -115,15 +408,11 @@ fn identify_recipient(
     // Iterate through all members to find the one with matching payout_position
     for member_address in members.iter() {
         // Load the member's profile
-        let profile_key = StorageKeyBuilder::member_profile(group_id, member_address.clone());
-        let profile: MemberProfile = env
-            .storage()
-            .persistent()
-            .get(&profile_key)
+        let profile: MemberProfile = MemberStore::load(env, group_id, &member_address)
             .ok_or(StellarSaveError::InvalidState)?;
 
-        // Check if this member's payout position matches the current cycle
-        if profile.payout_position == current_cycle {
+        // Check if this member holds the position
+        if profile.payout_position == position {
             recipient = Some(member_address);
             match_count += 1;
         }
@@ -133,15 +422,15 @@ fn identify_recipient(
     match match_count {
         0 => {
             // No member found with matching payout position
-            Err(StellarSaveError::InvalidState)
+            Err(StellarSaveError::NoRecipientForCycle)
         }
         1 => {
             // Exactly one member found - return their address
             Ok(recipient.unwrap())
         }
         _ => {
-            // Multiple members have the same payout position - invalid state
-            Err(StellarSaveError::InvalidState)
+            // Multiple members have the same payout position
+            Err(StellarSaveError::DuplicatePayoutPosition)
         }
     }
 }
@@ -152,15 +441,14 @@ fn identify_recipient(
 /// 1. Verifies the recipient is a current member of the group
 /// 2. Ensures the recipient has not already received a payout in this group
 ///
-/// The function checks all cycles from 0 to the current cycle to determine if
-/// the recipient has already received a payout. This ensures that each member
-/// receives exactly one payout per group lifecycle.
+/// Both checks come from a single read of the recipient's profile, which the
+/// payout path flags once paid. This ensures that each member receives exactly one payout per
+/// group lifecycle without walking the payout history.
 ///
 /// # Arguments
 /// * `env` - Soroban environment for storage access
 /// * `group_id` - Unique identifier of the group
 /// * `recipient` - Address of the recipient to verify
-/// * `current_cycle` - The current cycle number (used to check payout history)
 ///
 /// # Returns
 /// * `Ok(())` - Recipient is eligible to receive the payout
@@ -176,27 +464,15 @@ fn verify_recipient_eligibility(
     env: &Env,
     group_id: u64,
     recipient: &Address,
-    current_cycle: u32,
 ) -> Result<(), StellarSaveError> {
     // Check 1: Verify recipient is a current member of the group
-    let member_key = StorageKeyBuilder::member_profile(group_id, recipient.clone());
-    if !env.storage().persistent().has(&member_key) {
-        return Err(StellarSaveError::NotMember);
-    }
+    let profile: MemberProfile =
+        MemberStore::load(env, group_id, recipient).ok_or(StellarSaveError::NotMember)?;
 
     // Check 2: Verify recipient has not already received a payout
-    // We need to check all cycles from 0 to current_cycle to see if this
-    // recipient has already received a payout in any previous cycle
-    for cycle in 0..=current_cycle {
-        let recipient_key = StorageKeyBuilder::payout_recipient(group_id, cycle);
-        
-        // Check if a payout recipient exists for this cycle
-        if let Some(past_recipient) = env.storage().persistent().get::<_, Address>(&recipient_key) {
-            // If the past recipient matches our current recipient, they've already received a payout
-            if past_recipient == *recipient {
-                return Err(StellarSaveError::InvalidRecipient);
-            }
-        }
+    // The payout path flags each recipient's profile once paid
+    if profile.has_received_payout {
+        return Err(StellarSaveError::InvalidRecipient);
     }
 
     // All checks passed - recipient is eligible
@@ -206,14 +482,15 @@ fn verify_recipient_eligibility(
 /// Calculates the payout amount from the pool total.
 ///
 /// This function uses the PoolCalculator to determine the net payout amount
-/// by subtracting any applicable fees from the total pool. In v1, fees are
-/// set to zero, so the payout amount equals the pool total.
+/// by subtracting the protocol fee from the total pool. With a fee of 0 bps
+/// the payout amount equals the pool total.
 ///
 /// The function validates that the calculated amount is greater than zero
 /// before returning it.
 ///
 /// # Arguments
-/// * `pool_info` - Pool information containing the total pool amount
+/// * `payout_base` - The pool total, net of any insurance premium
+/// * `fee_bps` - Protocol fee in basis points
 ///
 /// # Returns
 /// * `Ok(i128)` - The calculated net payout amount
@@ -226,11 +503,11 @@ fn verify_recipient_eligibility(
 /// # Requirements
 /// Validates Requirements 3.1, 3.2, 3.3, 3.4
 fn calculate_and_validate_payout_amount(
-    pool_info: &crate::pool::PoolInfo,
+    payout_base: i128,
+    fee_bps: u32,
 ) -> Result<i128, StellarSaveError> {
     // Use PoolCalculator to calculate net payout (pool - fees)
-    // In v1, fees are 0, so this returns the total pool amount
-    let payout_amount = PoolCalculator::calculate_payout_amount(pool_info.total_pool_amount)?;
+    let payout_amount = PoolCalculator::calculate_payout_amount(payout_base, fee_bps)?;
 
     // Verify the calculated amount is greater than zero
     // This check is critical to prevent invalid payouts
@@ -242,6 +519,39 @@ fn calculate_and_validate_payout_amount(
     Ok(payout_amount)
 }
 
+/// Credits the protocol fee withheld from a payout to the treasury.
+///
+/// Does nothing when the fee is zero, so groups on a fee-free contract
+/// produce no treasury events.
+pub(crate) fn collect_protocol_fee(
+    env: &Env,
+    group_id: u64,
+    cycle: u32,
+    fee: i128,
+    timestamp: u64,
+) -> Result<(), StellarSaveError> {
+    if fee <= 0 {
+        return Ok(());
+    }
+
+    let treasury_key = StorageKeyBuilder::treasury_balance();
+    let balance: i128 = env.storage().persistent().get(&treasury_key).unwrap_or(0);
+    let new_balance = balance
+        .checked_add(fee)
+        .ok_or(StellarSaveError::Overflow)?;
+    set_persistent(env, &treasury_key, &new_balance);
+
+    let group_fees_key = StorageKeyBuilder::group_fees_collected(group_id);
+    let group_fees: i128 = env.storage().persistent().get(&group_fees_key).unwrap_or(0);
+    let new_group_fees = group_fees
+        .checked_add(fee)
+        .ok_or(StellarSaveError::Overflow)?;
+    set_persistent(env, &group_fees_key, &new_group_fees);
+
+    EventEmitter::emit_protocol_fee_collected(env, group_id, cycle, fee, timestamp);
+    Ok(())
+}
+
 /// Verifies that the contract has sufficient balance to cover the payout amount.
 ///
 /// This function queries the contract's current balance and ensures it is greater
@@ -260,11 +570,9 @@ fn calculate_and_validate_payout_amount(
 /// - `PayoutFailed` - Contract balance is less than the payout amount
 ///
 /// # Note
-/// This function uses a placeholder balance query. In production, it would:
-/// 1. Get the native token contract address
-/// 2. Create a token client for the native asset
-/// 3. Query the balance for this contract's address
-/// 4. Compare balance >= payout_amount
+/// Funds are held in the contribution token set with `set_contribution_token`.
+/// Until one is set the contract holds nothing it could pay out, so every
+/// payout fails this check.
 ///
 /// # Requirements
 /// Validates Requirements 3.5, 4.4
@@ -272,16 +580,11 @@ fn verify_contract_balance(
     env: &Env,
     payout_amount: i128,
 ) -> Result<(), StellarSaveError> {
-    // Get the contract's address
-    let _contract_address = env.current_contract_address();
-    
-    // Query the contract's current balance
-    // Note: This is a placeholder implementation
-    // In production, this would query the native token contract:
-    // let native_token = token::Client::new(&env, &native_token_address);
-    // let balance = native_token.balance(&_contract_address);
-    let balance: i128 = 0; // Placeholder
-    
+    let contract_address = env.current_contract_address();
+    let balance = StellarSaveContract::get_contribution_token(env.clone())
+        .map(|token| TokenClient::new(env, &token).balance(&contract_address))
+        .unwrap_or(0);
+
     // Verify that the contract has sufficient balance to cover the payout
     if balance < payout_amount {
         return Err(StellarSaveError::PayoutFailed);
@@ -314,16 +617,12 @@ fn verify_contract_balance(
 /// - `PayoutFailed` - Transfer failed due to insufficient funds, invalid recipient,
 ///   or other transfer-related errors
 /// - `Overflow` - Arithmetic overflow occurred during amount validation
+/// - `TransferLimitExceeded` - Amount exceeds the protocol's per-transaction cap
 ///
 /// # Implementation Note
-/// This is a placeholder implementation for the MVP. In production, this would:
-/// 1. Get the native token contract address from configuration
-/// 2. Create a token client for the native asset (XLM)
-/// 3. Execute the transfer using token.transfer(from, to, amount)
-/// 4. Handle specific transfer errors (insufficient balance, frozen account, etc.)
-///
-/// For now, this function validates the inputs and returns success to allow
-/// testing of the payout flow without requiring a full token integration.
+/// The transfer is made in the contribution token. A transfer the token
+/// rejects (insufficient balance, frozen account, etc.) reverts the whole
+/// transaction.
 ///
 /// # Requirements
 /// Validates Requirements 4.1, 4.2, 4.3, 10.3, 10.4
@@ -346,36 +645,25 @@ fn execute_transfer(
     if amount > i128::MAX {
         return Err(StellarSaveError::Overflow);
     }
+
+    // Last-line safety net against a miscalculated amount draining the contract
+    StellarSaveContract::check_payout_limit(env, amount)?;
     
-    // Execute the transfer from contract to recipient
-    // Note: This is a placeholder implementation for MVP
-    // In production, this would use the Stellar token API:
-    //
-    // // Get the native token contract address (XLM)
-    // let native_token_address = get_native_token_address(env);
-    // 
-    // // Create a token client for the native asset
-    // let token = token::Client::new(env, &native_token_address);
-    // 
-    // // Execute the transfer
-    // // This will automatically revert on failure (insufficient balance, etc.)
-    // token.transfer(&contract_address, recipient, &amount);
-    //
-    // The transfer operation in Soroban is atomic - if it fails, all state
-    // changes are automatically reverted by the Soroban runtime.
-    
-    // For MVP: Log the transfer details (in production, this would be the actual transfer)
-    // The actual transfer would be handled by the Stellar token contract
-    let _from = contract_address;
-    let _to = recipient.clone();
-    let _transfer_amount = amount;
-    
-    // In production, any transfer failure would cause a panic/revert
-    // For now, we return success to allow testing the payout flow
-    // TODO: Implement actual token transfer when token integration is ready
-    
+    let token = StellarSaveContract::get_contribution_token(env.clone())
+        .ok_or(StellarSaveError::PayoutFailed)?;
+    TokenClient::new(env, &token).transfer(&contract_address, recipient, &amount);
+
     Ok(())
 }
+
+/// Returns where a recipient's payout is sent: the payout address on their
+/// profile, or the recipient's own address if they haven't set one.
+fn payout_destination(env: &Env, group_id: u64, recipient: &Address) -> Address {
+    MemberStore::load(env, group_id, recipient)
+        .map(|profile| profile.payout_destination())
+        .unwrap_or(recipient.clone())
+}
+
 /// Creates and stores an immutable payout record.
 ///
 /// This function creates a PayoutRecord with all payout details and stores it in
@@ -419,13 +707,21 @@ fn record_payout(
     // Create PayoutRecord with all required fields
     // The PayoutRecord::new constructor validates that amount > 0
     // and will panic if validation fails
-    let payout_record = PayoutRecord::new(
-        recipient.clone(),
-        group_id,
-        cycle,
-        amount,
-        timestamp,
-    );
+    let payout_record = PayoutRecord::new(recipient, group_id, cycle, amount, timestamp);
+    record_payout_at(env, 0, payout_record)
+}
+
+/// Like `record_payout`, for a record of the `index`-th of the recipients
+/// paid in its cycle. Only the first recipient goes in the cycle's recipient
+/// lookup, which marks the cycle as paid.
+fn record_payout_at(
+    env: &Env,
+    index: u32,
+    payout_record: PayoutRecord,
+) -> Result<(), StellarSaveError> {
+    let recipient = &payout_record.recipient;
+    let group_id = payout_record.group_id;
+    let cycle = payout_record.cycle_number;
 
     // Validate the record before storage
     // This ensures the record meets all validation constraints
@@ -435,42 +731,46 @@ fn record_payout(
 
     // Store the complete payout record
     // This provides the full audit trail of the payout
-    let record_key = StorageKeyBuilder::payout_record(group_id, cycle);
-    env.storage().persistent().set(&record_key, &payout_record);
+    let record_key = StorageKeyBuilder::payout_record_at(group_id, cycle, index);
+    set_persistent(env, &record_key, &payout_record);
 
     // Store the recipient address for quick lookup
     // This allows efficient queries to check if a member has received a payout
-    let recipient_key = StorageKeyBuilder::payout_recipient(group_id, cycle);
-    env.storage().persistent().set(&recipient_key, &recipient);
+    if index == 0 {
+        let recipient_key = StorageKeyBuilder::payout_recipient(group_id, cycle);
+        set_persistent(env, &recipient_key, recipient);
+        // The cycle's contributions have left the pool
+        stats::record_released(env, CyclePool::load(env, group_id, cycle).total);
+    }
+    stats::record_paid_out(env, payout_record.amount);
+    recovery::touch(env, group_id);
+
+    // Mark the recipient as paid so eligibility checks are a single read
+    StellarSaveContract::mark_payout_received(env, group_id, recipient, cycle);
 
-    // Both storage operations completed successfully
+    // All storage operations completed successfully
     Ok(())
 }
 
-/// Updates the recipient's member status to reflect payout completion.
+/// Updates the recipient's member profile to reflect payout completion.
 ///
-/// This function ensures consistency between payout records and member status queries.
-/// The current architecture tracks payout receipt via the `payout_recipient` storage key
-/// rather than a flag in MemberProfile. This function verifies that the member profile
-/// exists and is consistent with the payout record that has been stored.
+/// Sets `has_received_payout` and `payout_cycle` on the profile, which is the
+/// primary source for eligibility checks and member queries. Recording the
+/// payout normally sets them already, so this leaves a profile that agrees
+/// untouched and repairs one that does not.
 ///
 /// # Arguments
 /// * `env` - Soroban environment for storage access
 /// * `group_id` - Unique identifier of the group
 /// * `recipient` - Address of the member who received the payout
+/// * `cycle` - Cycle the payout was made for
 ///
 /// # Returns
-/// * `Ok(())` - Member status is consistent and valid
-/// * `Err(StellarSaveError)` - Member profile not found or storage error
+/// * `Ok(())` - The profile records the payout
+/// * `Err(StellarSaveError)` - Member profile not found
 ///
 /// # Errors
-/// - `InternalError` - Member profile not found or storage operation failed
-///
-/// # Implementation Note
-/// Since the payout receipt is tracked via the `payout_recipient` storage key
-/// (stored by the `record_payout` function), this function primarily validates
-/// that the member profile exists and is accessible. The actual payout status
-/// is queried via the `payout_recipient` storage key in the eligibility checks.
+/// - `InternalError` - Member profile not found
 ///
 /// # Requirements
 /// Validates Requirements 6.1, 6.2, 6.3, 6.4, 6.5
@@ -478,30 +778,17 @@ fn update_member_status(
     env: &Env,
     group_id: u64,
     recipient: &Address,
+    cycle: u32,
 ) -> Result<(), StellarSaveError> {
-    // Load the MemberProfile for the recipient to ensure it exists
-    // This validates that the recipient is a valid member of the group
-    let member_key = StorageKeyBuilder::member_profile(group_id, recipient.clone());
-    
-    // Attempt to load the member profile
-    // If the profile doesn't exist, this indicates an internal consistency error
-    // since we should have already verified the member exists during eligibility checks
-    let _member_profile: MemberProfile = env
-        .storage()
-        .persistent()
-        .get(&member_key)
-        .ok_or(StellarSaveError::InternalError)?;
-    
-    // The member profile exists and is accessible
-    // The payout receipt status is tracked via the payout_recipient storage key
-    // which was set by the record_payout function, so no additional updates
-    // to the MemberProfile struct are needed.
-    //
-    // Future enhancements could add a payout_received flag to MemberProfile,
-    // but the current architecture uses the payout_recipient storage key as
-    // the source of truth for payout status.
-    
-    // Consistency check passed - member status is valid
+    // The recipient was verified as a member during the eligibility checks,
+    // so a missing profile is an internal consistency error
+    let mut profile: MemberProfile =
+        MemberStore::load(env, group_id, recipient).ok_or(StellarSaveError::InternalError)?;
+
+    if !profile.has_received_payout || profile.payout_cycle != Some(cycle) {
+        profile.record_payout(cycle);
+        MemberStore::save(env, &profile);
+    }
     Ok(())
 }
 
@@ -536,11 +823,11 @@ fn update_member_status(
 /// # Usage in execute_payout
 /// This function should be called after all storage operations complete successfully
 /// but before cycle advancement. The recommended call sequence in execute_payout is:
-/// 1. execute_transfer() - Transfer funds to recipient
-/// 2. record_payout() - Store payout record
-/// 3. update_member_status() - Update member status
-/// 4. emit_payout_event() - Emit event (non-critical, continues on failure)
-/// 5. advance_cycle_or_complete() - Advance to next cycle
+/// 1. record_payout() - Store payout record
+/// 2. update_member_status() - Update member status
+/// 3. emit_payout_event() - Emit event (non-critical, continues on failure)
+/// 4. advance_cycle_or_complete() - Advance to next cycle
+/// 5. execute_transfer() - Transfer funds to recipient
 ///
 /// # Requirements
 /// Validates Requirements 7.1, 7.2, 7.3, 7.4, 7.5
@@ -585,6 +872,8 @@ fn emit_payout_event(
 /// - If complete, emits a GroupCompleted event automatically
 ///
 /// After advancing the cycle, this function saves the updated group to storage.
+/// A group that has completed is moved to Completed through
+/// `status::transition`, which also updates its stored status and history.
 ///
 /// # Arguments
 /// * `env` - Soroban environment for storage access and event emission
@@ -606,11 +895,12 @@ fn emit_payout_event(
 /// - Event emission (GroupCompleted event)
 /// - State validation (panics if already complete)
 ///
-/// This function's responsibility is to call advance_cycle and persist the changes.
+/// This function's responsibility is to call advance_cycle, persist the
+/// changes and settle a completed group.
 ///
 /// # Requirements
 /// Validates Requirements 8.1, 8.2, 8.3, 8.4, 8.5, 8.6
-fn advance_cycle_or_complete(
+pub(crate) fn advance_cycle_or_complete(
     env: &Env,
     group: &mut Group,
 ) -> Result<(), StellarSaveError> {
@@ -624,14 +914,409 @@ fn advance_cycle_or_complete(
     group.advance_cycle(env);
 
     // Save the updated group to storage
-    // This persists the incremented cycle number and any status changes
-    let group_key = StorageKeyBuilder::group_data(group.id);
-    env.storage().persistent().set(&group_key, group);
+    // This persists the incremented cycle number, and a completed group
+    // records its transition to Completed like any other status change
+    if group.is_complete() {
+        status::transition(
+            env,
+            group,
+            GroupStatus::Active,
+            GroupStatus::Completed,
+            env.current_contract_address(),
+            ActionNote::unstated(),
+        )?;
+    } else {
+        GroupStore::save(env, group);
+    }
+
+    // Report final totals and drop the group from its members' group lists
+    if group.is_complete() {
+        StellarSaveContract::settle_completed_group(env, group)?;
+    }
 
     // Cycle advancement and storage completed successfully
     Ok(())
 }
 
+/// A payout that has passed every check and only remains to be carried out.
+///
+/// Produced by `prepare_payout` without touching storage, so a caller can
+/// decide whether to go ahead before anything is written.
+pub(crate) struct PreparedPayout {
+    group: Group,
+    /// The cycle's recipients, in payout position order
+    pub(crate) recipients: Vec<Address>,
+    settlement: InsuranceSettlement,
+    /// Each recipient's share of the pool, net of the protocol fee
+    pub(crate) net_payout: i128,
+    /// The pool's split among the recipients, including its remainder
+    allocation: Allocation,
+    /// Protocol fee withheld, with any remainder credited to the treasury
+    protocol_fee: i128,
+}
+
+/// Runs every payout precondition for the group's current cycle without
+/// changing any state.
+///
+/// Covers steps 1-8 of `execute_payout`. The balance check counts a pool
+/// deposited in the group's yield strategy as available, since
+/// `complete_payout` withdraws it before transferring.
+///
+/// # Errors
+/// Same as the validation errors of `execute_payout`.
+pub(crate) fn prepare_payout(
+    env: &Env,
+    group_id: u64,
+) -> Result<PreparedPayout, StellarSaveError> {
+    check_payout(env, group_id).map_err(|(_, error)| error)
+}
+
+/// Same as `prepare_payout`, but also reports which check failed.
+pub(crate) fn check_payout(
+    env: &Env,
+    group_id: u64,
+) -> Result<PreparedPayout, (PayoutBlocker, StellarSaveError)> {
+    // Step 1: Load group from storage
+    let group: Group = GroupStore::load(env, group_id)
+        .ok_or((PayoutBlocker::GroupStatus, StellarSaveError::GroupNotFound))?;
+
+    // Step 2: Validate group status is Active
+    // Only Active groups can process payouts
+    if group.status != GroupStatus::Active {
+        return Err((PayoutBlocker::GroupStatus, StellarSaveError::GroupNotActive));
+    }
+    StellarSaveContract::ensure_not_halted(env, group_id)
+        .map_err(|error| (PayoutBlocker::GroupStatus, error))?;
+    // Savings-only groups never pay out in turn; members withdraw instead
+    if savings::is_savings_group(env, group_id) {
+        return Err((PayoutBlocker::GroupStatus, StellarSaveError::InvalidState));
+    }
+
+    // Step 3: Check if payout already executed for current cycle
+    // This prevents duplicate payouts for the same cycle
+    let current_cycle = group.current_cycle;
+    let recipient_key = StorageKeyBuilder::payout_recipient(group_id, current_cycle);
+    if env.storage().persistent().has(&recipient_key) {
+        // Payout already executed for this cycle
+        return Err((PayoutBlocker::AlreadyPaid, StellarSaveError::PayoutAlreadyProcessed));
+    }
+    // A contract the payout in progress calls out to cannot start another
+    if env
+        .storage()
+        .persistent()
+        .has(&StorageKeyBuilder::group_payout_in_flight(group_id))
+    {
+        return Err((PayoutBlocker::AlreadyPaid, StellarSaveError::PayoutAlreadyProcessed));
+    }
+
+    // Step 3b: Hold the payout in escrow while a dispute about it is open
+    if dispute::holds_payout(env, group_id, current_cycle) {
+        return Err((PayoutBlocker::Disputed, StellarSaveError::PayoutDisputed));
+    }
+
+    // Step 4: Validate cycle is complete (all members have contributed, or
+    // the insurance reserve covers those who defaulted)
+    let (_pool_info, settlement) = validate_cycle_complete(env, &group, current_cycle)
+        .map_err(|error| (PayoutBlocker::CycleIncomplete, error))?;
+    
+    // Step 5: Identify the recipients for this cycle based on payout position
+    // Step 6: Verify each recipient is eligible to receive the payout
+    let mut recipients = Vec::new(env);
+    for position in group.cycle_positions(current_cycle) {
+        let recipient = identify_recipient(env, group_id, position, group.member_count)
+            .map_err(|error| (PayoutBlocker::Recipient, error))?;
+        verify_recipient_eligibility(env, group_id, &recipient)
+            .map_err(|error| (PayoutBlocker::Recipient, error))?;
+        recipients.push_back(recipient);
+    }
+    if recipients.is_empty() {
+        return Err((PayoutBlocker::Recipient, StellarSaveError::NoRecipientForCycle));
+    }
+    
+    // Step 7: Calculate each recipient's share of the pool total, net of
+    // protocol fee and with any remainder carried from the last cycle. What
+    // does not split evenly follows the group's rounding policy; the last
+    // cycle has nothing to carry to, so its recipient takes it instead
+    let fee_bps = PoolCalculator::get_fee_bps(env);
+    let mut policy = load_rounding_policy(env, group_id);
+    if policy == RoundingPolicy::CarryToNextCycle
+        && current_cycle.saturating_add(1) >= group.total_cycles()
+    {
+        policy = RoundingPolicy::RemainderToRecipient;
+    }
+    let net_pool = calculate_and_validate_payout_amount(settlement.payout_base, fee_bps)
+        .map_err(|error| (PayoutBlocker::Amount, error))?;
+    let allocation = net_pool
+        .checked_add(load_carried_remainder(env, group_id))
+        .ok_or(StellarSaveError::Overflow)
+        .and_then(|pool| PoolCalculator::allocate(pool, recipients.len(), policy))
+        .and_then(|allocation| match allocation.share {
+            0 => Err(StellarSaveError::InvalidAmount),
+            _ => Ok(allocation),
+        })
+        .map_err(|error| (PayoutBlocker::Amount, error))?;
+    let net_payout = allocation.share;
+    let protocol_fee = settlement.payout_base - net_pool + allocation.to_treasury;
+
+    // Step 7b: A payout above the group's approval threshold needs enough
+    // of its approvers to have signed off on this cycle
+    let largest = net_payout.saturating_add(allocation.to_recipient);
+    approval::check(env, group_id, current_cycle, largest, env.ledger().timestamp())
+        .map_err(|error| (PayoutBlocker::Approval, error))?;
+
+    // Step 8: Verify contract has sufficient balance to cover the payout,
+    // counting a pool held by the yield strategy for this cycle
+    let deposited = match yield_strategy::load_deposit(env, group_id) {
+        Some(deposit) if deposit.cycle != current_cycle => {
+            return Err((PayoutBlocker::Balance, StellarSaveError::InvalidState));
+        }
+        Some(deposit) => deposit.principal,
+        None => 0,
+    };
+    let net_total = net_payout
+        .saturating_mul(recipients.len() as i128)
+        .saturating_add(allocation.to_recipient);
+    verify_contract_balance(env, net_total.saturating_sub(deposited))
+        .map_err(|error| (PayoutBlocker::Balance, error))?;
+
+    Ok(PreparedPayout {
+        group,
+        recipients,
+        settlement,
+        net_payout,
+        allocation,
+        protocol_fee,
+    })
+}
+
+/// Breaks down a payout checked by `prepare_payout` as `complete_payout`
+/// would make it now, without changing any state.
+///
+/// Yield is left out, since it is only known once the pool is withdrawn
+/// from the group's strategy; compensation is quoted on the net share.
+///
+/// # Errors
+/// - `Overflow` - Arithmetic overflow in calculations
+pub(crate) fn quote_payout(
+    env: &Env,
+    prepared: &PreparedPayout,
+) -> Result<PayoutQuote, StellarSaveError> {
+    let group = &prepared.group;
+    let settlement = &prepared.settlement;
+    let timestamp = env.ledger().timestamp();
+
+    let compensation_bps = load_late_compensation_bps(env, group.id);
+    let delay_secs = payout_delay(env, group, compensation_bps, timestamp);
+    let mut reserve = settlement.reserve_after(load_insurance_reserve(env, group.id))?;
+    let mut compensations = Vec::new(env);
+    for index in 0..prepared.recipients.len() {
+        let mut amount = prepared.net_payout;
+        if index == 0 {
+            amount += prepared.allocation.to_recipient;
+        }
+        let compensation =
+            PoolCalculator::calculate_late_compensation(amount, compensation_bps, delay_secs)?
+                .min(reserve.max(0));
+        reserve -= compensation;
+        compensations.push_back(compensation);
+    }
+
+    Ok(PayoutQuote {
+        group_id: group.id,
+        cycle: group.current_cycle,
+        recipients: prepared.recipients.clone(),
+        collected: CyclePool::load(env, group.id, group.current_cycle).total,
+        insurance_premium: settlement.premium,
+        insurance_coverage: settlement.coverage,
+        protocol_fee: prepared.protocol_fee,
+        net_payout: prepared.net_payout,
+        remainder_to_recipient: prepared.allocation.to_recipient,
+        remainder_carried: prepared.allocation.carried,
+        delay_secs,
+        compensations,
+    })
+}
+
+/// Carries out a payout checked by `prepare_payout`.
+///
+/// Covers steps 9-16 of `execute_payout`. Each of the cycle's recipients
+/// receives an equal share of the pool and of any yield; the first takes the
+/// yield's indivisible remainder. In a group that compensates late payouts,
+/// each share executed after the cycle fell due also earns compensation from
+/// the insurance reserve, for as long as the reserve lasts.
+///
+/// The payout follows checks-effects-interactions: it is recorded and the
+/// cycle advanced before any token is transferred or the payout hook called,
+/// and the group is flagged as paying out from the yield withdrawal until it
+/// returns. A contract called along the way therefore finds the cycle paid
+/// and cannot start a second payout.
+///
+/// # Returns
+/// The cycle's first recipient and the amount transferred to them,
+/// including any yield.
+///
+/// # Errors
+/// - `PayoutFailed` - The strategy returned less than it was given, or the
+///   transfer failed
+/// - `TransferLimitExceeded` - Amount exceeds the protocol's per-transaction cap
+/// - `Overflow` - Arithmetic overflow in calculations
+pub(crate) fn complete_payout(
+    env: &Env,
+    prepared: PreparedPayout,
+) -> Result<(Address, i128), StellarSaveError> {
+    let PreparedPayout {
+        mut group,
+        recipients,
+        settlement,
+        net_payout,
+        allocation,
+        protocol_fee,
+    } = prepared;
+    let group_id = group.id;
+    let current_cycle = group.current_cycle;
+    let recipient_count = recipients.len() as i128;
+    let in_flight_key = StorageKeyBuilder::group_payout_in_flight(group_id);
+    set_persistent(env, &in_flight_key, &true);
+
+    // Step 9: Withdraw the pool from the group's yield strategy, if it was
+    // deposited, and re-check the balance now that the funds are back. The
+    // yield decides the amounts, so this is the one call made before the
+    // payout is recorded
+    let timestamp = env.ledger().timestamp();
+    let pool_yield = yield_strategy::withdraw_pool(env, group_id, current_cycle, timestamp)?;
+    let net_total = net_payout
+        .checked_mul(recipient_count)
+        .and_then(|total| total.checked_add(allocation.to_recipient))
+        .ok_or(StellarSaveError::Overflow)?;
+    verify_contract_balance(
+        env,
+        net_total.checked_add(pool_yield).ok_or(StellarSaveError::Overflow)?,
+    )?;
+    // Yield is earned for the recipients, so its remainder always stays
+    // with them
+    let yield_split = PoolCalculator::allocate(
+        pool_yield,
+        recipients.len(),
+        RoundingPolicy::RemainderToRecipient,
+    )?;
+
+    // A payout executed after it fell due is compensated from whatever the
+    // insurance reserve holds once this cycle is settled
+    let compensation_bps = load_late_compensation_bps(env, group_id);
+    let delay_secs = payout_delay(env, &group, compensation_bps, timestamp);
+    let mut reserve = settlement.reserve_after(load_insurance_reserve(env, group_id))?;
+    let mut compensations = Vec::new(env);
+
+    let first_position = group.cycle_positions(current_cycle).start;
+    let mut amounts = Vec::new(env);
+    for (index, recipient) in recipients.iter().enumerate() {
+        let index = index as u32;
+        let mut payout_amount = net_payout
+            .checked_add(yield_split.share)
+            .ok_or(StellarSaveError::Overflow)?;
+        if index == 0 {
+            payout_amount += allocation.to_recipient + yield_split.to_recipient;
+        }
+
+        // Step 10: Seat the recipient in their payout position, which a
+        // dynamic payout order may have given to someone else
+        payout_order::seat_recipient(env, group_id, first_position + index, &recipient)?;
+        let compensation = PoolCalculator::calculate_late_compensation(
+            payout_amount,
+            compensation_bps,
+            delay_secs,
+        )?
+        .min(reserve.max(0));
+        reserve -= compensation;
+
+        // Step 11: Create and store the payout record for audit trail, which
+        // marks the cycle as paid
+        let mut payout_record = PayoutRecord::new(
+            recipient.clone(),
+            group_id,
+            current_cycle,
+            payout_amount,
+            timestamp,
+        );
+        payout_record.compensation = compensation;
+        record_payout_at(env, index, payout_record)?;
+        amounts.push_back(payout_amount);
+        compensations.push_back(compensation);
+    }
+
+    // Step 11b: Credit the withheld protocol fee, with any remainder the
+    // rounding policy sends to the treasury, and hold back any remainder
+    // carried to the next cycle
+    collect_protocol_fee(env, group_id, current_cycle, protocol_fee, timestamp)?;
+    let carried_key = StorageKeyBuilder::group_carried_remainder(group_id);
+    match allocation.carried {
+        0 => env.storage().persistent().remove(&carried_key),
+        carried => set_persistent(env, &carried_key, &carried),
+    }
+
+    // Step 11c: Credit the insurance premium, draw any default coverage and
+    // any compensation for a late payout
+    apply_insurance_settlement(env, group_id, current_cycle, &settlement, timestamp)?;
+    draw_late_compensation(
+        env,
+        &group,
+        &recipients,
+        &compensations,
+        delay_secs,
+        timestamp,
+    );
+
+    // Step 11d: Charge any shortfall of a partial payout to the defaulters
+    record_shortfall(env, &group, current_cycle, settlement.shortfall, timestamp)?;
+
+    for (recipient, payout_amount) in recipients.iter().zip(amounts.iter()) {
+        // Step 12: Update the member status to reflect payout completion
+        update_member_status(env, group_id, &recipient, current_cycle)?;
+
+        // Step 13: Emit payout event (non-critical - continues on failure)
+        emit_payout_event(env, group_id, recipient, payout_amount, current_cycle, timestamp);
+    }
+    
+    // Step 14: Advance to the next cycle or mark group as complete, and let
+    // members' credit pay the cycle that opens, along with their join escrow
+    // if it is the last
+    advance_cycle_or_complete(env, &mut group)?;
+    if !group.is_complete() {
+        if group.current_cycle.saturating_add(1) == group.total_cycles()
+            && escrow::is_required(env, group_id)
+        {
+            StellarSaveContract::release_join_escrow(env, &group);
+        }
+        StellarSaveContract::apply_credits(env, group_id);
+    }
+
+    // Step 15: Execute the fund transfers, now that nothing is left to record,
+    // to any payout address the recipients have set
+    for ((recipient, payout_amount), compensation) in recipients
+        .iter()
+        .zip(amounts.iter())
+        .zip(compensations.iter())
+    {
+        execute_transfer(
+            env,
+            &payout_destination(env, group_id, &recipient),
+            payout_amount
+                .checked_add(compensation)
+                .ok_or(StellarSaveError::Overflow)?,
+        )?;
+    }
+
+    // Step 16: Notify the group's payout hook, if any. A failing hook is
+    // reported by event and cannot revert the payout
+    for (recipient, payout_amount) in recipients.iter().zip(amounts.iter()) {
+        payout_hook::notify(env, group_id, current_cycle, &recipient, payout_amount);
+    }
+    env.storage().persistent().remove(&in_flight_key);
+
+    let recipient = recipients.get(0).ok_or(StellarSaveError::NoRecipientForCycle)?;
+    Ok((recipient, amounts.get(0).unwrap_or(0)))
+}
+
 /// Executes a payout for the specified group.
 ///
 /// This is the main entry point for payout execution. It orchestrates all the steps
@@ -640,19 +1325,30 @@ fn advance_cycle_or_complete(
 /// preconditions are met.
 ///
 /// # Execution Flow
+/// Validation (`prepare_payout`), no state changes:
 /// 1. Load and validate group exists
 /// 2. Validate group status is Active
-/// 3. Check if payout already executed for current cycle
+/// 3. Check if payout already executed for current cycle, or held by an
+///    open dispute
 /// 4. Validate cycle is complete (all members contributed)
-/// 5. Identify the recipient based on payout position
+/// 5. Identify the recipients based on payout position
 /// 6. Verify recipient eligibility
-/// 7. Calculate payout amount
-/// 8. Verify contract has sufficient balance
-/// 9. Execute fund transfer to recipient
-/// 10. Record payout for audit trail
-/// 11. Update member status
-/// 12. Emit payout event (non-critical)
-/// 13. Advance cycle or mark group as complete
+/// 7. Calculate each recipient's share of the payout
+/// 8. Verify contract has sufficient balance, counting a pool deposited in the
+///    group's yield strategy
+///
+/// Execution (`complete_payout`), with every state change made before the
+/// transfers and the hook:
+/// 9. Withdraw the pool and any yield from the group's yield strategy
+/// 10. Seat each recipient in their payout position
+/// 11. Record payout for audit trail, collect the protocol fee, settle
+///     insurance and charge any shortfall to the defaulters
+/// 12. Update member status
+/// 13. Emit payout event (non-critical)
+/// 14. Advance cycle or mark group as complete
+/// 15. Transfer each recipient their share, with any compensation for a
+///     late payout, to their payout address if they have set one
+/// 16. Notify the group's payout hook, isolating any failure
 ///
 /// # Arguments
 /// * `env` - Soroban environment for storage, ledger access, and event emission
@@ -664,7 +1360,14 @@ fn advance_cycle_or_complete(
 ///
 /// # Errors
 /// - `GroupNotFound` - Group ID does not exist in storage
-/// - `InvalidState` - Group not in Active status, cycle not ready, or payout already executed
+/// - `GroupNotActive` - Group not in Active status
+/// - `PayoutAlreadyProcessed` - Payout already executed for the current cycle
+/// - `PayoutDisputed` - An open dispute holds the current cycle's payout
+/// - `NoRecipientForCycle` - No member holds the current payout position
+/// - `DuplicatePayoutPosition` - Several members hold the current payout position
+/// - `MemberCountMismatch` - Member list and member count disagree
+/// - `InvalidState` - Savings-only group, member records are inconsistent,
+///   or a yield deposit is from an earlier cycle
 /// - `CycleNotComplete` - Not all members have contributed to the current cycle
 /// - `NotMember` - Recipient is not a member of the group
 /// - `InvalidRecipient` - Recipient not eligible or already received payout
@@ -674,83 +1377,27 @@ fn advance_cycle_or_complete(
 /// - `Overflow` - Arithmetic overflow in calculations
 ///
 /// # Atomicity
-/// All operations are atomic - if any step fails after the transfer, all state changes
-/// are automatically reverted by the Soroban runtime. Event emission failures do not
+/// All operations are atomic - if a transfer fails, all state changes are
+/// automatically reverted by the Soroban runtime. Event emission failures do not
 /// cause rollback as events are non-critical.
 ///
 /// # Requirements
 /// Validates Requirements 9.1, 9.2, 9.3, 9.4, 9.5, 10.6, 10.7, and orchestrates all
 /// other requirements through helper functions.
 pub fn execute_payout(env: Env, group_id: u64) -> Result<(), StellarSaveError> {
-    // Step 1: Load group from storage
-    let group_key = StorageKeyBuilder::group_data(group_id);
-    let mut group: Group = env
-        .storage()
-        .persistent()
-        .get(&group_key)
-        .ok_or(StellarSaveError::GroupNotFound)?;
-
-    // Step 2: Validate group status is Active
-    // Only Active groups can process payouts
-    if group.status != GroupStatus::Active {
-        return Err(StellarSaveError::InvalidState);
-    }
-
-    // Step 3: Check if payout already executed for current cycle
-    // This prevents duplicate payouts for the same cycle
-    let current_cycle = group.current_cycle;
-    let recipient_key = StorageKeyBuilder::payout_recipient(group_id, current_cycle);
-    if env.storage().persistent().has(&recipient_key) {
-        // Payout already executed for this cycle
-        return Err(StellarSaveError::InvalidState);
-    }
-
-    // === VALIDATION PHASE (Task 12.2) ===
-    // All validation checks must pass before any state modifications occur
-    
-    // Step 4: Validate cycle is complete (all members have contributed)
-    let pool_info = validate_cycle_complete(&env, group_id, current_cycle)?;
-    
-    // Step 5: Identify the recipient for this cycle based on payout position
-    let recipient = identify_recipient(&env, group_id, current_cycle, group.member_count)?;
-    
-    // Step 6: Verify the recipient is eligible to receive the payout
-    verify_recipient_eligibility(&env, group_id, &recipient, current_cycle)?;
-    
-    // Step 7: Calculate the payout amount from the pool total
-    let payout_amount = calculate_and_validate_payout_amount(&pool_info)?;
-    
-    // Step 8: Verify contract has sufficient balance to cover the payout
-    verify_contract_balance(&env, payout_amount)?;
-    
-    // === EXECUTION PHASE (Task 12.3) ===
-    // All validations passed - proceed with payout execution
-    // If any step fails after this point, Soroban will automatically revert all changes
-    
-    // Step 9: Execute the fund transfer to the recipient
-    execute_transfer(&env, &recipient, payout_amount)?;
-    
-    // Step 10: Create and store the payout record for audit trail
-    let timestamp = env.ledger().timestamp();
-    record_payout(&env, group_id, current_cycle, recipient.clone(), payout_amount, timestamp)?;
-    
-    // Step 11: Update the member status to reflect payout completion
-    update_member_status(&env, group_id, &recipient)?;
-    
-    // Step 12: Emit payout event (non-critical - continues on failure)
-    emit_payout_event(&env, group_id, recipient, payout_amount, current_cycle, timestamp);
-    
-    // Step 13: Advance to the next cycle or mark group as complete
-    advance_cycle_or_complete(&env, &mut group)?;
-    
-    // Payout execution completed successfully
+    let prepared = prepare_payout(&env, group_id)?;
+    complete_payout(&env, prepared)?;
     Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::units::{xlm, SECONDS_PER_WEEK, STROOPS_PER_XLM};
+    use crate::events::CycleShortfall;
+    use crate::group::PayoutPolicy;
     use crate::pool::PoolInfo;
+    use crate::MemberStatus;
     use soroban_sdk::{testutils::Address as _, Address, Env};
 
     // Test calculate_and_validate_payout_amount with valid pool
@@ -767,9 +1414,9 @@ mod tests {
             is_cycle_complete: true,
         };
 
-        let result = calculate_and_validate_payout_amount(&pool_info);
+        let result = calculate_and_validate_payout_amount(pool_info.total_pool_amount, 0);
         assert!(result.is_ok());
-        // In v1, fees are 0, so payout equals total pool
+        // With no protocol fee, payout equals total pool
         assert_eq!(result.unwrap(), 5_000_000i128);
     }
 
@@ -787,7 +1434,7 @@ mod tests {
             is_cycle_complete: true,
         };
 
-        let result = calculate_and_validate_payout_amount(&pool_info);
+        let result = calculate_and_validate_payout_amount(pool_info.total_pool_amount, 0);
         assert!(result.is_err());
         assert_eq!(result.unwrap_err(), StellarSaveError::InvalidAmount);
     }
@@ -806,7 +1453,7 @@ mod tests {
             is_cycle_complete: true,
         };
 
-        let result = calculate_and_validate_payout_amount(&pool_info);
+        let result = calculate_and_validate_payout_amount(pool_info.total_pool_amount, 0);
         assert!(result.is_err());
         assert_eq!(result.unwrap_err(), StellarSaveError::InvalidAmount);
     }
@@ -818,18 +1465,37 @@ mod tests {
             group_id: 1,
             cycle: 0,
             member_count: 100,
-            contribution_amount: 100_000_000i128, // 10 XLM
+            contribution_amount: xlm(10),
             total_pool_amount: 10_000_000_000i128, // 1000 XLM
             current_contributions: 10_000_000_000i128,
             contributors_count: 100,
             is_cycle_complete: true,
         };
 
-        let result = calculate_and_validate_payout_amount(&pool_info);
+        let result = calculate_and_validate_payout_amount(pool_info.total_pool_amount, 0);
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), 10_000_000_000i128);
     }
 
+    // Test calculate_and_validate_payout_amount deducts the protocol fee
+    #[test]
+    fn test_calculate_payout_amount_with_fee() {
+        let pool_info = PoolInfo {
+            group_id: 1,
+            cycle: 0,
+            member_count: 5,
+            contribution_amount: 1_000_000i128,
+            total_pool_amount: 5_000_000i128,
+            current_contributions: 5_000_000i128,
+            contributors_count: 5,
+            is_cycle_complete: true,
+        };
+
+        // 2% fee
+        let result = calculate_and_validate_payout_amount(pool_info.total_pool_amount, 200);
+        assert_eq!(result.unwrap(), 4_900_000i128);
+    }
+
     // Test verify_contract_balance with sufficient balance
     // Note: This test uses the placeholder implementation
     #[test]
@@ -871,7 +1537,7 @@ mod tests {
         };
 
         // Calculate payout amount
-        let payout_amount = calculate_and_validate_payout_amount(&pool_info);
+        let payout_amount = calculate_and_validate_payout_amount(pool_info.total_pool_amount, 0);
         assert!(payout_amount.is_ok());
         assert_eq!(payout_amount.unwrap(), 6_000_000i128);
 
@@ -957,6 +1623,37 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    // Test execute_transfer against the protocol's per-transaction cap
+    #[test]
+    fn test_execute_transfer_exceeds_risk_limit() {
+        let env = Env::default();
+        let contract_id = env.register(crate::StellarSaveContract, ());
+        let recipient = Address::generate(&env);
+        let token = env.register_stellar_asset_contract_v2(Address::generate(&env));
+        env.mock_all_auths();
+        soroban_sdk::token::StellarAssetClient::new(&env, &token.address())
+            .mint(&contract_id, &STROOPS_PER_XLM);
+
+        env.as_contract(&contract_id, || {
+            let limits = crate::RiskLimits {
+                max_contribution: STROOPS_PER_XLM,
+                max_payout: STROOPS_PER_XLM,
+            };
+            env.storage()
+                .instance()
+                .set(&StorageKeyBuilder::risk_limits(), &limits);
+            env.storage()
+                .instance()
+                .set(&StorageKeyBuilder::contribution_token(), &token.address());
+
+            assert_eq!(execute_transfer(&env, &recipient, STROOPS_PER_XLM), Ok(()));
+            assert_eq!(
+                execute_transfer(&env, &recipient, STROOPS_PER_XLM + 1),
+                Err(StellarSaveError::TransferLimitExceeded)
+            );
+        });
+    }
+
     // Test record_payout with valid data
     #[test]
     fn test_record_payout_valid() {
@@ -1024,7 +1721,14 @@ mod tests {
         assert!(result1.is_ok());
 
         // Record payout for cycle 1
-        let result2 = record_payout(&env, group_id, 1, recipient2.clone(), amount, timestamp + 604800);
+        let result2 = record_payout(
+            &env,
+            group_id,
+            1,
+            recipient2.clone(),
+            amount,
+            timestamp + SECONDS_PER_WEEK,
+        );
         assert!(result2.is_ok());
 
         // Verify both records exist
@@ -1169,20 +1873,19 @@ mod tests {
         let group_id = 1u64;
 
         // Create a member profile for the recipient
-        let member_profile = MemberProfile {
-            address: recipient.clone(),
-            group_id,
-            payout_position: 0,
-            joined_at: 1234567890u64,
-        };
+        let member_profile = MemberProfile::new(recipient.clone(), group_id, 0, 1234567890u64);
 
         // Store the member profile
-        let member_key = StorageKeyBuilder::member_profile(group_id, recipient.clone());
-        env.storage().persistent().set(&member_key, &member_profile);
+        MemberStore::save(&env, &member_profile);
 
-        // Update member status should succeed
-        let result = update_member_status(&env, group_id, &recipient);
+        // Update member status should succeed and flag the payout
+        let result = update_member_status(&env, group_id, &recipient, 0);
         assert!(result.is_ok());
+
+        let profile: MemberProfile = MemberStore::load(&env, group_id, &recipient).unwrap();
+        assert!(profile.has_received_payout);
+        assert_eq!(profile.payout_cycle, Some(0));
+        assert_eq!(profile.status, MemberStatus::PaidOut);
     }
 
     // Test update_member_status with non-existent member
@@ -1195,7 +1898,7 @@ mod tests {
         // Don't create a member profile - member doesn't exist
 
         // Update member status should fail with InternalError
-        let result = update_member_status(&env, group_id, &recipient);
+        let result = update_member_status(&env, group_id, &recipient, 0);
         assert!(result.is_err());
         assert_eq!(result.unwrap_err(), StellarSaveError::InternalError);
     }
@@ -1209,21 +1912,15 @@ mod tests {
         let group_id_2 = 2u64;
 
         // Create member profile for group 1
-        let member_profile_1 = MemberProfile {
-            address: recipient.clone(),
-            group_id: group_id_1,
-            payout_position: 0,
-            joined_at: 1234567890u64,
-        };
-        let member_key_1 = StorageKeyBuilder::member_profile(group_id_1, recipient.clone());
-        env.storage().persistent().set(&member_key_1, &member_profile_1);
+        let member_profile_1 = MemberProfile::new(recipient.clone(), group_id_1, 0, 1234567890u64);
+        MemberStore::save(&env, &member_profile_1);
 
         // Update status for group 1 should succeed
-        let result1 = update_member_status(&env, group_id_1, &recipient);
+        let result1 = update_member_status(&env, group_id_1, &recipient, 0);
         assert!(result1.is_ok());
 
         // Update status for group 2 should fail (member not in group 2)
-        let result2 = update_member_status(&env, group_id_2, &recipient);
+        let result2 = update_member_status(&env, group_id_2, &recipient, 0);
         assert!(result2.is_err());
         assert_eq!(result2.unwrap_err(), StellarSaveError::InternalError);
     }
@@ -1237,21 +1934,15 @@ mod tests {
         let cycle = 0u32;
 
         // Create member profile
-        let member_profile = MemberProfile {
-            address: recipient.clone(),
-            group_id,
-            payout_position: cycle,
-            joined_at: 1234567890u64,
-        };
-        let member_key = StorageKeyBuilder::member_profile(group_id, recipient.clone());
-        env.storage().persistent().set(&member_key, &member_profile);
+        let member_profile = MemberProfile::new(recipient.clone(), group_id, cycle, 1234567890u64);
+        MemberStore::save(&env, &member_profile);
 
         // Store payout recipient (simulating record_payout)
         let recipient_key = StorageKeyBuilder::payout_recipient(group_id, cycle);
         env.storage().persistent().set(&recipient_key, &recipient);
 
         // Update member status should succeed
-        let result = update_member_status(&env, group_id, &recipient);
+        let result = update_member_status(&env, group_id, &recipient, 0);
         assert!(result.is_ok());
 
         // Verify payout recipient is still stored correctly
@@ -1269,28 +1960,16 @@ mod tests {
         let group_id = 1u64;
 
         // Create member profiles for both recipients
-        let member_profile_1 = MemberProfile {
-            address: recipient1.clone(),
-            group_id,
-            payout_position: 0,
-            joined_at: 1234567890u64,
-        };
-        let member_profile_2 = MemberProfile {
-            address: recipient2.clone(),
-            group_id,
-            payout_position: 1,
-            joined_at: 1234567890u64,
-        };
+        let member_profile_1 = MemberProfile::new(recipient1.clone(), group_id, 0, 1234567890u64);
+        let member_profile_2 = MemberProfile::new(recipient2.clone(), group_id, 1, 1234567890u64);
 
-        let member_key_1 = StorageKeyBuilder::member_profile(group_id, recipient1.clone());
-        let member_key_2 = StorageKeyBuilder::member_profile(group_id, recipient2.clone());
         
-        env.storage().persistent().set(&member_key_1, &member_profile_1);
-        env.storage().persistent().set(&member_key_2, &member_profile_2);
+        MemberStore::save(&env, &member_profile_1);
+        MemberStore::save(&env, &member_profile_2);
 
         // Update status for both members should succeed
-        let result1 = update_member_status(&env, group_id, &recipient1);
-        let result2 = update_member_status(&env, group_id, &recipient2);
+        let result1 = update_member_status(&env, group_id, &recipient1, 0);
+        let result2 = update_member_status(&env, group_id, &recipient2, 1);
         
         assert!(result1.is_ok());
         assert!(result2.is_ok());
@@ -1300,6 +1979,7 @@ mod tests {
     #[test]
     fn test_emit_payout_event_valid() {
         let env = Env::default();
+        let contract_id = env.register(crate::StellarSaveContract, ());
         let recipient = Address::generate(&env);
         let group_id = 1u64;
         let cycle = 0u32;
@@ -1308,7 +1988,9 @@ mod tests {
 
         // Event emission should not panic or fail
         // This function always succeeds, even if event emission fails internally
-        emit_payout_event(&env, group_id, recipient.clone(), amount, cycle, timestamp);
+        env.as_contract(&contract_id, || {
+            emit_payout_event(&env, group_id, recipient.clone(), amount, cycle, timestamp);
+        });
         
         // No assertion needed - if we reach here, the function succeeded
         // In a real test environment with event inspection, we would verify
@@ -1319,6 +2001,7 @@ mod tests {
     #[test]
     fn test_emit_payout_event_large_amount() {
         let env = Env::default();
+        let contract_id = env.register(crate::StellarSaveContract, ());
         let recipient = Address::generate(&env);
         let group_id = 1u64;
         let cycle = 5u32;
@@ -1326,13 +2009,16 @@ mod tests {
         let timestamp = 1234567890u64;
 
         // Event emission should handle large amounts without issue
-        emit_payout_event(&env, group_id, recipient, amount, cycle, timestamp);
+        env.as_contract(&contract_id, || {
+            emit_payout_event(&env, group_id, recipient, amount, cycle, timestamp);
+        });
     }
 
     // Test emit_payout_event with minimum amount
     #[test]
     fn test_emit_payout_event_minimum_amount() {
         let env = Env::default();
+        let contract_id = env.register(crate::StellarSaveContract, ());
         let recipient = Address::generate(&env);
         let group_id = 1u64;
         let cycle = 0u32;
@@ -1340,13 +2026,16 @@ mod tests {
         let timestamp = 1234567890u64;
 
         // Event emission should handle minimum amount
-        emit_payout_event(&env, group_id, recipient, amount, cycle, timestamp);
+        env.as_contract(&contract_id, || {
+            emit_payout_event(&env, group_id, recipient, amount, cycle, timestamp);
+        });
     }
 
     // Test emit_payout_event with different cycles
     #[test]
     fn test_emit_payout_event_multiple_cycles() {
         let env = Env::default();
+        let contract_id = env.register(crate::StellarSaveContract, ());
         let recipient1 = Address::generate(&env);
         let recipient2 = Address::generate(&env);
         let group_id = 1u64;
@@ -1354,8 +2043,10 @@ mod tests {
         let timestamp = 1234567890u64;
 
         // Emit events for multiple cycles
-        emit_payout_event(&env, group_id, recipient1, amount, 0, timestamp);
-        emit_payout_event(&env, group_id, recipient2, amount, 1, timestamp + 604800);
+        env.as_contract(&contract_id, || {
+            emit_payout_event(&env, group_id, recipient1, amount, 0, timestamp);
+            emit_payout_event(&env, group_id, recipient2, amount, 1, timestamp + SECONDS_PER_WEEK);
+        });
         
         // Both emissions should succeed
     }
@@ -1364,6 +2055,7 @@ mod tests {
     #[test]
     fn test_emit_payout_event_different_groups() {
         let env = Env::default();
+        let contract_id = env.register(crate::StellarSaveContract, ());
         let recipient1 = Address::generate(&env);
         let recipient2 = Address::generate(&env);
         let cycle = 0u32;
@@ -1371,8 +2063,10 @@ mod tests {
         let timestamp = 1234567890u64;
 
         // Emit events for different groups
-        emit_payout_event(&env, 1, recipient1, amount, cycle, timestamp);
-        emit_payout_event(&env, 2, recipient2, amount, cycle, timestamp);
+        env.as_contract(&contract_id, || {
+            emit_payout_event(&env, 1, recipient1, amount, cycle, timestamp);
+            emit_payout_event(&env, 2, recipient2, amount, cycle, timestamp);
+        });
         
         // Both emissions should succeed
     }
@@ -1381,6 +2075,7 @@ mod tests {
     #[test]
     fn test_emit_payout_event_non_critical() {
         let env = Env::default();
+        let contract_id = env.register(crate::StellarSaveContract, ());
         let recipient = Address::generate(&env);
         let group_id = 1u64;
         let cycle = 0u32;
@@ -1389,9 +2084,11 @@ mod tests {
 
         // Call emit_payout_event multiple times
         // This should never panic or fail, demonstrating non-critical behavior
-        for _ in 0..10 {
-            emit_payout_event(&env, group_id, recipient.clone(), amount, cycle, timestamp);
-        }
+        env.as_contract(&contract_id, || {
+            for _ in 0..10 {
+                emit_payout_event(&env, group_id, recipient.clone(), amount, cycle, timestamp);
+            }
+        });
         
         // All emissions should succeed without error
     }
@@ -1402,6 +2099,7 @@ mod tests {
     #[test]
     fn test_emit_payout_event_zero_amount() {
         let env = Env::default();
+        let contract_id = env.register(crate::StellarSaveContract, ());
         let recipient = Address::generate(&env);
         let group_id = 1u64;
         let cycle = 0u32;
@@ -1410,7 +2108,9 @@ mod tests {
 
         // Event emission should not panic even with invalid data
         // This demonstrates the non-critical nature of event emission
-        emit_payout_event(&env, group_id, recipient, amount, cycle, timestamp);
+        env.as_contract(&contract_id, || {
+            emit_payout_event(&env, group_id, recipient, amount, cycle, timestamp);
+        });
     }
 
     // Test advance_cycle_or_complete with valid group
@@ -1423,8 +2123,8 @@ mod tests {
         let mut group = Group::new(
             1,
             creator,
-            10_000_000i128, // 1 XLM
-            604800,         // 1 week
+            STROOPS_PER_XLM,
+            SECONDS_PER_WEEK,
             3,              // 3 members
             2,              // 2 min members
             1234567890,
@@ -1445,8 +2145,7 @@ mod tests {
         assert!(!group.is_complete());
         
         // Verify group was saved to storage
-        let group_key = StorageKeyBuilder::group_data(group.id);
-        let stored_group: Option<Group> = env.storage().persistent().get(&group_key);
+        let stored_group: Option<Group> = GroupStore::load(&env, group.id);
         assert!(stored_group.is_some());
         assert_eq!(stored_group.unwrap().current_cycle, 1);
     }
@@ -1461,8 +2160,8 @@ mod tests {
         let mut group = Group::new(
             1,
             creator,
-            10_000_000i128,
-            604800,
+            STROOPS_PER_XLM,
+            SECONDS_PER_WEEK,
             3,
             2,
             1234567890,
@@ -1489,8 +2188,7 @@ mod tests {
         assert!(!group.is_active);
         
         // Verify group was saved with completed status
-        let group_key = StorageKeyBuilder::group_data(group.id);
-        let stored_group: Group = env.storage().persistent().get(&group_key).unwrap();
+        let stored_group: Group = GroupStore::load(&env, group.id).unwrap();
         assert_eq!(stored_group.current_cycle, 3);
         assert_eq!(stored_group.status, GroupStatus::Completed);
         assert!(!stored_group.is_active);
@@ -1507,7 +2205,7 @@ mod tests {
             1,
             creator,
             5_000_000i128,
-            604800,
+            SECONDS_PER_WEEK,
             2, // Only 2 members
             2,
             1234567890,
@@ -1540,8 +2238,8 @@ mod tests {
         let mut group = Group::new(
             1,
             creator,
-            10_000_000i128,
-            604800,
+            STROOPS_PER_XLM,
+            SECONDS_PER_WEEK,
             10, // 10 members
             2,
             1234567890,
@@ -1570,8 +2268,8 @@ mod tests {
         let mut group = Group::new(
             42, // Specific group ID
             creator,
-            10_000_000i128,
-            604800,
+            STROOPS_PER_XLM,
+            SECONDS_PER_WEEK,
             3,
             2,
             1234567890,
@@ -1581,8 +2279,7 @@ mod tests {
         assert!(result.is_ok());
         
         // Verify the group was saved with the correct group ID
-        let group_key = StorageKeyBuilder::group_data(42);
-        let stored_group: Option<Group> = env.storage().persistent().get(&group_key);
+        let stored_group: Option<Group> = GroupStore::load(&env, 42);
         assert!(stored_group.is_some());
         assert_eq!(stored_group.unwrap().id, 42);
     }
@@ -1596,8 +2293,8 @@ mod tests {
         let mut group = Group::new(
             1,
             creator,
-            10_000_000i128,
-            604800,
+            STROOPS_PER_XLM,
+            SECONDS_PER_WEEK,
             5,
             2,
             1234567890,
@@ -1622,8 +2319,8 @@ mod tests {
         let mut group = Group::new(
             1,
             creator,
-            10_000_000i128,
-            604800,
+            STROOPS_PER_XLM,
+            SECONDS_PER_WEEK,
             2,
             2,
             1234567890,
@@ -1637,4 +2334,176 @@ mod tests {
         // This should panic because group is already complete
         let _result = advance_cycle_or_complete(&env, &mut group);
     }
+
+    // Test validate_cycle_complete covers a default from the insurance reserve
+    #[test]
+    fn test_validate_cycle_complete_insurance_coverage() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = crate::testutils::register(&env);
+        let client = crate::StellarSaveContractClient::new(&env, &contract_id);
+
+        let group_id = client.create_group(&Address::generate(&env), &100, &SECONDS_PER_WEEK, &3);
+        let mut members = soroban_sdk::Vec::new(&env);
+        for _ in 0..3 {
+            let member = Address::generate(&env);
+            client.join_group(&group_id, &member, &None);
+            members.push_back(member);
+        }
+        client.set_insurance_bps(&group_id, &500);
+        crate::testutils::start_group(&env, &contract_id, group_id);
+        for member in members.iter().take(2) {
+            crate::testutils::contribute(&env, &contract_id, group_id, &member).unwrap();
+        }
+
+        env.as_contract(&contract_id, || {
+            let reserve_key = StorageKeyBuilder::group_insurance_reserve(group_id);
+            env.storage().persistent().set(&reserve_key, &1_000i128);
+
+            // Members may still pay until the deadline, so nothing is covered yet
+            let group = StellarSaveContract::get_group(env.clone(), group_id).unwrap();
+            assert_eq!(
+                validate_cycle_complete(&env, &group, 0),
+                Err(StellarSaveError::CycleNotComplete)
+            );
+        });
+
+        crate::testutils::advance_past_deadline(&env, &contract_id, group_id, 1);
+        env.as_contract(&contract_id, || {
+            let group = StellarSaveContract::get_group(env.clone(), group_id).unwrap();
+            let (_, settlement) = validate_cycle_complete(&env, &group, 0).unwrap();
+
+            // 5% of the 200 collected is the premium; the recipient still gets
+            // the 285 a full pool would have paid
+            assert_eq!(settlement.premium, 10);
+            assert_eq!(settlement.payout_base, 285);
+            assert_eq!(settlement.coverage, 95);
+
+            apply_insurance_settlement(&env, group_id, 0, &settlement, 0).unwrap();
+            assert_eq!(load_insurance_reserve(&env, group_id), 915);
+        });
+    }
+
+    // Test validate_cycle_complete pays a best-effort group short once the
+    // quorum contributed, charging the defaulter
+    #[test]
+    fn test_validate_cycle_complete_best_effort_quorum() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = crate::testutils::register(&env);
+        let client = crate::StellarSaveContractClient::new(&env, &contract_id);
+
+        let group_id = client.create_group(&Address::generate(&env), &100, &SECONDS_PER_WEEK, &4);
+        let mut members = soroban_sdk::Vec::new(&env);
+        for _ in 0..4 {
+            let member = Address::generate(&env);
+            client.join_group(&group_id, &member, &None);
+            members.push_back(member);
+        }
+        client.set_payout_policy(&group_id, &PayoutPolicy::BestEffort(7_500));
+        crate::testutils::start_group(&env, &contract_id, group_id);
+        for member in members.iter().take(2) {
+            crate::testutils::contribute(&env, &contract_id, group_id, &member).unwrap();
+        }
+
+        // Two of four is below the 75% quorum, even after the deadline
+        crate::testutils::advance_past_deadline(&env, &contract_id, group_id, 1);
+        env.as_contract(&contract_id, || {
+            let group = StellarSaveContract::get_group(env.clone(), group_id).unwrap();
+            assert_eq!(
+                validate_cycle_complete(&env, &group, 0),
+                Err(StellarSaveError::CycleNotComplete)
+            );
+        });
+
+        crate::testutils::contribute(&env, &contract_id, group_id, &members.get(2).unwrap())
+            .unwrap();
+        let defaulter = members.get(3).unwrap();
+        env.as_contract(&contract_id, || {
+            let group = StellarSaveContract::get_group(env.clone(), group_id).unwrap();
+            let (_, settlement) = validate_cycle_complete(&env, &group, 0).unwrap();
+            assert_eq!(settlement.payout_base, 300);
+            assert_eq!(settlement.coverage, 0);
+            assert_eq!(settlement.shortfall, 100);
+
+            record_shortfall(&env, &group, 0, settlement.shortfall, 0).unwrap();
+        });
+
+        let event = crate::testutils::expect_event::<CycleShortfall>(&env);
+        assert_eq!(event.shortfall, 100);
+        assert_eq!(event.defaulters, soroban_sdk::vec![&env, defaulter.clone()]);
+        assert_eq!(client.get_member_shortfall(&group_id, &defaulter), 100);
+        assert_eq!(
+            client.get_member_profile(&group_id, &defaulter).missed_contributions,
+            1
+        );
+    }
+
+    // Test validate_cycle_complete rejects running totals that disagree with
+    // the group's terms
+    #[test]
+    fn test_validate_cycle_complete_rejects_inconsistent_pool() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = crate::testutils::register(&env);
+        let client = crate::StellarSaveContractClient::new(&env, &contract_id);
+
+        let group_id = client.create_group(&Address::generate(&env), &100, &SECONDS_PER_WEEK, &2);
+        for _ in 0..2 {
+            client.join_group(&group_id, &Address::generate(&env), &None);
+        }
+        crate::testutils::start_group(&env, &contract_id, group_id);
+        crate::testutils::contribute_all(&env, &contract_id, group_id);
+
+        env.as_contract(&contract_id, || {
+            let group = StellarSaveContract::get_group(env.clone(), group_id).unwrap();
+            assert!(validate_cycle_complete(&env, &group, 0).is_ok());
+
+            // A total inflated behind the accumulator's back
+            let total_key = StorageKeyBuilder::contribution_cycle_total(group_id, 0);
+            env.storage().persistent().set(&total_key, &250i128);
+            assert_eq!(
+                validate_cycle_complete(&env, &group, 0),
+                Err(StellarSaveError::DataCorruption)
+            );
+        });
+    }
+
+    // Test identify_recipient names each way the position scan can fail
+    #[test]
+    fn test_identify_recipient_reports_specific_errors() {
+        let env = Env::default();
+        let contract_id = env.register(crate::StellarSaveContract, ());
+        let group_id = 1u64;
+        let first = Address::generate(&env);
+        let second = Address::generate(&env);
+
+        env.as_contract(&contract_id, || {
+            let members = soroban_sdk::vec![&env, first.clone(), second.clone()];
+            env.storage()
+                .persistent()
+                .set(&StorageKeyBuilder::group_members(group_id), &members);
+            for (member, position) in [(&first, 0u32), (&second, 1u32)] {
+                let profile = MemberProfile::new(member.clone(), group_id, position, 0);
+                MemberStore::save(&env, &profile);
+            }
+
+            assert_eq!(identify_recipient(&env, group_id, 1, 2), Ok(second.clone()));
+            assert_eq!(
+                identify_recipient(&env, group_id, 1, 3),
+                Err(StellarSaveError::MemberCountMismatch)
+            );
+            assert_eq!(
+                identify_recipient(&env, group_id, 2, 2),
+                Err(StellarSaveError::NoRecipientForCycle)
+            );
+
+            let clash = MemberProfile::new(second.clone(), group_id, 0, 0);
+            MemberStore::save(&env, &clash);
+            assert_eq!(
+                identify_recipient(&env, group_id, 0, 2),
+                Err(StellarSaveError::DuplicatePayoutPosition)
+            );
+        });
+    }
 }
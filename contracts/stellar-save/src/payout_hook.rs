@@ -0,0 +1,49 @@
+//! Payout notifications for other contracts.
+//!
+//! A group admin can register a hook contract that is told about every payout
+//! the group makes, so lending protocols, payroll systems or notification
+//! relays can react on-chain. The hook is called once the payout has been
+//! transferred and recorded. It cannot hold up or undo a payout: if the call
+//! fails, its changes are rolled back, a `payout_hook_failed` event is
+//! emitted and the payout goes through as usual.
+
+use crate::events::EventEmitter;
+use crate::storage::StorageKeyBuilder;
+use soroban_sdk::{contractclient, Address, Env};
+
+/// Interface a payout hook contract must implement.
+#[contractclient(name = "PayoutHookClient")]
+pub trait PayoutHook {
+    /// Called after `recipient` has been paid `amount` by group `group_id`.
+    fn on_payout(env: Env, group_id: u64, recipient: Address, amount: i128);
+}
+
+/// Returns the group's payout hook, if one is registered.
+pub fn load_hook(env: &Env, group_id: u64) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&StorageKeyBuilder::group_payout_hook(group_id))
+}
+
+/// Tells the group's hook about a payout, if it has one.
+///
+/// # Returns
+/// Whether a hook was called and accepted the notification.
+pub(crate) fn notify(
+    env: &Env,
+    group_id: u64,
+    cycle: u32,
+    recipient: &Address,
+    amount: i128,
+) -> bool {
+    let Some(hook) = load_hook(env, group_id) else {
+        return false;
+    };
+
+    let result = PayoutHookClient::new(env, &hook).try_on_payout(&group_id, recipient, &amount);
+    let accepted = matches!(result, Ok(Ok(())));
+    if !accepted {
+        EventEmitter::emit_payout_hook_failed(env, group_id, cycle, hook, env.ledger().timestamp());
+    }
+    accepted
+}
@@ -0,0 +1,457 @@
+//! Payout ordering strategies.
+//!
+//! A group's creator picks how the rotation decides who is paid each cycle.
+//! Static orders fix every member's payout position before the rotation
+//! starts, and each cycle pays whoever holds the position numbered after it.
+//! Dynamic orders choose the recipient when the cycle is paid, among the
+//! members still owed a payout; the chosen member then takes over the cycle's
+//! position, so payout records and schedules stay keyed by position.
+
+use crate::error::StellarSaveError;
+use crate::storage::{set_persistent, MemberStore, StorageKeyBuilder};
+use crate::MemberProfile;
+use soroban_sdk::{contracttype, Address, Bytes, Env, Vec};
+
+/// How a group decides the order in which members are paid.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PayoutOrder {
+    /// Members are paid in the order they joined.
+    JoinOrder,
+    /// Each cycle's recipient is drawn from the members still owed a payout.
+    Random,
+    /// The admin sets every position with `assign_payout_positions` before
+    /// the rotation starts; join order applies until they do. Each member
+    /// must accept the position they hold before the group can activate.
+    AdminAssigned,
+    /// The member with the highest priority set by the admin is paid next.
+    NeedBased,
+    /// The member with the highest bid is paid next.
+    Auction,
+}
+
+impl PayoutOrder {
+    /// Whether the recipient is chosen when each cycle is paid rather than
+    /// fixed by position beforehand.
+    pub fn is_dynamic(&self) -> bool {
+        matches!(
+            self,
+            PayoutOrder::Random | PayoutOrder::NeedBased | PayoutOrder::Auction
+        )
+    }
+
+    /// Whether members rank themselves with a claim: a priority set by the
+    /// admin or a bid placed by the member.
+    pub fn uses_claims(&self) -> bool {
+        matches!(self, PayoutOrder::NeedBased | PayoutOrder::Auction)
+    }
+}
+
+/// Members still owed a payout, sorted by payout position.
+struct Candidates {
+    members: Vec<Address>,
+    positions: Vec<u32>,
+    claims: Vec<i128>,
+}
+
+impl Candidates {
+    fn new(env: &Env) -> Self {
+        Self {
+            members: Vec::new(env),
+            positions: Vec::new(env),
+            claims: Vec::new(env),
+        }
+    }
+
+    fn insert(&mut self, member: Address, position: u32, claim: i128) {
+        let index = self
+            .positions
+            .iter()
+            .position(|other| other > position)
+            .unwrap_or(self.positions.len() as usize) as u32;
+        self.members.insert(index, member);
+        self.positions.insert(index, position);
+        self.claims.insert(index, claim);
+    }
+
+    fn len(&self) -> u32 {
+        self.members.len()
+    }
+}
+
+/// A way of choosing a cycle's recipient.
+trait OrderStrategy {
+    /// Picks the index of the recipient among `candidates`, or returns
+    /// `None` to pay whoever holds the cycle's position.
+    fn select(env: &Env, group_id: u64, cycle: u32, candidates: &Candidates) -> Option<u32>;
+}
+
+/// Join order and admin-assigned order: positions decide.
+struct ByPosition;
+
+impl OrderStrategy for ByPosition {
+    fn select(_env: &Env, _group_id: u64, _cycle: u32, _candidates: &Candidates) -> Option<u32> {
+        None
+    }
+}
+
+/// Draws a candidate from a hash of the group and cycle, so a dry run and
+/// the payout that follows agree on the recipient.
+struct Draw;
+
+impl OrderStrategy for Draw {
+    fn select(env: &Env, group_id: u64, cycle: u32, candidates: &Candidates) -> Option<u32> {
+        if candidates.len() == 0 {
+            return None;
+        }
+        let mut seed = Bytes::new(env);
+        seed.extend_from_array(&group_id.to_be_bytes());
+        seed.extend_from_array(&cycle.to_be_bytes());
+        let hash = env.crypto().sha256(&seed).to_array();
+        let mut head = [0u8; 8];
+        head.copy_from_slice(&hash[..8]);
+        Some((u64::from_be_bytes(head) % candidates.len() as u64) as u32)
+    }
+}
+
+/// Highest claim first, earliest position on ties.
+struct HighestClaim;
+
+impl OrderStrategy for HighestClaim {
+    fn select(_env: &Env, _group_id: u64, _cycle: u32, candidates: &Candidates) -> Option<u32> {
+        let mut best: Option<(u32, i128)> = None;
+        for (index, claim) in candidates.claims.iter().enumerate() {
+            if best.is_none_or(|(_, best_claim)| claim > best_claim) {
+                best = Some((index as u32, claim));
+            }
+        }
+        best.map(|(index, _)| index)
+    }
+}
+
+/// Returns the group's payout order, or join order if none was set.
+pub fn load_order(env: &Env, group_id: u64) -> PayoutOrder {
+    env.storage()
+        .persistent()
+        .get(&StorageKeyBuilder::group_payout_order(group_id))
+        .unwrap_or(PayoutOrder::JoinOrder)
+}
+
+/// Returns a member's claim on the next payout, 0 if they have none.
+pub fn load_claim(env: &Env, group_id: u64, member: Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&StorageKeyBuilder::member_payout_claim(group_id, member))
+        .unwrap_or(0)
+}
+
+/// Returns the payout position a member has accepted, if any.
+pub fn load_accepted_position(env: &Env, group_id: u64, member: Address) -> Option<u32> {
+    env.storage()
+        .persistent()
+        .get(&StorageKeyBuilder::member_accepted_position(
+            group_id, member,
+        ))
+}
+
+/// Members of an admin-assigned group who have not accepted the position
+/// they currently hold. Empty under every other order.
+pub fn unaccepted_members(env: &Env, group_id: u64) -> Vec<Address> {
+    let mut unaccepted = Vec::new(env);
+    if load_order(env, group_id) != PayoutOrder::AdminAssigned {
+        return unaccepted;
+    }
+    let members: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&StorageKeyBuilder::group_members(group_id))
+        .unwrap_or(Vec::new(env));
+    for member in members.iter() {
+        let Some(profile) = MemberStore::load(env, group_id, &member) else {
+            continue;
+        };
+        let accepted = load_accepted_position(env, group_id, member.clone());
+        if accepted != Some(profile.payout_position) {
+            unaccepted.push_back(member);
+        }
+    }
+    unaccepted
+}
+
+/// Payout positions held by the group's members, in roster order.
+///
+/// # Errors
+/// - `InvalidState` - A member on the roster has no profile
+pub(crate) fn member_positions(env: &Env, group_id: u64) -> Result<Vec<u32>, StellarSaveError> {
+    let members: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&StorageKeyBuilder::group_members(group_id))
+        .unwrap_or(Vec::new(env));
+    let mut positions = Vec::new(env);
+    for member in members.iter() {
+        let profile =
+            MemberStore::load(env, group_id, &member).ok_or(StellarSaveError::InvalidState)?;
+        positions.push_back(profile.payout_position);
+    }
+    Ok(positions)
+}
+
+/// Checks that no two `positions` are the same and that each is below
+/// `slots`. With `complete`, every position below `slots` must also be held,
+/// so the positions are `0..slots` in some order: the shape a rotation needs
+/// before it starts. A running group may have fewer holders than slots once
+/// members leave.
+///
+/// # Errors
+/// - `InvalidPayoutOrder` - A position repeats or is out of range, or a
+///   complete order leaves one empty
+pub(crate) fn check_positions(
+    env: &Env,
+    positions: &Vec<u32>,
+    slots: u32,
+    complete: bool,
+) -> Result<(), StellarSaveError> {
+    let mut held = Vec::new(env);
+    for _ in 0..slots {
+        held.push_back(false);
+    }
+    for position in positions.iter() {
+        if position >= slots || held.get_unchecked(position) {
+            return Err(StellarSaveError::InvalidPayoutOrder);
+        }
+        held.set(position, true);
+    }
+    if complete && positions.len() != slots {
+        return Err(StellarSaveError::InvalidPayoutOrder);
+    }
+    Ok(())
+}
+
+/// Chooses the recipient of `cycle` under the group's payout order.
+///
+/// # Returns
+/// The recipient for dynamic orders, or `None` when the member holding the
+/// cycle's payout position is the recipient.
+pub(crate) fn select_recipient(
+    env: &Env,
+    group_id: u64,
+    cycle: u32,
+) -> Result<Option<Address>, StellarSaveError> {
+    let order = load_order(env, group_id);
+    if !order.is_dynamic() {
+        return Ok(None);
+    }
+
+    let candidates = unpaid_members(env, group_id, cycle, order.uses_claims());
+    let selected = match order {
+        PayoutOrder::JoinOrder | PayoutOrder::AdminAssigned => {
+            ByPosition::select(env, group_id, cycle, &candidates)
+        }
+        PayoutOrder::Random => Draw::select(env, group_id, cycle, &candidates),
+        PayoutOrder::NeedBased | PayoutOrder::Auction => {
+            HighestClaim::select(env, group_id, cycle, &candidates)
+        }
+    };
+    let index = selected.ok_or(StellarSaveError::NoRecipientForCycle)?;
+    Ok(candidates.members.get(index))
+}
+
+/// Members still owed a payout, with their claims if the order uses them.
+fn unpaid_members(env: &Env, group_id: u64, cycle: u32, with_claims: bool) -> Candidates {
+    let members: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&StorageKeyBuilder::group_members(group_id))
+        .unwrap_or(Vec::new(env));
+
+    let mut candidates = Candidates::new(env);
+    for member in members.iter() {
+        let Some(profile) = MemberStore::load(env, group_id, &member) else {
+            continue;
+        };
+        if profile.has_received_payout || profile.payout_position < cycle {
+            continue;
+        }
+        let claim = if with_claims {
+            load_claim(env, group_id, member.clone())
+        } else {
+            0
+        };
+        candidates.insert(member, profile.payout_position, claim);
+    }
+    candidates
+}
+
+/// Moves `recipient` into the payout position of `cycle`, swapping with the
+/// member who held it. Does nothing if the recipient already holds it.
+///
+/// # Errors
+/// - `NotMember` - The recipient has no profile in the group
+pub(crate) fn seat_recipient(
+    env: &Env,
+    group_id: u64,
+    cycle: u32,
+    recipient: &Address,
+) -> Result<(), StellarSaveError> {
+    let mut profile: MemberProfile =
+        MemberStore::load(env, group_id, recipient).ok_or(StellarSaveError::NotMember)?;
+    let vacated = profile.payout_position;
+    if vacated == cycle {
+        return Ok(());
+    }
+
+    let cycle_index_key = StorageKeyBuilder::group_position_member(group_id, cycle);
+    let vacated_index_key = StorageKeyBuilder::group_position_member(group_id, vacated);
+    match env
+        .storage()
+        .persistent()
+        .get::<_, Address>(&cycle_index_key)
+    {
+        Some(holder) => {
+            if let Some(mut holder_profile) = MemberStore::load(env, group_id, &holder) {
+                holder_profile.payout_position = vacated;
+                MemberStore::save(env, &holder_profile);
+                set_persistent(
+                    env,
+                    &StorageKeyBuilder::member_payout_eligibility(group_id, holder.clone()),
+                    &vacated,
+                );
+            }
+            set_persistent(env, &vacated_index_key, &holder);
+        }
+        None => env.storage().persistent().remove(&vacated_index_key),
+    }
+
+    profile.payout_position = cycle;
+    MemberStore::save(env, &profile);
+    set_persistent(
+        env,
+        &StorageKeyBuilder::member_payout_eligibility(group_id, recipient.clone()),
+        &cycle,
+    );
+    set_persistent(env, &cycle_index_key, recipient);
+    Ok(())
+}
+
+/// Exchanges the payout positions of two members, updating both profiles,
+/// their payout eligibility and the position index together.
+///
+/// # Returns
+/// The positions `first` and `second` hold afterwards
+///
+/// # Errors
+/// - `NotMember` - Either member has no profile in the group
+pub(crate) fn swap_positions(
+    env: &Env,
+    group_id: u64,
+    first: &Address,
+    second: &Address,
+) -> Result<(u32, u32), StellarSaveError> {
+    let first_key = StorageKeyBuilder::member_profile(group_id, first.clone());
+    let second_key = StorageKeyBuilder::member_profile(group_id, second.clone());
+    let mut first_profile: MemberProfile =
+        MemberStore::load(env, group_id, first).ok_or(StellarSaveError::NotMember)?;
+    let mut second_profile: MemberProfile =
+        MemberStore::load(env, group_id, second).ok_or(StellarSaveError::NotMember)?;
+
+    core::mem::swap(
+        &mut first_profile.payout_position,
+        &mut second_profile.payout_position,
+    );
+    for (member, key, profile) in [
+        (first, &first_key, &first_profile),
+        (second, &second_key, &second_profile),
+    ] {
+        set_persistent(env, key, profile);
+        set_persistent(
+            env,
+            &StorageKeyBuilder::member_payout_eligibility(group_id, member.clone()),
+            &profile.payout_position,
+        );
+        set_persistent(
+            env,
+            &StorageKeyBuilder::group_position_member(group_id, profile.payout_position),
+            member,
+        );
+    }
+    Ok((
+        first_profile.payout_position,
+        second_profile.payout_position,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+
+    fn candidates(env: &Env, claims: &[i128]) -> Candidates {
+        let mut candidates = Candidates::new(env);
+        // Inserted out of order to exercise the position sort
+        for (position, claim) in claims.iter().enumerate().rev() {
+            candidates.insert(Address::generate(env), position as u32, *claim);
+        }
+        candidates
+    }
+
+    #[test]
+    fn test_order_kinds() {
+        assert!(!PayoutOrder::JoinOrder.is_dynamic());
+        assert!(!PayoutOrder::AdminAssigned.is_dynamic());
+        assert!(PayoutOrder::Random.is_dynamic());
+        assert!(PayoutOrder::NeedBased.uses_claims());
+        assert!(PayoutOrder::Auction.uses_claims());
+        assert!(!PayoutOrder::Random.uses_claims());
+    }
+
+    #[test]
+    fn test_highest_claim_prefers_earliest_position_on_ties() {
+        let env = Env::default();
+        assert_eq!(
+            HighestClaim::select(&env, 1, 0, &candidates(&env, &[5, 9, 9, 2])),
+            Some(1)
+        );
+        assert_eq!(
+            HighestClaim::select(&env, 1, 0, &candidates(&env, &[0, 0, 0])),
+            Some(0)
+        );
+        assert_eq!(
+            HighestClaim::select(&env, 1, 0, &Candidates::new(&env)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_check_positions() {
+        let env = Env::default();
+        let positions = |list: &[u32]| Vec::from_slice(&env, list);
+        assert_eq!(
+            check_positions(&env, &positions(&[2, 0, 1]), 3, true),
+            Ok(())
+        );
+        assert_eq!(check_positions(&env, &positions(&[2, 0]), 3, false), Ok(()));
+        for (list, complete) in [
+            (&[0, 1, 1][..], false),
+            (&[0, 1, 3][..], false),
+            (&[2, 0][..], true),
+        ] {
+            assert_eq!(
+                check_positions(&env, &positions(list), 3, complete),
+                Err(StellarSaveError::InvalidPayoutOrder)
+            );
+        }
+    }
+
+    #[test]
+    fn test_draw_is_deterministic_and_in_range() {
+        let env = Env::default();
+        let pool = candidates(&env, &[0, 0, 0, 0, 0]);
+        for cycle in 0..10 {
+            let first = Draw::select(&env, 7, cycle, &pool).unwrap();
+            assert!(first < pool.len());
+            assert_eq!(Draw::select(&env, 7, cycle, &pool), Some(first));
+        }
+        assert_eq!(Draw::select(&env, 7, 0, &Candidates::new(&env)), None);
+    }
+}
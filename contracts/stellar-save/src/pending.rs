@@ -0,0 +1,80 @@
+//! Pending deposits.
+//!
+//! A contribution paid right at a cycle boundary can land in the cycle that
+//! is about to close instead of the one the member meant to pay. A pending
+//! deposit avoids that: the member, or a sponsor paying for them, parks one
+//! contribution against a named cycle, and it is applied only once that
+//! cycle is the group's open cycle, when the payout before it moves the
+//! group on, when the member calls `contribute`, or through `apply_pending`.
+//! A deposit whose cycle went by without it being applied moves into the
+//! member's credit and pays their next cycle instead. Until it is applied,
+//! the depositor can take it back.
+
+use crate::storage::{set_persistent, StorageKeyBuilder};
+use soroban_sdk::{contracttype, Address, Env};
+
+/// A contribution parked for a member until its cycle opens.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingDeposit {
+    pub member: Address,
+    /// Who paid the deposit: the member or a sponsor
+    pub depositor: Address,
+    /// Cycle the deposit pays
+    pub cycle: u32,
+    pub amount: i128,
+    pub parked_at: u64,
+}
+
+/// Returns the deposit parked for a member, if any.
+pub fn load(env: &Env, group_id: u64, member: Address) -> Option<PendingDeposit> {
+    env.storage()
+        .persistent()
+        .get(&StorageKeyBuilder::member_pending_deposit(group_id, member))
+}
+
+/// Parks a deposit for its member, replacing any already parked.
+pub fn store(env: &Env, group_id: u64, deposit: &PendingDeposit) {
+    set_persistent(
+        env,
+        &StorageKeyBuilder::member_pending_deposit(group_id, deposit.member.clone()),
+        deposit,
+    );
+}
+
+/// Takes the deposit parked for a member, if any.
+pub fn take(env: &Env, group_id: u64, member: Address) -> Option<PendingDeposit> {
+    let key = StorageKeyBuilder::member_pending_deposit(group_id, member);
+    let deposit = env.storage().persistent().get(&key);
+    env.storage().persistent().remove(&key);
+    deposit
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+
+    #[test]
+    fn test_store_and_take() {
+        let env = Env::default();
+        let contract_id = crate::testutils::register(&env);
+        let member = Address::generate(&env);
+        let deposit = PendingDeposit {
+            member: member.clone(),
+            depositor: Address::generate(&env),
+            cycle: 2,
+            amount: 100,
+            parked_at: 0,
+        };
+
+        env.as_contract(&contract_id, || {
+            assert_eq!(load(&env, 1, member.clone()), None);
+            store(&env, 1, &deposit);
+            assert_eq!(load(&env, 1, member.clone()), Some(deposit.clone()));
+            assert_eq!(load(&env, 2, member.clone()), None);
+            assert_eq!(take(&env, 1, member.clone()), Some(deposit));
+            assert_eq!(take(&env, 1, member), None);
+        });
+    }
+}
@@ -1,6 +1,7 @@
 use crate::error::StellarSaveError;
-use crate::storage::StorageKeyBuilder;
-use soroban_sdk::{contracttype, Env};
+use crate::storage::{set_persistent, GroupStore, StorageKeyBuilder};
+use crate::units;
+use soroban_sdk::{contracttype, Env, Vec};
 
 /// Pool calculation and management for rotational savings groups.
 ///
@@ -11,7 +12,9 @@ use soroban_sdk::{contracttype, Env};
 /// - Pool return amount calculations
 ///
 /// The pool represents the total funds available for distribution in a cycle,
-/// calculated as: pool_amount = contribution_amount × member_count
+/// calculated as: pool_amount = contribution_amount × member_count, where a
+/// group whose recipients do not contribute leaves the cycle's recipients
+/// out of `member_count`
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct PoolInfo {
@@ -21,7 +24,8 @@ pub struct PoolInfo {
     /// Current cycle number (0-indexed)
     pub cycle: u32,
 
-    /// Total number of members in the group
+    /// Number of members owing the cycle a contribution: every member, less
+    /// the cycle's recipients in a group where they do not contribute
     pub member_count: u32,
 
     /// Fixed contribution amount per member in stroops
@@ -59,6 +63,14 @@ impl PoolInfo {
         self.member_count.saturating_sub(self.contributors_count)
     }
 
+    /// The cycle's running totals this pool was built from.
+    pub fn cycle_pool(&self) -> CyclePool {
+        CyclePool {
+            total: self.current_contributions,
+            contributors: self.contributors_count,
+        }
+    }
+
     /// Calculates the percentage of cycle completion (0-100).
     pub fn completion_percentage(&self) -> u32 {
         if self.member_count == 0 {
@@ -68,6 +80,152 @@ impl PoolInfo {
     }
 }
 
+/// Running totals of one cycle's contributions.
+///
+/// Updated as each contribution is recorded or reversed, so a cycle's pool is
+/// read in constant time instead of by summing its contribution records. The
+/// two fields live under the existing `CONTRIB_TOTAL` and `CONTRIB_COUNT`
+/// keys, so groups created before the accumulator was introduced read the
+/// same values.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CyclePool {
+    /// Amount contributed to the cycle so far
+    pub total: i128,
+    /// Number of members who have contributed to the cycle
+    pub contributors: u32,
+}
+
+impl CyclePool {
+    /// Reads a cycle's totals; a cycle nobody has paid into is empty.
+    pub fn load(env: &Env, group_id: u64, cycle: u32) -> Self {
+        let storage = env.storage().persistent();
+        CyclePool {
+            total: storage
+                .get(&StorageKeyBuilder::contribution_cycle_total(
+                    group_id, cycle,
+                ))
+                .unwrap_or(0),
+            contributors: storage
+                .get(&StorageKeyBuilder::contribution_cycle_count(
+                    group_id, cycle,
+                ))
+                .unwrap_or(0),
+        }
+    }
+
+    /// Writes the cycle's totals back.
+    pub fn store(&self, env: &Env, group_id: u64, cycle: u32) {
+        set_persistent(
+            env,
+            &StorageKeyBuilder::contribution_cycle_total(group_id, cycle),
+            &self.total,
+        );
+        set_persistent(
+            env,
+            &StorageKeyBuilder::contribution_cycle_count(group_id, cycle),
+            &self.contributors,
+        );
+    }
+
+    /// Counts one more contribution of `amount`.
+    pub fn add(&mut self, amount: i128) -> Result<(), StellarSaveError> {
+        self.total = self
+            .total
+            .checked_add(amount)
+            .ok_or(StellarSaveError::Overflow)?;
+        self.contributors = self
+            .contributors
+            .checked_add(1)
+            .ok_or(StellarSaveError::Overflow)?;
+        Ok(())
+    }
+
+    /// Takes a reversed contribution of `amount` back out.
+    pub fn remove(&mut self, amount: i128) {
+        self.total = (self.total - amount).max(0);
+        self.contributors = self.contributors.saturating_sub(1);
+    }
+
+    /// Whether the totals agree with the group's terms: no more contributors
+    /// than members, each having paid exactly `contribution_amount`.
+    pub fn is_consistent(&self, contribution_amount: i128, member_count: u32) -> bool {
+        self.contributors <= member_count
+            && contribution_amount
+                .checked_mul(self.contributors as i128)
+                .is_some_and(|expected| expected == self.total)
+    }
+}
+
+/// Upper bound on the protocol fee (10%).
+pub const MAX_FEE_BPS: u32 = 1_000;
+
+/// Upper bound on a group's insurance premium (5%).
+pub const MAX_INSURANCE_BPS: u32 = 500;
+
+/// Share of a member's contributions forfeited on emergency withdrawal
+/// unless the group sets its own (10%).
+pub const DEFAULT_EMERGENCY_PENALTY_BPS: u32 = 1_000;
+
+/// Upper bound on a group's emergency withdrawal penalty (50%).
+pub const MAX_EMERGENCY_PENALTY_BPS: u32 = 5_000;
+
+/// Upper bound on a group's late payout compensation (1% per day).
+pub const MAX_LATE_PAYOUT_COMPENSATION_BPS: u32 = 100;
+
+/// How a cycle's pool is split between the recipient and the group's
+/// insurance reserve.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InsuranceSettlement {
+    /// Premium withheld from the contributions collected this cycle
+    pub premium: i128,
+    /// Amount drawn from the reserve to replace missing contributions
+    pub coverage: i128,
+    /// Pool the payout is calculated from, net of the premium
+    pub payout_base: i128,
+    /// Missing contributions neither covered nor paid out, owed by the
+    /// members who defaulted
+    pub shortfall: i128,
+}
+
+impl InsuranceSettlement {
+    /// Reserve balance after the premium is credited and the coverage drawn.
+    pub fn reserve_after(&self, reserve: i128) -> Result<i128, StellarSaveError> {
+        reserve
+            .checked_add(self.premium)
+            .and_then(|balance| balance.checked_sub(self.coverage))
+            .ok_or(StellarSaveError::Overflow)
+    }
+}
+
+/// Where the part of an amount that does not split evenly among a cycle's
+/// recipients goes.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RoundingPolicy {
+    /// Credited to the protocol treasury with the fee
+    RemainderToTreasury,
+    /// Paid to the cycle's first recipient
+    RemainderToRecipient,
+    /// Held back and added to the next cycle's pool
+    CarryToNextCycle,
+}
+
+/// An amount split equally among a cycle's recipients, with its remainder
+/// placed by a `RoundingPolicy`. Exactly one of the remainder fields is
+/// non-zero, and only when the amount does not split evenly.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Allocation {
+    /// Paid to each recipient
+    pub share: i128,
+    /// Paid to the first recipient on top of their share
+    pub to_recipient: i128,
+    /// Credited to the treasury
+    pub to_treasury: i128,
+    /// Held back for the next cycle
+    pub carried: i128,
+}
+
 /// Pool calculation functions for the Stellar-Save contract.
 pub struct PoolCalculator;
 
@@ -141,15 +299,38 @@ impl PoolCalculator {
     /// * `Ok(contribution_amount)` - The fixed contribution amount in stroops
     /// * `Err(StellarSaveError)` - If group not found or storage error
     pub fn get_contribution_amount(env: &Env, group_id: u64) -> Result<i128, StellarSaveError> {
-        let group_key = StorageKeyBuilder::group_data(group_id);
+        let group: crate::group::Group =
+            GroupStore::load(env, group_id).ok_or(StellarSaveError::GroupNotFound)?;
 
-        let group: crate::group::Group = env
-            .storage()
+        Ok(group.contribution_amount)
+    }
+
+    /// Whether a group's recipients pay into the pool of the cycle that pays
+    /// them. True unless the group admin has exempted them.
+    pub fn recipient_contributes(env: &Env, group_id: u64) -> bool {
+        env.storage()
             .persistent()
-            .get(&group_key)
-            .ok_or(StellarSaveError::GroupNotFound)?;
+            .get(&StorageKeyBuilder::group_recipient_contributes(group_id))
+            .unwrap_or(true)
+    }
 
-        Ok(group.contribution_amount)
+    /// Number of members excused from contributing to a cycle: the
+    /// recipients it pays, in a group where they do not contribute, and
+    /// nobody otherwise.
+    pub fn get_exempt_count(env: &Env, group: &crate::group::Group, cycle: u32) -> u32 {
+        if Self::recipient_contributes(env, group.id) {
+            return 0;
+        }
+        let positions = group.cycle_positions(cycle);
+        positions.end - positions.start
+    }
+
+    /// Whether the member at payout `position` is excused from contributing
+    /// to `cycle`, being one of the recipients it pays in a group where they
+    /// do not contribute.
+    pub fn is_exempt(env: &Env, group: &crate::group::Group, cycle: u32, position: u32) -> bool {
+        !Self::recipient_contributes(env, group.id)
+            && group.cycle_positions(cycle).contains(&position)
     }
 
     /// Retrieves the current cycle contributions total from storage.
@@ -212,20 +393,23 @@ impl PoolCalculator {
         group_id: u64,
         cycle: u32,
     ) -> Result<PoolInfo, StellarSaveError> {
-        // Get member count
-        let member_count = Self::get_member_count(env, group_id)?;
+        // Get the number of members owing a contribution: the cycle's
+        // recipients are left out where they do not contribute
+        let group: crate::group::Group =
+            GroupStore::load(env, group_id).ok_or(StellarSaveError::GroupNotFound)?;
+        let member_count = Self::get_member_count(env, group_id)?
+            .saturating_sub(Self::get_exempt_count(env, &group, cycle));
 
         // Get contribution amount
-        let contribution_amount = Self::get_contribution_amount(env, group_id)?;
+        let contribution_amount = group.contribution_amount;
 
         // Calculate total pool
         let total_pool_amount = Self::calculate_total_pool(contribution_amount, member_count)?;
 
-        // Get current cycle contributions
-        let current_contributions = Self::get_cycle_contributions_total(env, group_id, cycle)?;
-
-        // Get contributor count
-        let contributors_count = Self::get_cycle_contributor_count(env, group_id, cycle)?;
+        // Get the cycle's running contribution totals
+        let pool = CyclePool::load(env, group_id, cycle);
+        let current_contributions = pool.total;
+        let contributors_count = pool.contributors;
 
         // Determine if cycle is complete
         let is_cycle_complete = contributors_count >= member_count;
@@ -268,24 +452,55 @@ impl PoolCalculator {
         Ok(())
     }
 
+    /// Reads the protocol fee from storage.
+    ///
+    /// Returns 0 if the contract has not been initialized with a fee.
+    pub fn get_fee_bps(env: &Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&StorageKeyBuilder::protocol_fee_bps())
+            .unwrap_or(0)
+    }
+
+    /// Calculates the protocol fee taken from a pool.
+    ///
+    /// The fee is rounded down, so any remainder stays with the recipient.
+    ///
+    /// # Arguments
+    /// * `total_pool` - The total amount accumulated in the cycle pool
+    /// * `fee_bps` - Protocol fee in basis points
+    ///
+    /// # Returns
+    /// * `Ok(fee)` - The amount credited to the treasury
+    /// * `Err(StellarSaveError)` - If the inputs are invalid or overflow
+    pub fn calculate_fee(total_pool: i128, fee_bps: u32) -> Result<i128, StellarSaveError> {
+        if total_pool < 0 || fee_bps > MAX_FEE_BPS {
+            return Err(StellarSaveError::InvalidAmount);
+        }
+
+        units::bps_of(total_pool, fee_bps).ok_or(StellarSaveError::Overflow)
+    }
+
     /// Calculates the net payout amount for a cycle.
     ///
-    /// This function takes the total pool amount and subtracts any applicable fees.
-    /// In v1, fees are 0, so the net payout equals the total pool.
+    /// This function takes the total pool amount and subtracts the protocol fee.
     ///
     /// # Arguments
     /// * `total_pool` - The total amount accumulated in the cycle pool
+    /// * `fee_bps` - Protocol fee in basis points
     ///
     /// # Returns
     /// * `Ok(net_payout)` - The amount to be paid out to the recipient
     /// * `Err(StellarSaveError)` - If calculation fails
-    pub fn calculate_payout_amount(total_pool: i128) -> Result<i128, StellarSaveError> {
+    pub fn calculate_payout_amount(
+        total_pool: i128,
+        fee_bps: u32,
+    ) -> Result<i128, StellarSaveError> {
         if total_pool < 0 {
             return Err(StellarSaveError::InvalidAmount);
         }
 
-        // v1: 0 fees
-        let fees = 0i128;
+        let fees = Self::calculate_fee(total_pool, fee_bps)?;
 
         let net_payout = total_pool
             .checked_sub(fees)
@@ -293,11 +508,215 @@ impl PoolCalculator {
 
         Ok(net_payout)
     }
+
+    /// Settles a cycle's pool against the group's insurance reserve.
+    ///
+    /// The premium is taken from what was actually collected, while the
+    /// payout base is always the full pool net of its premium, so a covered
+    /// default leaves the recipient's payout unchanged. Any gap between the
+    /// two is drawn from the reserve. With `insurance_bps` of 0 there is no
+    /// premium and the pool must be complete.
+    ///
+    /// # Arguments
+    /// * `pool_info` - The cycle's pool information
+    /// * `insurance_bps` - The group's insurance premium in basis points
+    /// * `reserve` - The reserve balance before this cycle
+    ///
+    /// # Returns
+    /// * `Ok(InsuranceSettlement)` - The premium, coverage and payout base
+    /// * `Err(StellarSaveError)` - If the reserve cannot cover the shortfall
+    ///
+    /// # Errors
+    /// - `CycleNotComplete` if the reserve plus premium is below the shortfall
+    /// - `InvalidAmount` if the premium exceeds `MAX_INSURANCE_BPS`
+    /// - `Overflow` if the arithmetic overflows
+    pub fn settle_insurance(
+        pool_info: &PoolInfo,
+        insurance_bps: u32,
+        reserve: i128,
+    ) -> Result<InsuranceSettlement, StellarSaveError> {
+        if insurance_bps > MAX_INSURANCE_BPS {
+            return Err(StellarSaveError::InvalidAmount);
+        }
+
+        let premium = units::bps_of(pool_info.current_contributions, insurance_bps)
+            .ok_or(StellarSaveError::Overflow)?;
+        let payout_base = units::bps_of(pool_info.total_pool_amount, insurance_bps)
+            .and_then(|full_premium| pool_info.total_pool_amount.checked_sub(full_premium))
+            .ok_or(StellarSaveError::Overflow)?;
+        let collected = pool_info
+            .current_contributions
+            .checked_sub(premium)
+            .ok_or(StellarSaveError::Overflow)?;
+        let coverage = payout_base.saturating_sub(collected).max(0);
+
+        let settlement = InsuranceSettlement {
+            premium,
+            coverage,
+            payout_base,
+            shortfall: 0,
+        };
+        if settlement.reserve_after(reserve)? < 0 {
+            return Err(StellarSaveError::CycleNotComplete);
+        }
+
+        Ok(settlement)
+    }
+
+    /// Settles an incomplete cycle that pays out what was collected under a
+    /// best-effort payout policy.
+    ///
+    /// The premium is taken from the collected contributions as usual, but
+    /// nothing is drawn from the reserve: the recipient is paid from what is
+    /// left, and the missing contributions become the shortfall.
+    ///
+    /// # Errors
+    /// - `InvalidAmount` if the premium exceeds `MAX_INSURANCE_BPS`
+    /// - `Overflow` if the arithmetic overflows
+    pub fn settle_partial(
+        pool_info: &PoolInfo,
+        insurance_bps: u32,
+    ) -> Result<InsuranceSettlement, StellarSaveError> {
+        if insurance_bps > MAX_INSURANCE_BPS {
+            return Err(StellarSaveError::InvalidAmount);
+        }
+
+        let premium = units::bps_of(pool_info.current_contributions, insurance_bps)
+            .ok_or(StellarSaveError::Overflow)?;
+        let payout_base = pool_info
+            .current_contributions
+            .checked_sub(premium)
+            .ok_or(StellarSaveError::Overflow)?;
+        let shortfall = pool_info
+            .total_pool_amount
+            .checked_sub(pool_info.current_contributions)
+            .ok_or(StellarSaveError::Overflow)?
+            .max(0);
+
+        Ok(InsuranceSettlement {
+            premium,
+            coverage: 0,
+            payout_base,
+            shortfall,
+        })
+    }
+
+    /// Splits `amount` equally among `recipients`, placing whatever does not
+    /// split evenly as `policy` says.
+    ///
+    /// Every stroop is accounted for: the shares and the remainder always
+    /// add up to `amount`.
+    ///
+    /// # Errors
+    /// - `InvalidAmount` if the amount is negative
+    /// - `InvalidState` if there are no recipients
+    pub fn allocate(
+        amount: i128,
+        recipients: u32,
+        policy: RoundingPolicy,
+    ) -> Result<Allocation, StellarSaveError> {
+        if amount < 0 {
+            return Err(StellarSaveError::InvalidAmount);
+        }
+        if recipients == 0 {
+            return Err(StellarSaveError::InvalidState);
+        }
+
+        let share = amount / recipients as i128;
+        let remainder = amount - share * recipients as i128;
+        let mut allocation = Allocation {
+            share,
+            to_recipient: 0,
+            to_treasury: 0,
+            carried: 0,
+        };
+        match policy {
+            RoundingPolicy::RemainderToTreasury => allocation.to_treasury = remainder,
+            RoundingPolicy::RemainderToRecipient => allocation.to_recipient = remainder,
+            RoundingPolicy::CarryToNextCycle => allocation.carried = remainder,
+        }
+        Ok(allocation)
+    }
+
+    /// Splits `amount` in proportion to `weights`, rounding each share down
+    /// and giving what is left to the last recipient with a positive weight.
+    ///
+    /// Every stroop is accounted for: the shares always add up to `amount`.
+    ///
+    /// # Errors
+    /// - `InvalidAmount` if the amount or a weight is negative
+    /// - `InvalidState` if no weight is positive
+    /// - `Overflow` if the arithmetic overflows
+    pub fn split_pro_rata(
+        env: &Env,
+        amount: i128,
+        weights: &Vec<i128>,
+    ) -> Result<Vec<i128>, StellarSaveError> {
+        if amount < 0 {
+            return Err(StellarSaveError::InvalidAmount);
+        }
+        let mut total: i128 = 0;
+        let mut last = None;
+        for (index, weight) in weights.iter().enumerate() {
+            if weight < 0 {
+                return Err(StellarSaveError::InvalidAmount);
+            }
+            if weight > 0 {
+                last = Some(index as u32);
+            }
+            total = total
+                .checked_add(weight)
+                .ok_or(StellarSaveError::Overflow)?;
+        }
+        let last = last.ok_or(StellarSaveError::InvalidState)?;
+
+        let mut shares = Vec::new(env);
+        let mut remaining = amount;
+        for weight in weights.iter() {
+            let share = amount
+                .checked_mul(weight)
+                .ok_or(StellarSaveError::Overflow)?
+                / total;
+            remaining -= share;
+            shares.push_back(share);
+        }
+        shares.set(last, shares.get_unchecked(last) + remaining);
+        Ok(shares)
+    }
+
+    /// Calculates the compensation owed on a payout executed `delay_secs`
+    /// after it fell due, accruing `bps_per_day` of the payout per day.
+    ///
+    /// Accrues by the second and rounds down, so a payout a few seconds late
+    /// earns nothing.
+    ///
+    /// # Errors
+    /// - `InvalidAmount` if the amount is negative or the rate exceeds
+    ///   `MAX_LATE_PAYOUT_COMPENSATION_BPS`
+    /// - `Overflow` if the arithmetic overflows
+    pub fn calculate_late_compensation(
+        amount: i128,
+        bps_per_day: u32,
+        delay_secs: u64,
+    ) -> Result<i128, StellarSaveError> {
+        if amount < 0 || bps_per_day > MAX_LATE_PAYOUT_COMPENSATION_BPS {
+            return Err(StellarSaveError::InvalidAmount);
+        }
+
+        amount
+            .checked_mul(bps_per_day as i128)
+            .and_then(|scaled| scaled.checked_mul(delay_secs as i128))
+            .map(|scaled| {
+                scaled / (units::BPS_DENOMINATOR as i128 * units::SECONDS_PER_DAY as i128)
+            })
+            .ok_or(StellarSaveError::Overflow)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::units::STROOPS_PER_XLM;
 
     #[test]
     fn test_calculate_total_pool_valid() {
@@ -307,7 +726,7 @@ mod tests {
         let result = PoolCalculator::calculate_total_pool(contribution, member_count);
 
         assert!(result.is_ok());
-        assert_eq!(result.unwrap(), 10_000_000i128);
+        assert_eq!(result.unwrap(), STROOPS_PER_XLM);
     }
 
     #[test]
@@ -479,7 +898,7 @@ mod tests {
             cycle: 0,
             member_count: 10,
             contribution_amount: 1_000_000i128,
-            total_pool_amount: 10_000_000i128,
+            total_pool_amount: STROOPS_PER_XLM,
             current_contributions: 5_000_000i128,
             contributors_count: 5,
             is_cycle_complete: false,
@@ -650,8 +1069,8 @@ mod tests {
         let test_cases: [(i128, u32, i128); 4] = [
             (1_000_000i128, 2u32, 2_000_000i128),
             (5_000_000i128, 5u32, 25_000_000i128),
-            (10_000_000i128, 20u32, 200_000_000i128),
-            (100_000i128, 100u32, 10_000_000i128),
+            (STROOPS_PER_XLM, 20u32, 200_000_000i128),
+            (100_000i128, 100u32, STROOPS_PER_XLM),
         ];
 
         for (contribution, members, expected) in test_cases.iter() {
@@ -662,18 +1081,53 @@ mod tests {
     }
 
     #[test]
-    fn test_calculate_payout_amount_v1() {
-        let total_pool = 10_000_000i128;
-        let result = PoolCalculator::calculate_payout_amount(total_pool);
+    fn test_calculate_payout_amount_no_fee() {
+        let total_pool = STROOPS_PER_XLM;
+        let result = PoolCalculator::calculate_payout_amount(total_pool, 0);
 
         assert!(result.is_ok());
-        assert_eq!(result.unwrap(), 10_000_000i128); // 0 fees in v1
+        assert_eq!(result.unwrap(), STROOPS_PER_XLM);
+    }
+
+    #[test]
+    fn test_calculate_payout_amount_with_fee() {
+        // 1.5% of 10_000_000 = 150_000
+        let result = PoolCalculator::calculate_payout_amount(STROOPS_PER_XLM, 150);
+        assert_eq!(result.unwrap(), 9_850_000i128);
+    }
+
+    #[test]
+    fn test_calculate_fee_rounds_down() {
+        // 1 bps of 9_999 = 0.9999, rounds to 0
+        assert_eq!(PoolCalculator::calculate_fee(9_999, 1).unwrap(), 0);
+        assert_eq!(PoolCalculator::calculate_fee(10_000, 1).unwrap(), 1);
+    }
+
+    #[test]
+    fn test_calculate_late_compensation_accrues_per_second() {
+        let day = units::SECONDS_PER_DAY;
+        let accrue = |bps, secs| PoolCalculator::calculate_late_compensation(10_000, bps, secs);
+
+        assert_eq!(accrue(50, 0), Ok(0));
+        assert_eq!(accrue(50, day), Ok(50));
+        assert_eq!(accrue(50, day / 2), Ok(25));
+        assert_eq!(accrue(1, day / 2), Ok(0));
+        assert_eq!(
+            accrue(MAX_LATE_PAYOUT_COMPENSATION_BPS + 1, day),
+            Err(StellarSaveError::InvalidAmount)
+        );
+    }
+
+    #[test]
+    fn test_calculate_fee_above_max() {
+        let result = PoolCalculator::calculate_fee(STROOPS_PER_XLM, MAX_FEE_BPS + 1);
+        assert_eq!(result.unwrap_err(), StellarSaveError::InvalidAmount);
     }
 
     #[test]
     fn test_calculate_payout_amount_zero() {
         let total_pool = 0i128;
-        let result = PoolCalculator::calculate_payout_amount(total_pool);
+        let result = PoolCalculator::calculate_payout_amount(total_pool, 0);
 
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), 0i128);
@@ -682,9 +1136,216 @@ mod tests {
     #[test]
     fn test_calculate_payout_amount_invalid() {
         let total_pool = -1_000_000i128;
-        let result = PoolCalculator::calculate_payout_amount(total_pool);
+        let result = PoolCalculator::calculate_payout_amount(total_pool, 0);
 
         assert!(result.is_err());
         assert_eq!(result.unwrap_err(), StellarSaveError::InvalidAmount);
     }
+
+    fn cycle_pool(contributors_count: u32) -> PoolInfo {
+        PoolInfo {
+            group_id: 1,
+            cycle: 0,
+            member_count: 5,
+            contribution_amount: 1_000_000i128,
+            total_pool_amount: 5_000_000i128,
+            current_contributions: 1_000_000i128 * contributors_count as i128,
+            contributors_count,
+            is_cycle_complete: contributors_count >= 5,
+        }
+    }
+
+    #[test]
+    fn test_settle_insurance_full_pool() {
+        // 2% of 5_000_000 = 100_000 goes to the reserve
+        let settlement = PoolCalculator::settle_insurance(&cycle_pool(5), 200, 0).unwrap();
+
+        assert_eq!(settlement.premium, 100_000);
+        assert_eq!(settlement.coverage, 0);
+        assert_eq!(settlement.payout_base, 4_900_000);
+        assert_eq!(settlement.reserve_after(0), Ok(100_000));
+    }
+
+    #[test]
+    fn test_settle_insurance_covers_default() {
+        // One member missing: 4_000_000 collected, 80_000 premium
+        let settlement = PoolCalculator::settle_insurance(&cycle_pool(4), 200, 1_000_000).unwrap();
+
+        assert_eq!(settlement.premium, 80_000);
+        assert_eq!(settlement.payout_base, 4_900_000);
+        assert_eq!(settlement.coverage, 980_000);
+        assert_eq!(settlement.reserve_after(1_000_000), Ok(100_000));
+    }
+
+    #[test]
+    fn test_settle_insurance_reserve_too_small() {
+        let result = PoolCalculator::settle_insurance(&cycle_pool(4), 200, 899_999);
+        assert_eq!(result, Err(StellarSaveError::CycleNotComplete));
+
+        assert!(PoolCalculator::settle_insurance(&cycle_pool(4), 200, 900_000).is_ok());
+    }
+
+    #[test]
+    fn test_settle_insurance_uninsured() {
+        let settlement = PoolCalculator::settle_insurance(&cycle_pool(5), 0, 0).unwrap();
+        assert_eq!(settlement.premium, 0);
+        assert_eq!(settlement.payout_base, 5_000_000);
+
+        let result = PoolCalculator::settle_insurance(&cycle_pool(4), 0, 0);
+        assert_eq!(result, Err(StellarSaveError::CycleNotComplete));
+
+        let result = PoolCalculator::settle_insurance(&cycle_pool(5), MAX_INSURANCE_BPS + 1, 0);
+        assert_eq!(result, Err(StellarSaveError::InvalidAmount));
+    }
+
+    #[test]
+    fn test_settle_partial() {
+        // One member missing: 4_000_000 collected, 80_000 premium
+        let settlement = PoolCalculator::settle_partial(&cycle_pool(4), 200).unwrap();
+        assert_eq!(settlement.premium, 80_000);
+        assert_eq!(settlement.coverage, 0);
+        assert_eq!(settlement.payout_base, 3_920_000);
+        assert_eq!(settlement.shortfall, 1_000_000);
+
+        let settlement = PoolCalculator::settle_partial(&cycle_pool(3), 0).unwrap();
+        assert_eq!(settlement.payout_base, 3_000_000);
+        assert_eq!(settlement.shortfall, 2_000_000);
+    }
+
+    #[test]
+    fn test_cycle_pool_accumulates() {
+        let mut pool = CyclePool::default();
+        pool.add(100).unwrap();
+        pool.add(100).unwrap();
+        assert_eq!(
+            pool,
+            CyclePool {
+                total: 200,
+                contributors: 2
+            }
+        );
+        assert!(pool.is_consistent(100, 3));
+
+        pool.remove(100);
+        assert_eq!(pool.total, 100);
+        assert_eq!(pool.contributors, 1);
+
+        pool.total = i128::MAX;
+        assert_eq!(pool.add(1), Err(StellarSaveError::Overflow));
+    }
+
+    #[test]
+    fn test_cycle_pool_consistency() {
+        let pool = CyclePool {
+            total: 300,
+            contributors: 3,
+        };
+        assert!(pool.is_consistent(100, 3));
+        // More contributors than members
+        assert!(!pool.is_consistent(100, 2));
+        // A total that is not one contribution per contributor
+        assert!(!pool.is_consistent(90, 3));
+        assert!(CyclePool::default().is_consistent(100, 3));
+    }
+
+    const POLICIES: [RoundingPolicy; 3] = [
+        RoundingPolicy::RemainderToTreasury,
+        RoundingPolicy::RemainderToRecipient,
+        RoundingPolicy::CarryToNextCycle,
+    ];
+
+    fn remainder(allocation: &Allocation) -> i128 {
+        allocation.to_recipient + allocation.to_treasury + allocation.carried
+    }
+
+    #[test]
+    fn test_split_pro_rata() {
+        let env = Env::default();
+        let split = |amount, weights: &[i128]| {
+            PoolCalculator::split_pro_rata(&env, amount, &Vec::from_slice(&env, weights))
+        };
+
+        assert_eq!(
+            split(300, &[100, 200]),
+            Ok(Vec::from_array(&env, [100, 200]))
+        );
+        // Dust goes to the last positive weight, not a trailing zero
+        assert_eq!(
+            split(100, &[1, 1, 1, 0]),
+            Ok(Vec::from_array(&env, [33, 33, 34, 0]))
+        );
+        assert_eq!(split(0, &[5, 5]), Ok(Vec::from_array(&env, [0, 0])));
+        assert_eq!(split(100, &[0, 0]), Err(StellarSaveError::InvalidState));
+        assert_eq!(split(-1, &[1]), Err(StellarSaveError::InvalidAmount));
+        assert_eq!(split(100, &[1, -1]), Err(StellarSaveError::InvalidAmount));
+        assert_eq!(split(i128::MAX, &[2, 1]), Err(StellarSaveError::Overflow));
+    }
+
+    #[test]
+    fn test_allocate_places_remainder_by_policy() {
+        let split = |policy| PoolCalculator::allocate(1_000, 3, policy).unwrap();
+
+        let treasury = split(RoundingPolicy::RemainderToTreasury);
+        assert_eq!((treasury.share, treasury.to_treasury), (333, 1));
+        assert_eq!((treasury.to_recipient, treasury.carried), (0, 0));
+
+        let recipient = split(RoundingPolicy::RemainderToRecipient);
+        assert_eq!((recipient.share, recipient.to_recipient), (333, 1));
+        assert_eq!((recipient.to_treasury, recipient.carried), (0, 0));
+
+        let carried = split(RoundingPolicy::CarryToNextCycle);
+        assert_eq!((carried.share, carried.carried), (333, 1));
+        assert_eq!((carried.to_recipient, carried.to_treasury), (0, 0));
+    }
+
+    #[test]
+    fn test_allocate_edge_amounts() {
+        for policy in POLICIES {
+            // Nothing to split
+            let empty = PoolCalculator::allocate(0, 3, policy).unwrap();
+            assert_eq!((empty.share, remainder(&empty)), (0, 0));
+
+            // Less than one stroop per recipient: all of it is remainder
+            let dust = PoolCalculator::allocate(2, 3, policy).unwrap();
+            assert_eq!((dust.share, remainder(&dust)), (0, 2));
+
+            // Largest remainder there can be
+            let largest = PoolCalculator::allocate(11, 4, policy).unwrap();
+            assert_eq!((largest.share, remainder(&largest)), (2, 3));
+
+            // Even splits and single recipients leave nothing over
+            let even = PoolCalculator::allocate(900, 3, policy).unwrap();
+            assert_eq!((even.share, remainder(&even)), (300, 0));
+            let single = PoolCalculator::allocate(7, 1, policy).unwrap();
+            assert_eq!((single.share, remainder(&single)), (7, 0));
+
+            // The largest amount splits without overflowing
+            let max = PoolCalculator::allocate(i128::MAX, u32::MAX, policy).unwrap();
+            assert_eq!(max.share, i128::MAX / u32::MAX as i128);
+            assert_eq!(remainder(&max), i128::MAX % u32::MAX as i128);
+
+            assert_eq!(
+                PoolCalculator::allocate(-1, 3, policy),
+                Err(StellarSaveError::InvalidAmount)
+            );
+            assert_eq!(
+                PoolCalculator::allocate(100, 0, policy),
+                Err(StellarSaveError::InvalidState)
+            );
+        }
+    }
+
+    #[test]
+    fn test_allocate_accounts_for_every_stroop() {
+        for policy in POLICIES {
+            for recipients in 1..=7u32 {
+                for amount in 0..=50i128 {
+                    let allocation = PoolCalculator::allocate(amount, recipients, policy).unwrap();
+                    let remainder = remainder(&allocation);
+                    assert_eq!(allocation.share * recipients as i128 + remainder, amount);
+                    assert!((0..recipients as i128).contains(&remainder));
+                }
+            }
+        }
+    }
 }
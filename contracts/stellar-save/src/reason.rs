@@ -0,0 +1,56 @@
+//! Reason codes for governance actions.
+//!
+//! Removing a member, pausing or cancelling a group and opening a dispute
+//! take an `ActionNote`: a reason from a short, fixed list and, optionally,
+//! the hash of a longer memo kept off-chain. The note is stored on the
+//! record the action leaves and carried in its event, so an auditor reading
+//! the group years later can tell why it happened without asking anyone.
+//! Penalty waivers keep their own `WaiverReason` and take only the memo.
+
+use soroban_sdk::{contracttype, BytesN};
+
+/// Why a governance action was taken.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ActionReason {
+    /// No reason was given: transitions the contract makes on its own, and
+    /// actions recorded before reasons were kept
+    Unstated,
+    /// The member fell behind on contributions
+    MissedContributions,
+    /// The member broke the group's rules
+    Misconduct,
+    /// Funds or identities are suspected to be at risk
+    SuspectedFraud,
+    /// The members concerned asked for it
+    MemberRequest,
+    /// Medical, family or other emergency
+    Emergency,
+    /// Correcting something done in error
+    Mistake,
+    /// Decided by a passed group proposal, which holds the details
+    GroupVote,
+    /// Anything else; the memo explains
+    Other,
+}
+
+/// A reason for an action, with an optional memo.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ActionNote {
+    pub reason: ActionReason,
+    /// SHA-256 of a short explanation kept off-chain
+    pub memo: Option<BytesN<32>>,
+}
+
+impl ActionNote {
+    /// A note with only a reason.
+    pub fn new(reason: ActionReason) -> Self {
+        ActionNote { reason, memo: None }
+    }
+
+    /// The note on actions taken without a stated reason.
+    pub fn unstated() -> Self {
+        Self::new(ActionReason::Unstated)
+    }
+}
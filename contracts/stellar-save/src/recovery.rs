@@ -0,0 +1,111 @@
+//! Recovery of funds held by abandoned groups.
+//!
+//! A group whose members and admin all walk away would otherwise hold its
+//! contributions forever. Once a group has seen no joins, contributions,
+//! payouts or status changes for the protocol's inactivity period, the
+//! protocol admin can announce a recovery. After a further timelock, during
+//! which any activity in the group voids the announcement, the recovery
+//! cancels the group and refunds what it holds to its members, in
+//! proportion to what each contributed.
+
+use crate::group::Group;
+use crate::storage::{set_instance, set_persistent, StorageKeyBuilder};
+use crate::units;
+use soroban_sdk::{contracttype, Env};
+
+/// Inactivity period used until the protocol admin sets one: a year.
+pub const DEFAULT_INACTIVITY_PERIOD: u64 = units::days(365);
+
+/// Time between announcing a recovery and carrying it out.
+pub const RECOVERY_TIMELOCK: u64 = units::days(30);
+
+/// An announced recovery of a group's funds.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecoveryRequest {
+    /// When the protocol admin announced the recovery
+    pub initiated_at: u64,
+    /// Earliest time the recovery can be carried out
+    pub executable_at: u64,
+}
+
+/// Returns how long a group must be inactive before recovery can start.
+pub fn inactivity_period(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&StorageKeyBuilder::recovery_inactivity_period())
+        .unwrap_or(DEFAULT_INACTIVITY_PERIOD)
+}
+
+/// Sets how long a group must be inactive before recovery can start.
+pub fn set_inactivity_period(env: &Env, period: u64) {
+    set_instance(
+        env,
+        &StorageKeyBuilder::recovery_inactivity_period(),
+        &period,
+    );
+}
+
+/// Records activity in a group.
+pub fn touch(env: &Env, group_id: u64) {
+    set_persistent(
+        env,
+        &StorageKeyBuilder::group_last_activity(group_id),
+        &env.ledger().timestamp(),
+    );
+}
+
+/// Returns when a group last saw activity. Groups with none recorded count
+/// from when they were created or started.
+pub fn last_activity(env: &Env, group: &Group) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&StorageKeyBuilder::group_last_activity(group.id))
+        .unwrap_or(group.created_at.max(group.started_at))
+}
+
+/// Returns the recovery announced for a group, if any.
+pub fn load(env: &Env, group_id: u64) -> Option<RecoveryRequest> {
+    env.storage()
+        .persistent()
+        .get(&StorageKeyBuilder::group_recovery(group_id))
+}
+
+/// Stores the recovery announced for a group.
+pub fn store(env: &Env, group_id: u64, request: &RecoveryRequest) {
+    set_persistent(env, &StorageKeyBuilder::group_recovery(group_id), request);
+}
+
+/// Removes a group's announced recovery.
+pub fn clear(env: &Env, group_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&StorageKeyBuilder::group_recovery(group_id));
+}
+
+/// A member's share of `balance`, in proportion to `contributed` out of
+/// `total_contributed`, rounded down.
+///
+/// Returns `None` on overflow or if nothing was contributed.
+pub fn pro_rata_share(balance: i128, contributed: i128, total_contributed: i128) -> Option<i128> {
+    if total_contributed <= 0 {
+        return None;
+    }
+    balance
+        .checked_mul(contributed)
+        .map(|scaled| scaled / total_contributed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pro_rata_share() {
+        assert_eq!(pro_rata_share(300, 100, 300), Some(100));
+        assert_eq!(pro_rata_share(100, 1, 3), Some(33));
+        assert_eq!(pro_rata_share(100, 0, 3), Some(0));
+        assert_eq!(pro_rata_share(100, 1, 0), None);
+        assert_eq!(pro_rata_share(i128::MAX, 2, 3), None);
+    }
+}
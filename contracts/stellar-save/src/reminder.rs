@@ -0,0 +1,152 @@
+//! Contribution deadline reminders.
+//!
+//! A group keeps a list of warning thresholds, each a number of seconds
+//! before the cycle's contribution deadline. Once the clock passes one,
+//! anyone can call `ping_cycle` to emit a `contribution_reminder` event
+//! naming the members who have not contributed yet, which gives off-chain
+//! notification services a single trigger to act on. Every threshold fires at
+//! most once per cycle.
+
+use crate::error::StellarSaveError;
+use crate::storage::{self, StorageKeyBuilder};
+use crate::units::SECONDS_PER_DAY;
+use soroban_sdk::{Env, Vec};
+
+/// Threshold used until the admin sets their own: 24 hours before the
+/// deadline.
+pub const DEFAULT_THRESHOLD_SECS: u64 = SECONDS_PER_DAY;
+
+/// Most thresholds a group can configure.
+pub const MAX_THRESHOLDS: u32 = 5;
+
+/// Returns the group's thresholds, longest first.
+pub fn load_thresholds(env: &Env, group_id: u64) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&StorageKeyBuilder::group_reminder_thresholds(group_id))
+        .unwrap_or(Vec::from_array(env, [DEFAULT_THRESHOLD_SECS]))
+}
+
+/// Checks thresholds for a group with cycles of `cycle_duration` seconds and
+/// returns them longest first. An empty list turns reminders off.
+///
+/// # Errors
+/// * `InvalidState` - If there are more than `MAX_THRESHOLDS`, or one is
+///   zero, repeated, or not shorter than the cycle
+pub fn normalize_thresholds(
+    env: &Env,
+    thresholds: &Vec<u64>,
+    cycle_duration: u64,
+) -> Result<Vec<u64>, StellarSaveError> {
+    if thresholds.len() > MAX_THRESHOLDS {
+        return Err(StellarSaveError::InvalidState);
+    }
+
+    let mut sorted: Vec<u64> = Vec::new(env);
+    for threshold in thresholds.iter() {
+        if threshold == 0 || threshold >= cycle_duration || sorted.contains(threshold) {
+            return Err(StellarSaveError::InvalidState);
+        }
+        let index = sorted
+            .iter()
+            .position(|longer| longer < threshold)
+            .unwrap_or(sorted.len() as usize);
+        sorted.insert(index as u32, threshold);
+    }
+    Ok(sorted)
+}
+
+/// Threshold a reminder is due for at `now`: the shortest one already
+/// passed, provided it is shorter than `last_sent`, the threshold of the
+/// cycle's previous reminder. Nothing is due once the deadline has passed.
+pub fn due_threshold(
+    thresholds: &Vec<u64>,
+    deadline: u64,
+    now: u64,
+    last_sent: Option<u64>,
+) -> Option<u64> {
+    if now > deadline {
+        return None;
+    }
+    let remaining = deadline - now;
+    thresholds
+        .iter()
+        .filter(|threshold| *threshold >= remaining)
+        .min()
+        .filter(|threshold| last_sent.is_none_or(|sent| *threshold < sent))
+}
+
+/// Threshold of the last reminder sent for `cycle`, if any.
+pub fn load_last_sent(env: &Env, group_id: u64, cycle: u32) -> Option<u64> {
+    storage::load(env, &StorageKeyBuilder::group_reminder_sent(group_id, cycle))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::{hours, SECONDS_PER_WEEK};
+
+    const DEADLINE: u64 = 1_700_000_000;
+
+    #[test]
+    fn test_normalize_sorts_longest_first() {
+        let env = Env::default();
+        let thresholds = Vec::from_array(&env, [hours(1), hours(48), hours(24)]);
+        assert_eq!(
+            normalize_thresholds(&env, &thresholds, SECONDS_PER_WEEK),
+            Ok(Vec::from_array(&env, [hours(48), hours(24), hours(1)]))
+        );
+        assert_eq!(
+            normalize_thresholds(&env, &Vec::new(&env), SECONDS_PER_WEEK),
+            Ok(Vec::new(&env))
+        );
+    }
+
+    #[test]
+    fn test_normalize_rejects_invalid_thresholds() {
+        let env = Env::default();
+        for thresholds in [
+            Vec::from_array(&env, [0]),
+            Vec::from_array(&env, [SECONDS_PER_WEEK]),
+            Vec::from_array(&env, [hours(1), hours(1)]),
+            Vec::from_array(&env, [1, 2, 3, 4, 5, 6]),
+        ] {
+            assert_eq!(
+                normalize_thresholds(&env, &thresholds, SECONDS_PER_WEEK),
+                Err(StellarSaveError::InvalidState)
+            );
+        }
+    }
+
+    #[test]
+    fn test_due_threshold() {
+        let env = Env::default();
+        let thresholds = Vec::from_array(&env, [hours(24), hours(1)]);
+
+        assert_eq!(
+            due_threshold(&thresholds, DEADLINE, DEADLINE - hours(25), None),
+            None
+        );
+        assert_eq!(
+            due_threshold(&thresholds, DEADLINE, DEADLINE - hours(24), None),
+            Some(hours(24))
+        );
+        assert_eq!(
+            due_threshold(&thresholds, DEADLINE, DEADLINE - hours(2), Some(hours(24))),
+            None
+        );
+        // A late ping skips straight to the shortest threshold passed
+        assert_eq!(
+            due_threshold(&thresholds, DEADLINE, DEADLINE - 60, None),
+            Some(hours(1))
+        );
+        assert_eq!(
+            due_threshold(&thresholds, DEADLINE, DEADLINE, Some(hours(1))),
+            None
+        );
+        assert_eq!(
+            due_threshold(&thresholds, DEADLINE, DEADLINE + 1, None),
+            None
+        );
+    }
+}
@@ -0,0 +1,341 @@
+//! Deterministic replay of action scripts.
+//!
+//! Bug reports can be captured as a plain-text script and replayed against a
+//! fresh contract, turning them directly into regression tests. Every run uses
+//! the same starting ledger time and the same address generation order, so a
+//! script always produces the same state.
+//!
+//! # Script format
+//!
+//! One action per line; blank lines and lines starting with `#` are ignored.
+//! Actors are referred to by name and get an address on first use. Group IDs
+//! are the sequential IDs returned by `create`, starting at 1.
+//!
+//! ```text
+//! create <actor> <amount> <cycle_duration> <max_members>
+//! join <group> <actor>
+//! start <group>
+//! contribute <group> <actor>
+//! contribute_all <group>
+//! advance <seconds>
+//! payout <group>
+//! pause <group>
+//! resume <group>
+//! cancel <group>
+//! remove <group> <actor> shrink|substitute
+//! expect_cycle <group> <cycle>
+//! expect_members <group> <count>
+//! ```
+//!
+//! `contribute` and `payout` go through the contract's `contribute` and
+//! `execute_payout` entrypoints, so tokens really move: each contribution is
+//! minted to the member just before they pay it.
+//!
+//! Appending `=> <code>` to a line asserts that the action fails with that
+//! error code, e.g. `join 1 alice => 2001`. Any other failure panics with the
+//! offending line number.
+
+use crate::error::StellarSaveError;
+use crate::testutils;
+use crate::{ActionNote, StellarSaveContractClient, VacancyPolicy};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env, Map, Symbol};
+
+/// Ledger timestamp every replay starts from.
+pub const REPLAY_START_TIME: u64 = 1_700_000_000;
+
+/// A fresh contract instance driven by action scripts.
+pub struct Replay {
+    pub env: Env,
+    pub contract_id: Address,
+    actors: Map<Symbol, Address>,
+}
+
+impl Default for Replay {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Replay {
+    /// Registers and initializes a fresh contract with all auths mocked.
+    pub fn new() -> Self {
+        let env = Env::default();
+        env.mock_all_auths();
+        testutils::set_time(&env, REPLAY_START_TIME);
+        let contract_id = testutils::register(&env);
+        let actors = Map::new(&env);
+
+        Self {
+            env,
+            contract_id,
+            actors,
+        }
+    }
+
+    /// Returns a client for the replayed contract.
+    pub fn client(&self) -> StellarSaveContractClient<'_> {
+        StellarSaveContractClient::new(&self.env, &self.contract_id)
+    }
+
+    /// Returns the address for a named actor, generating it on first use.
+    pub fn actor(&mut self, name: &str) -> Address {
+        let key = Symbol::new(&self.env, name);
+        if let Some(address) = self.actors.get(key.clone()) {
+            return address;
+        }
+
+        let address = Address::generate(&self.env);
+        self.actors.set(key, address.clone());
+        address
+    }
+
+    /// Replays every line of a script, panicking on the first unexpected outcome.
+    pub fn run(&mut self, script: &str) {
+        for (index, raw) in script.lines().enumerate() {
+            let line_no = index + 1;
+            let line = raw.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (action, expected) = match line.split_once("=>") {
+                Some((action, code)) => {
+                    let code: u32 = code
+                        .trim()
+                        .parse()
+                        .unwrap_or_else(|_| panic!("line {}: bad error code", line_no));
+                    (action.trim(), Some(code))
+                }
+                None => (line, None),
+            };
+
+            match (self.step(action, line_no), expected) {
+                (Ok(()), None) => {}
+                (Err(err), Some(code)) if err.code() == code => {}
+                (Ok(()), Some(code)) => {
+                    panic!(
+                        "line {}: expected error {}, action succeeded",
+                        line_no, code
+                    )
+                }
+                (Err(err), _) => panic!("line {}: unexpected error {}", line_no, err.code()),
+            }
+        }
+    }
+
+    /// Executes a single action.
+    fn step(&mut self, action: &str, line_no: usize) -> Result<(), StellarSaveError> {
+        let mut parts = action.split_whitespace();
+        let command = parts.next().unwrap_or("");
+        let mut next = || {
+            parts
+                .next()
+                .unwrap_or_else(|| panic!("line {}: missing argument", line_no))
+        };
+
+        match command {
+            "create" => {
+                let creator = self.actor(next());
+                let amount: i128 = parse(next(), line_no);
+                let duration: u64 = parse(next(), line_no);
+                let max_members: u32 = parse(next(), line_no);
+                let result =
+                    self.client()
+                        .try_create_group(&creator, &amount, &duration, &max_members);
+                lift(result).map(|_| ())
+            }
+            "join" => {
+                let group_id: u64 = parse(next(), line_no);
+                let member = self.actor(next());
+                lift(self.client().try_join_group(&group_id, &member, &None))
+            }
+            "start" => {
+                let group_id: u64 = parse(next(), line_no);
+                lift(self.client().try_get_group(&group_id))?;
+                testutils::start_group(&self.env, &self.contract_id, group_id);
+                Ok(())
+            }
+            "contribute" => {
+                let group_id: u64 = parse(next(), line_no);
+                let member = self.actor(next());
+                self.contribute(group_id, &member)
+            }
+            "contribute_all" => {
+                let group_id: u64 = parse(next(), line_no);
+                let members = lift(self.client().try_get_group_members(&group_id, &0, &100))?;
+                for member in members.iter() {
+                    self.contribute(group_id, &member)?;
+                }
+                Ok(())
+            }
+            "advance" => {
+                let seconds: u64 = parse(next(), line_no);
+                testutils::advance_time(&self.env, seconds);
+                Ok(())
+            }
+            "payout" => {
+                let group_id: u64 = parse(next(), line_no);
+                self.payout(group_id)
+            }
+            "pause" => {
+                let group_id: u64 = parse(next(), line_no);
+                lift(
+                    self.client()
+                        .try_pause_group(&group_id, &ActionNote::unstated()),
+                )
+            }
+            "resume" => {
+                let group_id: u64 = parse(next(), line_no);
+                lift(self.client().try_resume_group(&group_id))
+            }
+            "cancel" => {
+                let group_id: u64 = parse(next(), line_no);
+                lift(
+                    self.client()
+                        .try_cancel_group(&group_id, &ActionNote::unstated()),
+                )
+            }
+            "remove" => {
+                let group_id: u64 = parse(next(), line_no);
+                let member = self.actor(next());
+                let policy = match next() {
+                    "shrink" => VacancyPolicy::Shrink,
+                    "substitute" => VacancyPolicy::Substitute,
+                    other => panic!("line {}: unknown vacancy policy {}", line_no, other),
+                };
+                let note = ActionNote::unstated();
+                lift(
+                    self.client()
+                        .try_remove_member(&group_id, &member, &policy, &note),
+                )
+            }
+            "expect_cycle" => {
+                let group_id: u64 = parse(next(), line_no);
+                let cycle: u32 = parse(next(), line_no);
+                let group = testutils::load_group(&self.env, &self.contract_id, group_id);
+                assert_eq!(group.current_cycle, cycle, "line {}", line_no);
+                Ok(())
+            }
+            "expect_members" => {
+                let group_id: u64 = parse(next(), line_no);
+                let count: u32 = parse(next(), line_no);
+                let group = testutils::load_group(&self.env, &self.contract_id, group_id);
+                assert_eq!(group.member_count, count, "line {}", line_no);
+                Ok(())
+            }
+            other => panic!("line {}: unknown action {}", line_no, other),
+        }
+    }
+
+    /// Mints a member the group's contribution and pays it in.
+    fn contribute(&self, group_id: u64, member: &Address) -> Result<(), StellarSaveError> {
+        let group = lift(self.client().try_get_group(&group_id))?;
+        testutils::mint(&self.env, &self.contract_id, member, group.contribution_amount);
+        lift(self.client().try_contribute(&group_id, member, &None)).map(|_| ())
+    }
+
+    /// Executes the group's due payout on behalf of the `keeper` actor.
+    fn payout(&mut self, group_id: u64) -> Result<(), StellarSaveError> {
+        let keeper = self.actor("keeper");
+        lift(self.client().try_execute_payout(&group_id, &keeper, &None)).map(|_| ())
+    }
+}
+
+fn parse<T: core::str::FromStr>(token: &str, line_no: usize) -> T {
+    token
+        .parse()
+        .unwrap_or_else(|_| panic!("line {}: bad number {}", line_no, token))
+}
+
+fn flatten<E>(err: Result<StellarSaveError, E>) -> StellarSaveError {
+    err.unwrap_or(StellarSaveError::InternalError)
+}
+
+fn lift<T, C, E>(
+    result: Result<Result<T, C>, Result<StellarSaveError, E>>,
+) -> Result<T, StellarSaveError> {
+    match result {
+        Ok(value) => Ok(value.unwrap_or_else(|_| panic!("failed to convert contract result"))),
+        Err(err) => Err(flatten(err)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_replay_full_rotation() {
+        let mut replay = Replay::new();
+        replay.run(
+            "
+            # three members, two cycles
+            create alice 100 3600 3
+            join 1 alice
+            join 1 bob
+            join 1 carol
+            start 1
+            contribute_all 1
+            advance 3600
+            payout 1
+            expect_cycle 1 1
+            contribute_all 1
+            payout 1
+            expect_cycle 1 2
+            ",
+        );
+
+        let client = replay.client();
+        assert_eq!(client.get_total_paid_out(&1), 600);
+        let alice = replay.actor("alice");
+        let token = testutils::token(&replay.env, &replay.contract_id);
+        assert_eq!(token.balance(&alice), 300);
+        assert_eq!(token.balance(&replay.contract_id), 0);
+    }
+
+    #[test]
+    fn test_replay_expected_errors() {
+        let mut replay = Replay::new();
+        replay.run(
+            "
+            create alice 100 3600 2
+            join 1 alice
+            join 1 alice => 2001
+            join 1 bob
+            start 1
+            contribute 1 alice
+            contribute 1 alice => 3002
+            contribute 1 mallory => 2002
+            payout 1 => 3003
+            ",
+        );
+    }
+
+    #[test]
+    fn test_replay_is_deterministic() {
+        let script = "
+            create alice 100 3600 2
+            join 1 alice
+            join 1 bob
+        ";
+
+        let mut first = Replay::new();
+        first.run(script);
+        let mut second = Replay::new();
+        second.run(script);
+
+        assert_eq!(first.actor("bob"), second.actor("bob"));
+        assert_eq!(
+            first.client().get_group_members(&1, &0, &10),
+            second.client().get_group_members(&1, &0, &10)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "line 3")]
+    fn test_replay_reports_failing_line() {
+        let mut replay = Replay::new();
+        replay.run("create alice 100 3600 2\njoin 1 alice\njoin 1 alice");
+    }
+}
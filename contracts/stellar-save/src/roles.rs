@@ -0,0 +1,153 @@
+//! Per-group roles.
+//!
+//! The group admin can appoint treasurers, who manage how the group's funds
+//! are held and priced, and moderators, who handle disputes and waive late
+//! penalties. The admin holds every role. Entry points gated on a role take
+//! the caller's address and check it with `require_role`.
+
+use crate::error::StellarSaveError;
+use crate::group::Group;
+use crate::storage::{set_persistent, GroupStore, StorageKeyBuilder};
+use crate::StellarSaveContract;
+use soroban_sdk::{contracttype, Address, Env, Map};
+
+/// What an address may do in a group.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum GroupRole {
+    /// Everything; held by the group admin alone.
+    Admin,
+    /// Yield strategy, fiat pricing and payout hook configuration.
+    Treasurer,
+    /// Arbiter appointment and late penalty waivers.
+    Moderator,
+}
+
+impl GroupRole {
+    /// Whether holding this role allows acting as `required`.
+    pub fn grants(&self, required: GroupRole) -> bool {
+        *self == GroupRole::Admin || *self == required
+    }
+}
+
+/// Treasurers and moderators appointed in a group.
+pub fn load_roles(env: &Env, group_id: u64) -> Map<Address, GroupRole> {
+    env.storage()
+        .persistent()
+        .get(&StorageKeyBuilder::group_roles(group_id))
+        .unwrap_or(Map::new(env))
+}
+
+/// Role `address` holds in `group`, if any. The group admin is `Admin`
+/// whatever else they were appointed.
+pub fn role_of(env: &Env, group: &Group, address: &Address) -> Option<GroupRole> {
+    if StellarSaveContract::load_group_admin(env, group) == *address {
+        return Some(GroupRole::Admin);
+    }
+    load_roles(env, group.id).get(address.clone())
+}
+
+/// Appoints `address` to `role`, or removes their role with `None`.
+pub fn set_role(env: &Env, group_id: u64, address: Address, role: Option<GroupRole>) {
+    let key = StorageKeyBuilder::group_roles(group_id);
+    let mut roles = load_roles(env, group_id);
+    match role {
+        Some(role) => roles.set(address, role),
+        None => {
+            roles.remove(address);
+        }
+    }
+    if roles.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        set_persistent(env, &key, &roles);
+    }
+}
+
+/// Checks that `caller` authorized the call and holds `role` in the group.
+///
+/// # Errors
+/// * `GroupNotFound` - If the group doesn't exist
+/// * `Unauthorized` - If the caller does not hold the role
+pub fn require_role(
+    env: &Env,
+    group_id: u64,
+    caller: &Address,
+    role: GroupRole,
+) -> Result<(), StellarSaveError> {
+    let group: Group = GroupStore::load(env, group_id).ok_or(StellarSaveError::GroupNotFound)?;
+    authorize(env, &group, caller, role)
+}
+
+/// `require_role` for a group already loaded.
+pub fn authorize(
+    env: &Env,
+    group: &Group,
+    caller: &Address,
+    role: GroupRole,
+) -> Result<(), StellarSaveError> {
+    caller.require_auth();
+    match role_of(env, group, caller) {
+        Some(held) if held.grants(role) => Ok(()),
+        _ => Err(StellarSaveError::Unauthorized),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+
+    #[test]
+    fn test_admin_grants_every_role() {
+        for role in [GroupRole::Admin, GroupRole::Treasurer, GroupRole::Moderator] {
+            assert!(GroupRole::Admin.grants(role));
+        }
+        assert!(GroupRole::Treasurer.grants(GroupRole::Treasurer));
+        assert!(!GroupRole::Treasurer.grants(GroupRole::Moderator));
+        assert!(!GroupRole::Moderator.grants(GroupRole::Admin));
+    }
+
+    #[test]
+    fn test_roles_grant_and_revoke() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let group = crate::testutils::TestGroupBuilder::new(&env).build();
+        let stored = group.load(&env);
+        let treasurer = Address::generate(&env);
+
+        env.as_contract(&group.contract_id, || {
+            assert_eq!(
+                role_of(&env, &stored, &group.creator),
+                Some(GroupRole::Admin)
+            );
+            assert_eq!(role_of(&env, &stored, &treasurer), None);
+
+            set_role(
+                &env,
+                group.group_id,
+                treasurer.clone(),
+                Some(GroupRole::Treasurer),
+            );
+            assert_eq!(
+                role_of(&env, &stored, &treasurer),
+                Some(GroupRole::Treasurer)
+            );
+            assert_eq!(
+                require_role(&env, group.group_id, &treasurer, GroupRole::Moderator),
+                Err(StellarSaveError::Unauthorized)
+            );
+            assert_eq!(
+                require_role(&env, group.group_id + 1, &treasurer, GroupRole::Treasurer),
+                Err(StellarSaveError::GroupNotFound)
+            );
+
+            set_role(&env, group.group_id, treasurer.clone(), None);
+            assert_eq!(role_of(&env, &stored, &treasurer), None);
+            assert!(!env
+                .storage()
+                .persistent()
+                .has(&StorageKeyBuilder::group_roles(group.group_id)));
+        });
+    }
+}
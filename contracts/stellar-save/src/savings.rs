@@ -0,0 +1,239 @@
+//! Savings-only groups.
+//!
+//! A savings group collects contributions on the same schedule as a rotation
+//! but never pays anyone out in turn. Each member's contributions accumulate
+//! as their own principal, locked until the group's goal is reached: a target
+//! amount, an unlock date, or whichever comes first. Once unlocked, every
+//! member withdraws their own principal together with a share of any yield
+//! added to the group.
+
+use crate::error::StellarSaveError;
+use crate::storage::{set_persistent, StorageKeyBuilder};
+use soroban_sdk::{contracttype, Address, Env};
+
+/// Whose savings the target amount is measured against.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum GoalKind {
+    /// The target is for the whole group; everyone unlocks together.
+    Shared,
+    /// The target applies to each member's own balance.
+    Individual,
+}
+
+/// What a savings group is saving towards.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SavingsGoal {
+    /// Whether the target is shared or per member
+    pub kind: GoalKind,
+    /// Savings that unlock withdrawals, in stroops; 0 for a date-only goal
+    pub target_amount: i128,
+    /// Timestamp that unlocks withdrawals; 0 for an amount-only goal
+    pub unlock_at: u64,
+}
+
+impl SavingsGoal {
+    /// A goal needs a positive target, a future unlock date, or both.
+    pub fn validate(&self, now: u64) -> bool {
+        self.target_amount >= 0 && (self.target_amount > 0 || self.unlock_at > now)
+    }
+
+    /// Whether a member holding `balance` in a group holding `total` may
+    /// withdraw at `now`.
+    pub fn is_unlocked(&self, balance: i128, total: i128, now: u64) -> bool {
+        if self.unlock_at > 0 && now >= self.unlock_at {
+            return true;
+        }
+        let saved = match self.kind {
+            GoalKind::Shared => total,
+            GoalKind::Individual => balance,
+        };
+        self.target_amount > 0 && saved >= self.target_amount
+    }
+}
+
+/// Returns the group's savings goal, or `None` for a rotating group.
+pub fn load_goal(env: &Env, group_id: u64) -> Option<SavingsGoal> {
+    env.storage()
+        .persistent()
+        .get(&StorageKeyBuilder::group_savings_goal(group_id))
+}
+
+/// Whether the group is a savings-only group.
+pub fn is_savings_group(env: &Env, group_id: u64) -> bool {
+    env.storage()
+        .persistent()
+        .has(&StorageKeyBuilder::group_savings_goal(group_id))
+}
+
+/// Returns the principal a member has saved and not yet withdrawn.
+pub fn load_balance(env: &Env, group_id: u64, member: Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&StorageKeyBuilder::member_savings_balance(group_id, member))
+        .unwrap_or(0)
+}
+
+/// Returns the principal the group holds for its members.
+pub fn load_total(env: &Env, group_id: u64) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&StorageKeyBuilder::group_savings_total(group_id))
+        .unwrap_or(0)
+}
+
+/// Returns the yield the group holds for its members.
+pub fn load_yield(env: &Env, group_id: u64) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&StorageKeyBuilder::group_savings_yield(group_id))
+        .unwrap_or(0)
+}
+
+/// Whether `member` may withdraw from the group now.
+pub fn is_unlocked_for(env: &Env, group_id: u64, goal: &SavingsGoal, member: Address) -> bool {
+    goal.is_unlocked(
+        load_balance(env, group_id, member),
+        load_total(env, group_id),
+        env.ledger().timestamp(),
+    )
+}
+
+/// Adds a contribution to the member's balance and the group's total.
+///
+/// Reaching a shared target brings the unlock date forward to that moment,
+/// so the group stays unlocked while members withdraw and the total drains.
+pub fn credit(
+    env: &Env,
+    group_id: u64,
+    member: Address,
+    amount: i128,
+) -> Result<(), StellarSaveError> {
+    let balance = load_balance(env, group_id, member.clone())
+        .checked_add(amount)
+        .ok_or(StellarSaveError::Overflow)?;
+    let total = load_total(env, group_id)
+        .checked_add(amount)
+        .ok_or(StellarSaveError::Overflow)?;
+    set_persistent(
+        env,
+        &StorageKeyBuilder::member_savings_balance(group_id, member),
+        &balance,
+    );
+    set_persistent(
+        env,
+        &StorageKeyBuilder::group_savings_total(group_id),
+        &total,
+    );
+
+    if let Some(mut goal) = load_goal(env, group_id) {
+        let now = env.ledger().timestamp();
+        if goal.kind == GoalKind::Shared
+            && goal.target_amount > 0
+            && total >= goal.target_amount
+            && (goal.unlock_at == 0 || goal.unlock_at > now)
+        {
+            goal.unlock_at = now.max(1);
+            set_persistent(env, &StorageKeyBuilder::group_savings_goal(group_id), &goal);
+        }
+    }
+    Ok(())
+}
+
+/// Adds yield to the group, to be shared among members as they withdraw.
+pub fn add_yield(env: &Env, group_id: u64, amount: i128) -> Result<i128, StellarSaveError> {
+    let held = load_yield(env, group_id)
+        .checked_add(amount)
+        .ok_or(StellarSaveError::Overflow)?;
+    set_persistent(
+        env,
+        &StorageKeyBuilder::group_savings_yield(group_id),
+        &held,
+    );
+    Ok(held)
+}
+
+/// Clears the member's balance and takes their share of the yield, in
+/// proportion to their share of the principal still held.
+///
+/// # Returns
+/// The principal and the yield share being withdrawn.
+pub fn debit_all(
+    env: &Env,
+    group_id: u64,
+    member: Address,
+) -> Result<(i128, i128), StellarSaveError> {
+    let principal = load_balance(env, group_id, member.clone());
+    let total = load_total(env, group_id);
+    let held_yield = load_yield(env, group_id);
+    if principal <= 0 || total < principal {
+        return Err(StellarSaveError::InvalidAmount);
+    }
+
+    let yield_share = held_yield
+        .checked_mul(principal)
+        .ok_or(StellarSaveError::Overflow)?
+        / total;
+    env.storage()
+        .persistent()
+        .remove(&StorageKeyBuilder::member_savings_balance(group_id, member));
+    set_persistent(
+        env,
+        &StorageKeyBuilder::group_savings_total(group_id),
+        &(total - principal),
+    );
+    set_persistent(
+        env,
+        &StorageKeyBuilder::group_savings_yield(group_id),
+        &(held_yield - yield_share),
+    );
+    Ok((principal, yield_share))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_goal_validation() {
+        let goal = |target_amount, unlock_at| SavingsGoal {
+            kind: GoalKind::Shared,
+            target_amount,
+            unlock_at,
+        };
+        assert!(goal(1_000, 0).validate(100));
+        assert!(goal(0, 200).validate(100));
+        assert!(goal(1_000, 200).validate(100));
+        assert!(!goal(0, 0).validate(100));
+        assert!(!goal(0, 100).validate(100));
+        assert!(!goal(-1, 200).validate(100));
+    }
+
+    #[test]
+    fn test_goal_unlocks_on_target_or_date() {
+        let shared = SavingsGoal {
+            kind: GoalKind::Shared,
+            target_amount: 1_000,
+            unlock_at: 500,
+        };
+        assert!(!shared.is_unlocked(400, 999, 499));
+        assert!(shared.is_unlocked(400, 1_000, 499));
+        assert!(shared.is_unlocked(0, 0, 500));
+
+        let individual = SavingsGoal {
+            kind: GoalKind::Individual,
+            ..shared
+        };
+        assert!(!individual.is_unlocked(999, 5_000, 499));
+        assert!(individual.is_unlocked(1_000, 1_000, 499));
+
+        let date_only = SavingsGoal {
+            kind: GoalKind::Shared,
+            target_amount: 0,
+            unlock_at: 500,
+        };
+        assert!(!date_only.is_unlocked(1_000, 1_000, 499));
+        assert!(date_only.is_unlocked(0, 0, 500));
+    }
+}
@@ -0,0 +1,378 @@
+//! Cycle deadline math.
+//!
+//! Every timestamp derived from a group's start time and cycle duration is
+//! computed here, so contribution checks, queries and test helpers agree on
+//! where a cycle starts, when its contributions are due and how long the
+//! grace window stays open. All functions return `None` on overflow.
+//!
+//! A group on a monthly schedule has its deadlines on a day of the month
+//! instead. Dates are computed in UTC with the Gregorian calendar, so every
+//! node agrees on them whatever the month lengths.
+
+use crate::group::{CycleSchedule, Group};
+use crate::units::SECONDS_PER_DAY;
+
+/// When a contribution arrives relative to its cycle's deadline.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ContributionTiming {
+    /// At or before the deadline.
+    OnTime,
+    /// After the deadline, but no later than the end of the grace period.
+    InGrace,
+    /// After the grace period has closed.
+    Late,
+}
+
+/// Start of `cycle` for a rotation that began at `started_at`.
+pub fn cycle_start_at(started_at: u64, cycle_duration: u64, cycle: u32) -> Option<u64> {
+    (cycle as u64)
+        .checked_mul(cycle_duration)
+        .and_then(|offset| started_at.checked_add(offset))
+}
+
+/// Contribution deadline of `cycle` for a rotation that began at
+/// `started_at`. This is also when the cycle's payout becomes due.
+pub fn deadline_at(started_at: u64, cycle_duration: u64, cycle: u32) -> Option<u64> {
+    cycle_start_at(started_at, cycle_duration, cycle)?.checked_add(cycle_duration)
+}
+
+/// Contribution deadline of `cycle` on a monthly schedule due on `day`, for
+/// a rotation that began at `started_at`: midnight UTC on that day of the
+/// `cycle + 1`-th month after the start, or on the month's last day when it
+/// is shorter.
+pub fn monthly_deadline_at(started_at: u64, day: u32, cycle: u32) -> Option<u64> {
+    let (year, month) = civil_from_days((started_at / SECONDS_PER_DAY) as i64);
+    let months = month as i64 + cycle as i64;
+    let year = year + months / 12;
+    let month = (months % 12) as u32 + 1;
+    let date = days_from_civil(year, month, day.min(days_in_month(year, month)));
+    u64::try_from(date).ok()?.checked_mul(SECONDS_PER_DAY)
+}
+
+/// Contribution deadline of `cycle` for a group following its schedule
+/// from `started_at`, which need not be the group's own start.
+pub fn scheduled_deadline(group: &Group, started_at: u64, cycle: u32) -> Option<u64> {
+    match group.schedule {
+        CycleSchedule::Monthly(day) => monthly_deadline_at(started_at, day, cycle),
+        _ => deadline_at(started_at, group.cycle_duration, cycle),
+    }
+}
+
+/// Start of `cycle` in a started group.
+pub fn cycle_start(group: &Group, cycle: u32) -> Option<u64> {
+    match (group.schedule, cycle.checked_sub(1)) {
+        (CycleSchedule::Monthly(_), Some(previous)) => contribution_deadline(group, previous),
+        (CycleSchedule::Monthly(_), None) => Some(group.started_at),
+        _ => cycle_start_at(group.started_at, group.cycle_duration, cycle),
+    }
+}
+
+/// Cycle of a started group that `timestamp` falls in, counting every
+/// elapsed cycle whether or not it was paid out. Timestamps before the start
+/// fall in cycle 0.
+pub fn cycle_at(group: &Group, timestamp: u64) -> u32 {
+    let CycleSchedule::Monthly(_) = group.schedule else {
+        let elapsed = timestamp.saturating_sub(group.started_at);
+        let cycles = elapsed.checked_div(group.cycle_duration).unwrap_or(0);
+        return cycles.min(u32::MAX as u64) as u32;
+    };
+
+    // One deadline falls in each month after the start's, so all but
+    // possibly the last of the months elapsed have closed a cycle
+    let (start_year, start_month) = civil_from_days((group.started_at / SECONDS_PER_DAY) as i64);
+    let (year, month) = civil_from_days((timestamp / SECONDS_PER_DAY) as i64);
+    let months = (year - start_year) * 12 + month as i64 - start_month as i64;
+    let mut cycle = months.saturating_sub(1).clamp(0, u32::MAX as i64) as u32;
+    while cycle < u32::MAX
+        && contribution_deadline(group, cycle).is_some_and(|deadline| deadline <= timestamp)
+    {
+        cycle += 1;
+    }
+    cycle
+}
+
+/// Contribution deadline of `cycle` in a started group.
+pub fn contribution_deadline(group: &Group, cycle: u32) -> Option<u64> {
+    scheduled_deadline(group, group.started_at, cycle)
+}
+
+/// Seconds from `now` until `cycle`'s contribution deadline; 0 once it has
+/// passed.
+pub fn seconds_until_deadline(group: &Group, cycle: u32, now: u64) -> Option<u64> {
+    Some(contribution_deadline(group, cycle)?.saturating_sub(now))
+}
+
+/// Projected time of the final payout of a started group, as of `now`.
+///
+/// The remaining cycles are assumed to keep to the schedule. A group running
+/// behind schedule cannot pay its current cycle before `now`, so every later
+/// cycle is pushed back by as long as the current one is late. Returns
+/// `None` for a group that has completed.
+pub fn projected_completion(group: &Group, now: u64) -> Option<u64> {
+    let last_cycle = group.total_cycles().checked_sub(1)?;
+    if group.current_cycle > last_cycle {
+        return None;
+    }
+    let current_due = contribution_deadline(group, group.current_cycle)?;
+    contribution_deadline(group, last_cycle)?.checked_add(now.saturating_sub(current_due))
+}
+
+/// Last timestamp at which a contribution for `cycle` is accepted, or the
+/// plain deadline if the group has no grace period.
+pub fn grace_deadline(group: &Group, cycle: u32) -> Option<u64> {
+    contribution_deadline(group, cycle)?.checked_add(group.grace_period_secs.unwrap_or(0))
+}
+
+/// Classifies a contribution made at `timestamp` for `cycle`.
+pub fn contribution_timing(
+    group: &Group,
+    cycle: u32,
+    timestamp: u64,
+) -> Option<ContributionTiming> {
+    let timing = if timestamp <= contribution_deadline(group, cycle)? {
+        ContributionTiming::OnTime
+    } else if timestamp <= grace_deadline(group, cycle)? {
+        ContributionTiming::InGrace
+    } else {
+        ContributionTiming::Late
+    };
+    Some(timing)
+}
+
+/// Days from 1970-01-01 to the given date.
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    // Years counted from March, so the leap day ends the year
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let day_of_year = (153 * ((month as i64 + 9) % 12) + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Year and month (1-12) of the date `days` after 1970-01-01.
+fn civil_from_days(days: i64) -> (i64, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_from_march = (5 * day_of_year + 2) / 153;
+    let month = if month_from_march < 10 {
+        month_from_march + 3
+    } else {
+        month_from_march - 9
+    } as u32;
+    let year = era * 400 + year_of_era + i64::from(month <= 2);
+    (year, month)
+}
+
+/// Number of days in `month` (1-12) of `year`.
+fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Whether a contribution made at `timestamp` for `cycle` is accepted.
+///
+/// Groups without a grace period keep accepting late contributions; setting
+/// one closes the cycle to contributions once the grace window ends.
+pub fn accepts_contribution(group: &Group, cycle: u32, timestamp: u64) -> Option<bool> {
+    if group.grace_period_secs.is_none() {
+        return Some(true);
+    }
+    Some(contribution_timing(group, cycle, timestamp)? != ContributionTiming::Late)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::{hours, SECONDS_PER_DAY, SECONDS_PER_WEEK};
+    use soroban_sdk::{testutils::Address as _, Address, Env};
+
+    const STARTED_AT: u64 = 1_700_000_000;
+
+    fn started_group(env: &Env, grace_period_secs: Option<u64>) -> Group {
+        let mut group = Group::new(1, Address::generate(env), 100, SECONDS_PER_WEEK, 4, 2, 0);
+        group.started = true;
+        group.started_at = STARTED_AT;
+        group.grace_period_secs = grace_period_secs;
+        group
+    }
+
+    #[test]
+    fn test_cycle_boundaries() {
+        assert_eq!(
+            cycle_start_at(STARTED_AT, SECONDS_PER_DAY, 0),
+            Some(STARTED_AT)
+        );
+        assert_eq!(
+            cycle_start_at(STARTED_AT, SECONDS_PER_DAY, 3),
+            Some(STARTED_AT + 3 * SECONDS_PER_DAY)
+        );
+        assert_eq!(
+            deadline_at(STARTED_AT, SECONDS_PER_DAY, 3),
+            Some(STARTED_AT + 4 * SECONDS_PER_DAY)
+        );
+        assert_eq!(deadline_at(u64::MAX - 1, SECONDS_PER_DAY, 0), None);
+        assert_eq!(cycle_start_at(0, u64::MAX, 2), None);
+    }
+
+    #[test]
+    fn test_monthly_deadlines() {
+        let env = Env::default();
+        let mut group = started_group(&env, None);
+        // 2024-01-15 09:30 UTC
+        group.started_at = 1_705_311_000;
+        group.schedule = CycleSchedule::Monthly(31);
+
+        // 2024-01-31, 2024-02-29 (leap year), 2024-03-31 and 2024-04-30
+        let jan_31 = 1_706_659_200;
+        let feb_29 = 1_709_164_800;
+        let mar_31 = 1_711_843_200;
+        let apr_30 = 1_714_435_200;
+        assert_eq!(monthly_deadline_at(jan_31 - 1, 31, 0), Some(feb_29));
+        assert_eq!(contribution_deadline(&group, 0), Some(feb_29));
+        assert_eq!(contribution_deadline(&group, 1), Some(mar_31));
+        assert_eq!(contribution_deadline(&group, 2), Some(apr_30));
+        // 2025-02-28 and 2100-02-28, which is not a leap year
+        assert_eq!(contribution_deadline(&group, 12), Some(1_740_700_800));
+        assert_eq!(contribution_deadline(&group, 912), Some(4_107_456_000));
+
+        assert_eq!(cycle_start(&group, 0), Some(group.started_at));
+        assert_eq!(cycle_start(&group, 1), Some(feb_29));
+        assert_eq!(cycle_at(&group, group.started_at - 1), 0);
+        assert_eq!(cycle_at(&group, feb_29 - 1), 0);
+        assert_eq!(cycle_at(&group, feb_29), 1);
+        assert_eq!(cycle_at(&group, apr_30 + 1), 3);
+        assert_eq!(
+            cycle_at(&group, contribution_deadline(&group, 40).unwrap()),
+            41
+        );
+
+        // Paying on the 1st of each month
+        group.schedule = CycleSchedule::Monthly(1);
+        assert_eq!(contribution_deadline(&group, 0), Some(1_706_745_600));
+        assert_eq!(
+            projected_completion(&group, group.started_at),
+            contribution_deadline(&group, 3)
+        );
+    }
+
+    #[test]
+    fn test_grace_deadline() {
+        let env = Env::default();
+        let deadline = STARTED_AT + SECONDS_PER_WEEK;
+
+        let group = started_group(&env, None);
+        assert_eq!(contribution_deadline(&group, 0), Some(deadline));
+        assert_eq!(grace_deadline(&group, 0), Some(deadline));
+
+        let group = started_group(&env, Some(hours(6)));
+        assert_eq!(grace_deadline(&group, 0), Some(deadline + hours(6)));
+    }
+
+    #[test]
+    fn test_cycle_at() {
+        let env = Env::default();
+        let group = started_group(&env, None);
+
+        assert_eq!(cycle_at(&group, STARTED_AT - 1), 0);
+        assert_eq!(cycle_at(&group, STARTED_AT), 0);
+        assert_eq!(cycle_at(&group, STARTED_AT + SECONDS_PER_WEEK - 1), 0);
+        assert_eq!(cycle_at(&group, STARTED_AT + SECONDS_PER_WEEK), 1);
+        assert_eq!(cycle_at(&group, STARTED_AT + 10 * SECONDS_PER_WEEK), 10);
+    }
+
+    #[test]
+    fn test_seconds_until_deadline_and_completion() {
+        let env = Env::default();
+        let mut group = started_group(&env, None);
+        let deadline = STARTED_AT + SECONDS_PER_WEEK;
+
+        assert_eq!(
+            seconds_until_deadline(&group, 0, STARTED_AT),
+            Some(SECONDS_PER_WEEK)
+        );
+        assert_eq!(seconds_until_deadline(&group, 0, deadline + 1), Some(0));
+
+        // Four cycles on schedule end with the fourth deadline
+        let on_schedule = STARTED_AT + 4 * SECONDS_PER_WEEK;
+        assert_eq!(projected_completion(&group, STARTED_AT), Some(on_schedule));
+
+        // A payout running a day late pushes every later cycle back
+        let late = deadline + SECONDS_PER_DAY;
+        assert_eq!(
+            projected_completion(&group, late),
+            Some(late + 3 * SECONDS_PER_WEEK)
+        );
+
+        group.current_cycle = 3;
+        assert_eq!(projected_completion(&group, STARTED_AT), Some(on_schedule));
+        group.current_cycle = 4;
+        assert_eq!(projected_completion(&group, STARTED_AT), None);
+    }
+
+    #[test]
+    fn test_contribution_timing_boundaries() {
+        let env = Env::default();
+        let group = started_group(&env, Some(hours(6)));
+        let deadline = STARTED_AT + SECONDS_PER_WEEK;
+        let closes = deadline + hours(6);
+
+        assert_eq!(
+            contribution_timing(&group, 0, STARTED_AT),
+            Some(ContributionTiming::OnTime)
+        );
+        assert_eq!(
+            contribution_timing(&group, 0, deadline),
+            Some(ContributionTiming::OnTime)
+        );
+        assert_eq!(
+            contribution_timing(&group, 0, deadline + 1),
+            Some(ContributionTiming::InGrace)
+        );
+        assert_eq!(
+            contribution_timing(&group, 0, closes),
+            Some(ContributionTiming::InGrace)
+        );
+        assert_eq!(
+            contribution_timing(&group, 0, closes + 1),
+            Some(ContributionTiming::Late)
+        );
+
+        assert_eq!(accepts_contribution(&group, 0, closes), Some(true));
+        assert_eq!(accepts_contribution(&group, 0, closes + 1), Some(false));
+    }
+
+    #[test]
+    fn test_no_grace_period_accepts_late_contributions() {
+        let env = Env::default();
+        let group = started_group(&env, None);
+        let deadline = STARTED_AT + SECONDS_PER_WEEK;
+
+        assert_eq!(
+            contribution_timing(&group, 0, deadline + 1),
+            Some(ContributionTiming::Late)
+        );
+        assert_eq!(
+            accepts_contribution(&group, 0, deadline + SECONDS_PER_WEEK),
+            Some(true)
+        );
+    }
+
+    #[test]
+    fn test_zero_grace_period_closes_at_deadline() {
+        let env = Env::default();
+        let group = started_group(&env, Some(0));
+        let deadline = STARTED_AT + SECONDS_PER_WEEK;
+
+        assert_eq!(accepts_contribution(&group, 0, deadline), Some(true));
+        assert_eq!(accepts_contribution(&group, 0, deadline + 1), Some(false));
+    }
+}
@@ -0,0 +1,255 @@
+//! Scenario-based economic simulation.
+//!
+//! Runs many groups under randomized but reproducible member behavior (on time,
+//! late, or defaulting), with and without an insurance reserve, paying real
+//! tokens in and out through `contribute` and `execute_payout`. It checks that
+//! the contract never pays out more than it collected and that its token
+//! balance always covers the fees, reserves and stalled pools it still holds.
+//! For each scenario it also compares the contributions lost to defaults
+//! against the protocol fees collected, reporting the parameter combinations
+//! where fee reserves could not cover the shortfall.
+//!
+//! These tests are slow and only build with the `simulation` feature:
+//!
+//! ```text
+//! cargo test -p stellar-save --features simulation simulation -- --nocapture
+//! ```
+
+extern crate std;
+
+use crate::error::StellarSaveError;
+use crate::testutils;
+use crate::units::{SECONDS_PER_WEEK, STROOPS_PER_XLM};
+use crate::StellarSaveContractClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env};
+use std::println;
+use std::vec::Vec;
+
+const CONTRIBUTION: i128 = STROOPS_PER_XLM;
+const CYCLE_DURATION: u64 = SECONDS_PER_WEEK;
+const GROUPS_PER_SCENARIO: u32 = 8;
+
+/// Small deterministic generator so every run draws the same behaviors.
+struct Lcg(u64);
+
+impl Lcg {
+    fn next_percent(&mut self) -> u32 {
+        self.0 = self
+            .0
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        ((self.0 >> 33) % 100) as u32
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Scenario {
+    group_size: u32,
+    late_pct: u32,
+    default_pct: u32,
+    fee_bps: u32,
+    insurance_bps: u32,
+}
+
+#[derive(Debug, Default)]
+struct Outcome {
+    contributed: i128,
+    paid_out: i128,
+    fees: i128,
+    reserves: i128,
+    stranded: i128,
+    shortfall: i128,
+    late_contributions: u32,
+    covered_cycles: u32,
+    stalled_groups: u32,
+    completed_cycles: u32,
+}
+
+impl Outcome {
+    fn reserves_sufficient(&self) -> bool {
+        self.fees >= self.shortfall
+    }
+}
+
+fn run_scenario(scenario: Scenario, seed: u64) -> Outcome {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.cost_estimate().budget().reset_unlimited();
+    testutils::set_time(&env, 1_700_000_000);
+
+    let contract_id = testutils::register(&env);
+    let client = StellarSaveContractClient::new(&env, &contract_id);
+    client.set_fee_bps(&scenario.fee_bps);
+    let keeper = Address::generate(&env);
+
+    let mut rng = Lcg(seed);
+    let mut outcome = Outcome::default();
+    let contribute = |member: &Address, group_id: u64| {
+        testutils::mint(&env, &contract_id, member, CONTRIBUTION);
+        client.contribute(&group_id, member, &None);
+    };
+
+    for _ in 0..GROUPS_PER_SCENARIO {
+        let creator = Address::generate(&env);
+        let group_id = client.create_group(
+            &creator,
+            &CONTRIBUTION,
+            &CYCLE_DURATION,
+            &scenario.group_size,
+        );
+        let mut members = Vec::new();
+        for _ in 0..scenario.group_size {
+            let member = Address::generate(&env);
+            client.join_group(&group_id, &member, &None);
+            members.push(member);
+        }
+        client.set_insurance_bps(&group_id, &scenario.insurance_bps);
+        testutils::start_group(&env, &contract_id, group_id);
+
+        for _ in 0..scenario.group_size {
+            let mut late = Vec::new();
+            let mut missing = 0i128;
+            let mut collected = 0i128;
+
+            for member in members.iter() {
+                let roll = rng.next_percent();
+                if roll < scenario.default_pct {
+                    missing += CONTRIBUTION;
+                } else if roll < scenario.default_pct + scenario.late_pct {
+                    late.push(member.clone());
+                } else {
+                    contribute(member, group_id);
+                    collected += CONTRIBUTION;
+                }
+            }
+
+            // Late members pay after the cycle deadline has passed.
+            testutils::advance_past_deadline(&env, &contract_id, group_id, 1);
+            for member in late.iter() {
+                contribute(member, group_id);
+                collected += CONTRIBUTION;
+                outcome.late_contributions += 1;
+            }
+            outcome.contributed += collected;
+
+            // An insured group's reserve may cover a default; otherwise it
+            // stalls the rotation and members still owed a payout are the
+            // reserves' problem.
+            match client.try_execute_payout(&group_id, &keeper, &None) {
+                Ok(_) if missing > 0 => outcome.covered_cycles += 1,
+                Ok(_) => {}
+                Err(Ok(StellarSaveError::CycleNotComplete)) if missing > 0 => {
+                    outcome.shortfall += missing;
+                    outcome.stranded += collected;
+                    outcome.stalled_groups += 1;
+                    break;
+                }
+                Err(error) => panic!("{:?} payout failed: {:?}", scenario, error),
+            }
+            outcome.completed_cycles += 1;
+        }
+
+        outcome.paid_out += client.get_total_paid_out(&group_id);
+        outcome.reserves += client.get_insurance_reserve(&group_id);
+    }
+    outcome.fees = client.get_treasury_balance();
+
+    // Solvency: everything paid out or withheld as fees was collected first,
+    // and the contract still holds every stroop it owes.
+    assert!(
+        outcome.paid_out + outcome.fees <= outcome.contributed,
+        "{:?} paid out more than collected: {:?}",
+        scenario,
+        outcome
+    );
+    assert_eq!(
+        testutils::token(&env, &contract_id).balance(&contract_id),
+        outcome.fees + outcome.reserves + outcome.stranded,
+        "{:?} holds other than it owes: {:?}",
+        scenario,
+        outcome
+    );
+
+    outcome
+}
+
+fn scenarios() -> Vec<Scenario> {
+    let mut scenarios = Vec::new();
+    for group_size in [3u32, 5, 10] {
+        for (late_pct, default_pct) in [(0u32, 0u32), (20, 0), (10, 2), (20, 5), (30, 10)] {
+            for fee_bps in [0u32, 100, 500] {
+                for insurance_bps in [0u32, 500] {
+                    scenarios.push(Scenario {
+                        group_size,
+                        late_pct,
+                        default_pct,
+                        fee_bps,
+                        insurance_bps,
+                    });
+                }
+            }
+        }
+    }
+    scenarios
+}
+
+#[test]
+fn simulate_solvency_across_scenarios() {
+    let mut insufficient = Vec::new();
+
+    for (index, scenario) in scenarios().into_iter().enumerate() {
+        let outcome = run_scenario(scenario, 0x5eed + index as u64);
+
+        if scenario.default_pct == 0 {
+            assert_eq!(outcome.stalled_groups, 0, "{:?}", scenario);
+            assert_eq!(
+                outcome.completed_cycles,
+                GROUPS_PER_SCENARIO * scenario.group_size
+            );
+        }
+        if !outcome.reserves_sufficient() {
+            insufficient.push((scenario, outcome));
+        }
+    }
+
+    println!("scenarios where fee reserves cannot cover defaults:");
+    for (scenario, outcome) in insufficient.iter() {
+        println!(
+            "  size={} late={}% default={}% fee={}bps insurance={}bps shortfall={} fees={}",
+            scenario.group_size,
+            scenario.late_pct,
+            scenario.default_pct,
+            scenario.fee_bps,
+            scenario.insurance_bps,
+            outcome.shortfall,
+            outcome.fees
+        );
+    }
+
+    // Fees alone never cover a defaulting population without an insurance
+    // reserve, so this list is expected to be non-empty.
+    assert!(!insufficient.is_empty());
+    assert!(insufficient
+        .iter()
+        .all(|(scenario, _)| scenario.default_pct > 0));
+}
+
+#[test]
+fn simulate_is_reproducible() {
+    let scenario = Scenario {
+        group_size: 5,
+        late_pct: 20,
+        default_pct: 5,
+        fee_bps: 100,
+        insurance_bps: 500,
+    };
+
+    let first = run_scenario(scenario, 42);
+    let second = run_scenario(scenario, 42);
+
+    assert_eq!(first.contributed, second.contributed);
+    assert_eq!(first.shortfall, second.shortfall);
+    assert_eq!(first.late_contributions, second.late_contributions);
+    assert_eq!(first.covered_cycles, second.covered_cycles);
+}
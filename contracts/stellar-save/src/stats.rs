@@ -0,0 +1,136 @@
+//! Protocol-wide statistics.
+//!
+//! Counters are kept in instance storage and updated as groups are created
+//! and closed, gain and lose members, take contributions and pay out, so
+//! dashboards can read the totals from `get_protocol_stats` instead of
+//! replaying the event history. Updates saturate rather than fail: a counter
+//! going wrong must never block the state change it describes.
+
+use crate::storage::{set_instance, StorageKey, StorageKeyBuilder};
+use soroban_sdk::{contracttype, Env};
+
+/// Totals across every group on the contract.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProtocolStats {
+    /// Groups ever created
+    pub total_groups: u64,
+    /// Groups created and not yet completed, cancelled or deleted
+    pub active_groups: u64,
+    /// Memberships across all groups; an address in two groups counts twice
+    pub total_members: u64,
+    /// Contributions recorded and not yet paid out or withdrawn, in stroops
+    pub total_value_locked: i128,
+    /// Sum of every payout, in stroops
+    pub total_paid_out: i128,
+}
+
+/// Reads the current totals.
+pub fn load(env: &Env) -> ProtocolStats {
+    let storage = env.storage().instance();
+    ProtocolStats {
+        total_groups: storage.get(&StorageKeyBuilder::total_groups()).unwrap_or(0),
+        active_groups: storage
+            .get(&StorageKeyBuilder::active_groups())
+            .unwrap_or(0),
+        total_members: storage
+            .get(&StorageKeyBuilder::total_members())
+            .unwrap_or(0),
+        total_value_locked: storage
+            .get(&StorageKeyBuilder::total_value_locked())
+            .unwrap_or(0),
+        total_paid_out: storage
+            .get(&StorageKeyBuilder::total_paid_out())
+            .unwrap_or(0),
+    }
+}
+
+/// Counts a newly created group.
+pub fn record_group_created(env: &Env) {
+    bump_count(env, &StorageKeyBuilder::total_groups(), true);
+    bump_count(env, &StorageKeyBuilder::active_groups(), true);
+}
+
+/// Counts a group that has completed, been cancelled or been deleted.
+pub fn record_group_closed(env: &Env) {
+    bump_count(env, &StorageKeyBuilder::active_groups(), false);
+}
+
+/// Counts a member joining a group.
+pub fn record_member_joined(env: &Env) {
+    bump_count(env, &StorageKeyBuilder::total_members(), true);
+}
+
+/// Counts a member leaving or being removed from a group.
+pub fn record_member_left(env: &Env) {
+    bump_count(env, &StorageKeyBuilder::total_members(), false);
+}
+
+/// Adds a recorded contribution to the value locked.
+pub fn record_locked(env: &Env, amount: i128) {
+    add_amount(env, &StorageKeyBuilder::total_value_locked(), amount);
+}
+
+/// Removes paid out or returned contributions from the value locked.
+pub fn record_released(env: &Env, amount: i128) {
+    let key = StorageKeyBuilder::total_value_locked();
+    let locked: i128 = env.storage().instance().get(&key).unwrap_or(0);
+    set_instance(env, &key, &locked.saturating_sub(amount).max(0));
+}
+
+/// Adds a payout to the total paid out.
+pub fn record_paid_out(env: &Env, amount: i128) {
+    add_amount(env, &StorageKeyBuilder::total_paid_out(), amount);
+}
+
+fn bump_count(env: &Env, key: &StorageKey, up: bool) {
+    let count: u64 = env.storage().instance().get(key).unwrap_or(0);
+    let count = if up {
+        count.saturating_add(1)
+    } else {
+        count.saturating_sub(1)
+    };
+    set_instance(env, key, &count);
+}
+
+fn add_amount(env: &Env, key: &StorageKey, amount: i128) {
+    let total: i128 = env.storage().instance().get(key).unwrap_or(0);
+    set_instance(env, key, &total.saturating_add(amount));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_counters_saturate() {
+        let env = Env::default();
+        let contract_id = crate::testutils::register(&env);
+
+        env.as_contract(&contract_id, || {
+            record_group_closed(&env);
+            record_member_left(&env);
+            record_released(&env, 50);
+            assert_eq!(load(&env).active_groups, 0);
+            assert_eq!(load(&env).total_value_locked, 0);
+
+            record_group_created(&env);
+            record_group_created(&env);
+            record_group_closed(&env);
+            record_member_joined(&env);
+            record_locked(&env, 300);
+            record_released(&env, 100);
+            record_paid_out(&env, 100);
+            assert_eq!(
+                load(&env),
+                ProtocolStats {
+                    total_groups: 2,
+                    active_groups: 1,
+                    total_members: 1,
+                    total_value_locked: 200,
+                    total_paid_out: 100,
+                }
+            );
+        });
+    }
+}
@@ -1,4 +1,27 @@
-use soroban_sdk::{contracterror, contracttype};
+//! Group lifecycle transitions.
+//!
+//! A group's status is kept in its own entry as well as on the stored
+//! `Group`, and every change is announced by a `group_status_changed` event.
+//! `transition` is the one place that changes either: it checks the edge
+//! against `group::GroupStatus::can_transition_to`, keeps both copies in
+//! step and appends the change to the group's status history.
+//!
+//! The `GroupStatus` defined in this module is an older model of the same
+//! lifecycle; groups store `group::GroupStatus`.
+//!
+//! The allowed edges are:
+//! - Pending → Active, Cancelled, Expired
+//! - Active → Paused, Completed, Cancelled
+//! - Paused → Active, Cancelled
+//!
+//! Completed, Cancelled and Expired are terminal.
+
+use crate::error::StellarSaveError;
+use crate::events::EventEmitter;
+use crate::group::{self, Group};
+use crate::reason::ActionNote;
+use crate::storage::{set_persistent, GroupStore, StorageKeyBuilder};
+use soroban_sdk::{contracterror, contracttype, Address, Env, Vec};
 
 /// Error types for invalid state transitions.
 #[contracterror]
@@ -183,9 +206,99 @@ impl GroupStatus {
     }
 }
 
+/// Maximum number of changes kept in a group's status history; the oldest
+/// are dropped first.
+pub const MAX_STATUS_HISTORY: u32 = 32;
+
+/// One change of a group's lifecycle status.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StatusChange {
+    pub from: group::GroupStatus,
+    pub to: group::GroupStatus,
+    pub changed_by: Address,
+    pub changed_at: u64,
+    /// Why the status changed
+    pub note: ActionNote,
+}
+
+/// Returns a group's status, Pending if none is stored.
+pub fn load(env: &Env, group_id: u64) -> group::GroupStatus {
+    env.storage()
+        .persistent()
+        .get(&StorageKeyBuilder::group_status(group_id))
+        .unwrap_or(group::GroupStatus::Pending)
+}
+
+/// Returns a group's status changes, oldest first.
+pub fn history(env: &Env, group_id: u64) -> Vec<StatusChange> {
+    env.storage()
+        .persistent()
+        .get(&StorageKeyBuilder::group_status_history(group_id))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Moves a group from `from` to `to`, storing the new status on both the
+/// status entry and `group`, recording the change and emitting
+/// `group_status_changed`.
+///
+/// Callers check their own preconditions and apply any side effects of the
+/// new status; this only guards the edge itself.
+///
+/// # Errors
+/// * `InvalidState` - If the group's stored status is not `from`, or the
+///   edge from `from` to `to` is not allowed
+pub fn transition(
+    env: &Env,
+    group: &mut Group,
+    from: group::GroupStatus,
+    to: group::GroupStatus,
+    changed_by: Address,
+    note: ActionNote,
+) -> Result<(), StellarSaveError> {
+    if load(env, group.id) != from || from == to || !from.can_transition_to(&to) {
+        return Err(StellarSaveError::InvalidState);
+    }
+
+    set_persistent(env, &StorageKeyBuilder::group_status(group.id), &to);
+    group.status = to.clone();
+    group.is_active = to == group::GroupStatus::Active;
+    GroupStore::save(env, group);
+
+    let changed_at = env.ledger().timestamp();
+    let mut changes = history(env, group.id);
+    if changes.len() >= MAX_STATUS_HISTORY {
+        changes.pop_front();
+    }
+    changes.push_back(StatusChange {
+        from: from.clone(),
+        to: to.clone(),
+        changed_by: changed_by.clone(),
+        changed_at,
+        note,
+    });
+    set_persistent(
+        env,
+        &StorageKeyBuilder::group_status_history(group.id),
+        &changes,
+    );
+
+    EventEmitter::emit_group_status_changed(
+        env,
+        group.id,
+        from as u32,
+        to as u32,
+        changed_by,
+        changed_at,
+    );
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::reason::ActionReason;
+    use soroban_sdk::testutils::Address as _;
 
     #[test]
     fn test_valid_transitions_from_pending() {
@@ -421,4 +534,56 @@ mod tests {
             .transition_to(GroupStatus::Cancelled)
             .is_ok());
     }
+
+    #[test]
+    fn test_transition_keeps_status_and_history_in_step() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let group = crate::testutils::TestGroupBuilder::new(&env)
+            .members(2)
+            .build();
+        let admin = Address::generate(&env);
+
+        env.as_contract(&group.contract_id, || {
+            let mut stored: Group = GroupStore::load(&env, group.group_id).unwrap();
+
+            // The stored status must match, and only listed edges are allowed
+            let stale = transition(
+                &env,
+                &mut stored,
+                group::GroupStatus::Active,
+                group::GroupStatus::Paused,
+                admin.clone(),
+                ActionNote::unstated(),
+            );
+            assert_eq!(stale, Err(StellarSaveError::InvalidState));
+            let skipped = transition(
+                &env,
+                &mut stored,
+                group::GroupStatus::Pending,
+                group::GroupStatus::Completed,
+                admin.clone(),
+                ActionNote::unstated(),
+            );
+            assert_eq!(skipped, Err(StellarSaveError::InvalidState));
+
+            for (from, to) in [
+                (group::GroupStatus::Pending, group::GroupStatus::Active),
+                (group::GroupStatus::Active, group::GroupStatus::Paused),
+                (group::GroupStatus::Paused, group::GroupStatus::Active),
+            ] {
+                let note = ActionNote::new(ActionReason::Emergency);
+                transition(&env, &mut stored, from, to, admin.clone(), note).unwrap();
+            }
+
+            assert_eq!(load(&env, group.group_id), group::GroupStatus::Active);
+            assert_eq!(stored.status, group::GroupStatus::Active);
+            assert!(stored.is_active);
+            let changes = history(&env, group.group_id);
+            assert_eq!(changes.len(), 3);
+            assert_eq!(changes.get(1).unwrap().to, group::GroupStatus::Paused);
+            assert_eq!(changes.get(2).unwrap().changed_by, admin);
+            assert_eq!(changes.get(2).unwrap().note.reason, ActionReason::Emergency);
+        });
+    }
 }
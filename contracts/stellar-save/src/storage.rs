@@ -1,4 +1,7 @@
-use soroban_sdk::{contracttype, Address};
+use crate::group::Group;
+use crate::units::LEDGERS_PER_DAY;
+use crate::MemberProfile;
+use soroban_sdk::{contracttype, Address, Env, IntoVal, TryFromVal, Val};
 
 /// Storage key structure for efficient data access in the Stellar-Save contract.
 ///
@@ -30,6 +33,73 @@ pub enum StorageKey {
 
     /// Keys for various counters and metadata.
     Counter(CounterKey),
+
+    /// Keys for discovery indexes.
+    Index(IndexKey),
+}
+
+impl StorageKey {
+    /// Returns the kind of storage entries under this key are kept in.
+    pub fn tier(&self) -> StorageTier {
+        match self {
+            StorageKey::Group(key) => key.tier(),
+            StorageKey::Counter(key) => key.tier(),
+            _ => StorageTier::Persistent,
+        }
+    }
+
+    /// Returns how entries under this key are kept from expiring.
+    pub fn ttl_policy(&self) -> TtlPolicy {
+        match self.tier() {
+            StorageTier::Instance => TtlPolicy::INSTANCE,
+            StorageTier::Persistent => TtlPolicy::GROUP_LIFETIME,
+            StorageTier::Temporary => TtlPolicy::CYCLE_SCRATCH,
+        }
+    }
+}
+
+/// Kind of Soroban storage an entry is kept in.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum StorageTier {
+    /// Loaded with the contract instance on every call. Suits small global
+    /// configuration and counters.
+    Instance,
+    /// Archived once its TTL runs out, and restorable afterwards.
+    Persistent,
+    /// Deleted for good once its TTL runs out.
+    Temporary,
+}
+
+/// How an entry's TTL is kept up, in ledgers.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TtlPolicy {
+    /// Remaining TTL below which a write extends the entry
+    pub threshold: u32,
+    /// TTL the entry is extended to
+    pub extend_to: u32,
+}
+
+impl TtlPolicy {
+    /// Group data, extended on every write and by `bump_group` so that
+    /// entries of long-running groups are not archived between cycles.
+    pub const GROUP_LIFETIME: TtlPolicy = TtlPolicy {
+        threshold: TTL_THRESHOLD,
+        extend_to: TTL_EXTEND_TO,
+    };
+
+    /// Global configuration, extended together with the contract instance.
+    pub const INSTANCE: TtlPolicy = TtlPolicy {
+        threshold: TTL_THRESHOLD,
+        extend_to: TTL_EXTEND_TO,
+    };
+
+    /// Scratch data for a cycle, which is only read while the cycle runs.
+    /// Kept for about 90 days from when it is written, and extended by
+    /// `bump_group` for cycles that run longer.
+    pub const CYCLE_SCRATCH: TtlPolicy = TtlPolicy {
+        threshold: 7 * LEDGERS_PER_DAY,
+        extend_to: 90 * LEDGERS_PER_DAY,
+    };
 }
 
 /// Storage keys for group-related data.
@@ -50,6 +120,193 @@ pub enum GroupKey {
     /// Group status: GROUP_STATUS_{id}
     /// Stores the current GroupStatus for quick status checks.
     Status(u64),
+
+    /// Group admin: GROUP_ADMIN_{id}
+    /// Stores the address allowed to manage the group (initially the creator).
+    Admin(u64),
+
+    /// Pending group admin: GROUP_PENDING_ADMIN_{id}
+    /// Stores the nominated admin until they accept the transfer.
+    PendingAdmin(u64),
+
+    /// Group halt flag: GROUP_HALTED_{id}
+    /// Set by `halt_group`; freezes every flow for the group.
+    Halted(u64),
+
+    /// Member halt votes: GROUP_HALT_VOTES_{id}
+    /// Members who have asked the guardian to halt the group.
+    HaltVotes(u64),
+
+    /// Group fee total: GROUP_FEES_{id}
+    /// Protocol fees withheld from the group's payouts.
+    FeesCollected(u64),
+
+    /// Payout position index: GROUP_POSITION_{id}_{position}
+    /// Reverse index from a payout position to the member holding it.
+    PositionMember(u64, u32),
+
+    /// Household policy: GROUP_HOUSEHOLD_POLICY_{id}
+    /// Optional seat cap and adjacency rule for linked addresses.
+    HouseholdPolicy(u64),
+
+    /// Insurance reserve: GROUP_INSURANCE_{id}
+    /// Premiums withheld from the group's pools to cover member defaults.
+    InsuranceReserve(u64),
+
+    /// Yield strategy: GROUP_YIELD_CONFIG_{id}
+    /// Strategy contract the group's idle pools are deposited into.
+    YieldConfig(u64),
+
+    /// Outstanding yield deposit: GROUP_YIELD_DEPOSIT_{id}
+    /// The cycle and principal currently held by the strategy.
+    YieldDeposit(u64),
+
+    /// Yield total: GROUP_YIELD_EARNED_{id}
+    /// Yield earned on the group's pools over its lifetime.
+    YieldEarned(u64),
+
+    /// Held-back yield: GROUP_YIELD_RESERVE_{id}
+    /// Yield set aside to be split among members at completion.
+    YieldReserve(u64),
+
+    /// Governance rules: GROUP_GOVERNANCE_{id}
+    /// Quorum, approval threshold and voting period for proposals.
+    GovernanceConfig(u64),
+
+    /// Proposal counter: GROUP_PROPOSAL_COUNT_{id}
+    /// Number of proposals opened in the group, used as the next proposal ID.
+    ProposalCount(u64),
+
+    /// Proposal: GROUP_PROPOSAL_{id}_{proposal_id}
+    Proposal(u64, u32),
+
+    /// Proposal vote: GROUP_PROPOSAL_VOTE_{id}_{proposal_id}_{address}
+    /// Whether the member voted for the proposal.
+    ProposalVote(u64, u32, Address),
+
+    /// Returned total: GROUP_RETURNED_{id}
+    /// Reserves and residual funds paid back to members at completion.
+    Returned(u64),
+
+    /// Final summary: GROUP_SUMMARY_{id}
+    /// Reconciled totals recorded by `finalize_group`.
+    Summary(u64),
+
+    /// Payout order: GROUP_PAYOUT_ORDER_{id}
+    /// Strategy choosing each cycle's recipient; join order when absent.
+    PayoutOrder(u64),
+
+    /// Terms version: GROUP_TERMS_VERSION_{id}
+    /// Number of times the group's terms were changed while Forming.
+    TermsVersion(u64),
+
+    /// Referral bonus: GROUP_REFERRAL_BONUS_{id}
+    /// Creator-funded bonus shared among referrers when the group completes.
+    ReferralBonus(u64),
+
+    /// Group metadata: GROUP_METADATA_{id}
+    /// Name, description and category shown in listings.
+    Metadata(u64),
+
+    /// Savings goal: GROUP_SAVINGS_GOAL_{id}
+    /// Target and unlock date of a savings-only group; absent for rotations.
+    SavingsGoal(u64),
+
+    /// Savings total: GROUP_SAVINGS_TOTAL_{id}
+    /// Principal members of a savings-only group have not yet withdrawn.
+    SavingsTotal(u64),
+
+    /// Savings yield: GROUP_SAVINGS_YIELD_{id}
+    /// Yield added to a savings-only group and not yet withdrawn.
+    SavingsYield(u64),
+
+    /// Emergency penalty: GROUP_EMERGENCY_PENALTY_{id}
+    /// Share of contributions, in basis points, forfeited on emergency exit.
+    EmergencyPenaltyBps(u64),
+
+    /// Arbiter: GROUP_ARBITER_{id}
+    /// Address that resolves the group's disputes; absent if none was named.
+    Arbiter(u64),
+
+    /// Dispute: GROUP_DISPUTE_{id}_{cycle}
+    Dispute(u64, u32),
+
+    /// Payout hook: GROUP_PAYOUT_HOOK_{id}
+    /// Contract notified of every payout the group makes.
+    PayoutHook(u64),
+
+    /// Fiat pricing: GROUP_FIAT_PRICING_{id}
+    /// Oracle settings for a contribution defined in fiat; absent for a
+    /// fixed token amount.
+    FiatPricing(u64),
+
+    /// Price snapshot: GROUP_PRICE_SNAPSHOT_{id}_{cycle}
+    PriceSnapshot(u64, u32),
+
+    /// Archived group: GROUP_ARCHIVED_{id}
+    /// Condensed record left once a finalized group's entries are removed.
+    Archived(u64),
+
+    /// Reminder thresholds: GROUP_REMINDER_THRESHOLDS_{id}
+    /// Seconds before each deadline at which `ping_cycle` sends a reminder.
+    ReminderThresholds(u64),
+
+    /// Reminder sent: GROUP_REMINDER_SENT_{id}_{cycle} (temporary storage)
+    /// Threshold of the last reminder sent for the cycle.
+    ReminderSent(u64, u32),
+
+    /// Previous group: GROUP_PREVIOUS_{id}
+    /// Completed group a restarted group was cloned from.
+    Previous(u64),
+
+    /// Invitees: GROUP_INVITEES_{id}
+    /// Members of the previous group whose seats are still held for them.
+    Invitees(u64),
+
+    /// Roles: GROUP_ROLES_{id}
+    /// Treasurers and moderators the admin has appointed.
+    Roles(u64),
+
+    /// Observers: GROUP_OBSERVERS_{id}
+    /// Non-contributing auditors invited to the group.
+    Observers(u64),
+
+    /// Defaults progress: GROUP_DEFAULTS_PROGRESS_{id}_{cycle} (temporary storage)
+    /// How far `process_defaults` has recorded a cycle's defaults.
+    DefaultsProgress(u64, u32),
+
+    /// Late payout compensation: GROUP_LATE_PAYOUT_COMP_{id}
+    /// Compensation, in basis points per day, owed to a recipient whose
+    /// payout is executed after it fell due.
+    LatePayoutCompensationBps(u64),
+
+    /// Status history: GROUP_STATUS_HISTORY_{id}
+    /// The group's most recent lifecycle status changes.
+    StatusHistory(u64),
+
+    /// Token decimals: GROUP_TOKEN_DECIMALS_{id}
+    /// Decimals of the contribution token, queried when the group was created.
+    TokenDecimals(u64),
+
+    /// Payout in flight: GROUP_PAYOUT_IN_FLIGHT_{id}
+    /// Set while a payout of the group is being carried out.
+    PayoutInFlight(u64),
+
+    /// Join escrow: GROUP_JOIN_ESCROW_{id}
+    /// Set when members deposit their first and last contributions on joining.
+    JoinEscrow(u64),
+
+    /// Attestation gate: GROUP_ATTESTATION_GATE_{id}
+    /// Attestation contract and claim consulted before admitting a member.
+    AttestationGate(u64),
+
+    /// Last activity: GROUP_LAST_ACTIVITY_{id}
+    /// When the group last saw a join, contribution, payout or status change.
+    LastActivity(u64),
+
+    /// Recovery: GROUP_RECOVERY_{id}
+    /// Recovery of the group's funds announced by the protocol admin.
+    Recovery(u64),
 }
 
 /// Storage keys for member-related data.
@@ -70,6 +327,75 @@ pub enum MemberKey {
     /// Member payout eligibility: MEMBER_PAYOUT_{group_id}_{address}
     /// Tracks payout turn order and eligibility status.
     PayoutEligibility(u64, Address),
+
+    /// Member removal record: MEMBER_REMOVAL_{group_id}_{address}
+    /// Stores why and when a member was removed and who replaced them.
+    Removal(u64, Address),
+
+    /// Member household: MEMBER_HOUSEHOLD_{group_id}_{address}
+    /// Household the creator has linked the address to.
+    Household(u64, Address),
+
+    /// Member payout receipt: MEMBER_PAYOUT_RECEIVED_{group_id}_{address}
+    /// Cycle in which the member received their payout, once they have.
+    PayoutReceived(u64, Address),
+
+    /// Member auto-contribution opt-in: MEMBER_AUTO_CONTRIB_{group_id}_{address}
+    /// Present while keepers may pull the member's contributions.
+    AutoContribute(u64, Address),
+
+    /// Former member profile: MEMBER_FORMER_{group_id}_{address}
+    /// Final profile of a removed member, kept for `get_member_profile`.
+    FormerProfile(u64, Address),
+
+    /// Member payout claim: MEMBER_PAYOUT_CLAIM_{group_id}_{address}
+    /// Priority set by the admin or bid placed by the member, ranking them
+    /// for the next payout under need-based and auction ordering.
+    PayoutClaim(u64, Address),
+
+    /// Member referrer: MEMBER_REFERRER_{group_id}_{address}
+    /// Member who referred the address into the group.
+    Referrer(u64, Address),
+
+    /// Member savings balance: MEMBER_SAVINGS_{group_id}_{address}
+    /// Principal the member has saved in a savings-only group.
+    SavingsBalance(u64, Address),
+
+    /// Member shortfall: MEMBER_SHORTFALL_{group_id}_{address}
+    /// Contributions the member missed in cycles that paid out partially.
+    Shortfall(u64, Address),
+
+    /// Member debt: MEMBER_DEBT_{group_id}_{address}
+    /// What the member owes after missing cycles once paid out.
+    Debt(u64, Address),
+
+    /// Penalty waiver: MEMBER_WAIVER_{group_id}_{address}_{cycle}
+    /// Late contribution or default forgiven for one cycle.
+    Waiver(u64, Address, u32),
+
+    /// Accepted payout position: MEMBER_ACCEPTED_POSITION_{group_id}_{address}
+    /// Position the member agreed to in an admin-assigned payout order.
+    AcceptedPosition(u64, Address),
+
+    /// Position swap proposal: MEMBER_SWAP_PROPOSAL_{group_id}_{address}
+    /// Member the proposer has offered to trade payout positions with.
+    SwapProposal(u64, Address),
+
+    /// Contribution credit: MEMBER_CREDIT_{group_id}_{address}
+    /// Overpaid contributions held for the member's later cycles.
+    Credit(u64, Address),
+
+    /// Processed nonce: MEMBER_NONCE_{group_id}_{address}_{nonce}
+    /// Idempotency nonce the member has used in the group.
+    Nonce(u64, Address, u64),
+
+    /// Last-cycle escrow: MEMBER_ESCROW_{group_id}_{address}
+    /// Final cycle's contribution deposited when the member joined.
+    Escrow(u64, Address),
+
+    /// Pending deposit: MEMBER_PENDING_DEPOSIT_{group_id}_{address}
+    /// Contribution parked for the member until its cycle opens.
+    PendingDeposit(u64, Address),
 }
 
 /// Storage keys for contribution tracking.
@@ -90,6 +416,23 @@ pub enum ContributionKey {
     /// Cycle contributor count: CONTRIB_COUNT_{group_id}_{cycle}
     /// Tracks how many members have contributed in the current cycle.
     CycleCount(u64, u32),
+
+    /// Cycle contributors: CONTRIB_INDEX_{group_id}_{cycle}
+    /// Addresses that contributed in a cycle, in contribution order.
+    CycleContributors(u64, u32),
+
+    /// Contribution swap: CONTRIB_SWAP_{group_id}_{cycle}_{address}
+    /// The swap a member paid a cycle's contribution with, if they paid in
+    /// another asset.
+    Swap(u64, u32, Address),
+
+    /// Contribution chain head: CONTRIB_CHAIN_{group_id}
+    /// Hash of the group's latest contribution record.
+    ChainHead(u64),
+
+    /// Cycle checkpoint: CONTRIB_CHECKPOINT_{group_id}_{cycle}
+    /// Hash chain head, count and total of a cycle's chained contributions.
+    Checkpoint(u64, u32),
 }
 
 /// Storage keys for payout records.
@@ -110,6 +453,11 @@ pub enum PayoutKey {
     /// Payout status: PAYOUT_STATUS_{group_id}_{cycle}
     /// Tracks whether the payout has been processed for the cycle.
     Status(u64, u32),
+
+    /// Payout share: PAYOUT_SHARE_{group_id}_{cycle}_{index}
+    /// PayoutRecord of a cycle's second and later recipients in a group that
+    /// pays several members per cycle. The first recipient's is at `Record`.
+    Share(u64, u32, u32),
 }
 
 /// Storage keys for counters and global metadata.
@@ -123,15 +471,15 @@ pub enum CounterKey {
     /// Provides unique sequential IDs for new groups.
     NextGroupId,
 
-    /// Total groups created: COUNTER_TOTAL_GROUPS
+    /// Total groups created: COUNTER_TOTAL_GROUPS (instance storage)
     /// Tracks the total number of groups ever created.
     TotalGroups,
 
-    /// Active groups count: COUNTER_ACTIVE_GROUPS
-    /// Tracks the number of currently active groups.
+    /// Active groups count: COUNTER_ACTIVE_GROUPS (instance storage)
+    /// Groups created and not yet completed, cancelled or deleted.
     ActiveGroups,
 
-    /// Total members across all groups: COUNTER_TOTAL_MEMBERS
+    /// Total members across all groups: COUNTER_TOTAL_MEMBERS (instance storage)
     /// Global member count for statistics.
     TotalMembers,
 
@@ -144,6 +492,171 @@ pub enum CounterKey {
 
     /// Reentrancy protection flag for transfer operations.
     ReentrancyGuard,
+
+    /// Protocol admin address: PROTOCOL_ADMIN (instance storage)
+    /// Set once by `initialize`; controls fees and the treasury.
+    ProtocolAdmin,
+
+    /// Protocol fee in basis points: PROTOCOL_FEE_BPS (instance storage)
+    /// Deducted from each payout and credited to the treasury.
+    ProtocolFeeBps,
+
+    /// Accumulated protocol fees: TREASURY_BALANCE
+    TreasuryBalance,
+
+    /// Per-transaction transfer caps: RISK_LIMITS (instance storage)
+    /// Set by the protocol admin; unset means uncapped.
+    RiskLimits,
+
+    /// Protocol guardian address: GUARDIAN
+    /// Co-signs group halts together with the creator or a member quorum.
+    Guardian,
+
+    /// Whitelisted yield strategies: YIELD_STRATEGIES
+    /// Strategy contracts group admins may deposit idle pools into.
+    YieldStrategies,
+
+    /// Contribution token: CONTRIBUTION_TOKEN (instance storage)
+    /// Token contract contributions, payouts and refunds are made in.
+    ContributionToken,
+
+    /// Protocol referral reward: REFERRAL_REWARD
+    /// Paid from the treasury for each referral in a completed group.
+    ReferralReward,
+
+    /// Unclaimed referral rewards: REFERRAL_REWARDS_{address}
+    /// Rewards accrued to a referrer across groups, until claimed.
+    ReferralRewards(Address),
+
+    /// Protocol caps on group parameters: SAFETY_LIMITS (instance storage)
+    /// Checked when groups are created or updated; unset means uncapped.
+    SafetyLimits,
+
+    /// Next template ID counter: COUNTER_TEMPLATE_ID
+    NextTemplateId,
+
+    /// Group template: TEMPLATE_{id}
+    /// Configuration new groups can be created from.
+    Template(u64),
+
+    /// Total value locked: COUNTER_TVL (instance storage)
+    /// Contributions recorded and not yet paid out or withdrawn.
+    TotalValueLocked,
+
+    /// Total paid out: COUNTER_PAID_OUT (instance storage)
+    /// Sum of every payout across all groups.
+    TotalPaidOut,
+
+    /// Blacklisted address: BLACKLISTED_{address}
+    /// When the protocol admin barred the address from creating or joining
+    /// groups.
+    Blacklisted(Address),
+
+    /// Recovery inactivity period: RECOVERY_INACTIVITY_PERIOD (instance storage)
+    /// How long a group must be inactive before its funds can be recovered.
+    RecoveryInactivityPeriod,
+
+    /// Group event sequence: EVENT_SEQUENCE_{id}
+    /// Sequence number of the last event published about a group. Kept when
+    /// the group is archived, so its sequence never restarts.
+    EventSequence(u64),
+
+    /// Group rounding policy: GROUP_ROUNDING_POLICY_{id}
+    /// Where the part of a pool that does not split evenly among a cycle's
+    /// recipients goes; unset means the treasury.
+    RoundingPolicy(u64),
+
+    /// Carried remainder: GROUP_CARRIED_REMAINDER_{id}
+    /// Remainder of the last payout held back for the group's next cycle.
+    CarriedRemainder(u64),
+
+    /// Swap router: SWAP_ROUTER (instance storage)
+    /// Router contributions paid in another asset are swapped through.
+    SwapRouter,
+
+    /// Payout approval policy: GROUP_APPROVAL_POLICY_{id}
+    /// Approvers whose sign-off large payouts need, and how many.
+    PayoutApprovalPolicy(u64),
+
+    /// Payout approvals: PAYOUT_APPROVALS_{group_id}_{cycle}
+    /// Approvals recorded for a cycle's payout.
+    PayoutApprovals(u64, u32),
+
+    /// Debug diagnostics flag: DEBUG_DIAGNOSTICS (instance storage)
+    /// Present while failure paths publish `ErrorDiagnostic` events.
+    DebugDiagnostics,
+
+    /// Recipient contribution rule: GROUP_RECIPIENT_CONTRIBUTES_{id}
+    /// Whether a cycle's recipients pay into the pool they receive; unset
+    /// means they do.
+    RecipientContributes(u64),
+}
+
+impl GroupKey {
+    /// Per-cycle scratch data that can be rebuilt or safely forgotten lives
+    /// in temporary storage, which costs less rent and expires on its own.
+    fn tier(&self) -> StorageTier {
+        match self {
+            GroupKey::ReminderSent(..) | GroupKey::DefaultsProgress(..) => StorageTier::Temporary,
+            _ => StorageTier::Persistent,
+        }
+    }
+}
+
+impl CounterKey {
+    /// Counters and configuration read on most calls live in instance
+    /// storage; the rest are persistent.
+    fn tier(&self) -> StorageTier {
+        match self {
+            CounterKey::TotalGroups
+            | CounterKey::ActiveGroups
+            | CounterKey::TotalMembers
+            | CounterKey::ProtocolAdmin
+            | CounterKey::ProtocolFeeBps
+            | CounterKey::RiskLimits
+            | CounterKey::ContributionToken
+            | CounterKey::SafetyLimits
+            | CounterKey::TotalValueLocked
+            | CounterKey::TotalPaidOut
+            | CounterKey::RecoveryInactivityPeriod
+            | CounterKey::SwapRouter
+            | CounterKey::DebugDiagnostics => StorageTier::Instance,
+            _ => StorageTier::Persistent,
+        }
+    }
+}
+
+/// Storage keys for discovery indexes.
+///
+/// Indexes are maintained as groups are created and change state, so queries
+/// can page through a subset of groups without scanning every group ID.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum IndexKey {
+    /// Joinable groups before schema version 17: INDEX_OPEN_GROUPS
+    /// One list of every open group ID. `migrate` moves it into
+    /// `OpenGroupsPage` entries and removes it.
+    OpenGroups,
+
+    /// Groups by creator: INDEX_CREATOR_GROUPS_{address}
+    /// IDs of every group created by an address, in creation order.
+    CreatorGroups(Address),
+
+    /// Groups by member: INDEX_MEMBER_GROUPS_{address}
+    /// IDs of the groups an address currently belongs to, in join order.
+    /// Groups are dropped when the member leaves or the group completes, is
+    /// cancelled or expires.
+    MemberGroups(Address),
+
+    /// Membership badges: INDEX_MEMBER_BADGES_{address}
+    /// IDs of the groups an address holds a membership badge for, in mint
+    /// order. Unlike `MemberGroups`, groups stay listed once they complete.
+    MemberBadges(Address),
+
+    /// Joinable groups: INDEX_OPEN_GROUPS_{page}
+    /// IDs of Pending groups that still have free seats, in creation order,
+    /// among the `OPEN_GROUPS_PAGE_SIZE` group IDs of the page.
+    OpenGroupsPage(u64),
 }
 
 /// Utility functions for creating storage keys with consistent formatting.
@@ -170,6 +683,243 @@ impl StorageKeyBuilder {
         StorageKey::Group(GroupKey::Status(group_id))
     }
 
+    /// Creates a key for storing the group admin.
+    pub fn group_admin(group_id: u64) -> StorageKey {
+        StorageKey::Group(GroupKey::Admin(group_id))
+    }
+
+    /// Creates a key for storing the pending group admin.
+    pub fn group_pending_admin(group_id: u64) -> StorageKey {
+        StorageKey::Group(GroupKey::PendingAdmin(group_id))
+    }
+
+    /// Creates a key for the group halt flag.
+    pub fn group_halted(group_id: u64) -> StorageKey {
+        StorageKey::Group(GroupKey::Halted(group_id))
+    }
+
+    /// Creates a key for the members' halt votes.
+    pub fn group_halt_votes(group_id: u64) -> StorageKey {
+        StorageKey::Group(GroupKey::HaltVotes(group_id))
+    }
+
+    /// Creates a key for the protocol fees collected from a group.
+    pub fn group_fees_collected(group_id: u64) -> StorageKey {
+        StorageKey::Group(GroupKey::FeesCollected(group_id))
+    }
+
+    /// Creates a key for the member holding a payout position.
+    pub fn group_position_member(group_id: u64, position: u32) -> StorageKey {
+        StorageKey::Group(GroupKey::PositionMember(group_id, position))
+    }
+
+    /// Creates a key for a group's household policy.
+    pub fn group_household_policy(group_id: u64) -> StorageKey {
+        StorageKey::Group(GroupKey::HouseholdPolicy(group_id))
+    }
+
+    /// Creates a key for a group's insurance reserve balance.
+    pub fn group_insurance_reserve(group_id: u64) -> StorageKey {
+        StorageKey::Group(GroupKey::InsuranceReserve(group_id))
+    }
+
+    /// Creates a key for a group's yield strategy configuration.
+    pub fn group_yield_config(group_id: u64) -> StorageKey {
+        StorageKey::Group(GroupKey::YieldConfig(group_id))
+    }
+
+    /// Creates a key for a group's outstanding yield deposit.
+    pub fn group_yield_deposit(group_id: u64) -> StorageKey {
+        StorageKey::Group(GroupKey::YieldDeposit(group_id))
+    }
+
+    /// Creates a key for the total yield a group has earned.
+    pub fn group_yield_earned(group_id: u64) -> StorageKey {
+        StorageKey::Group(GroupKey::YieldEarned(group_id))
+    }
+
+    /// Creates a key for yield held back for a group's members.
+    pub fn group_yield_reserve(group_id: u64) -> StorageKey {
+        StorageKey::Group(GroupKey::YieldReserve(group_id))
+    }
+
+    /// Creates a key for a group's governance rules.
+    pub fn group_governance_config(group_id: u64) -> StorageKey {
+        StorageKey::Group(GroupKey::GovernanceConfig(group_id))
+    }
+
+    /// Creates a key for a group's proposal counter.
+    pub fn group_proposal_count(group_id: u64) -> StorageKey {
+        StorageKey::Group(GroupKey::ProposalCount(group_id))
+    }
+
+    /// Creates a key for one of a group's proposals.
+    pub fn group_proposal(group_id: u64, proposal_id: u32) -> StorageKey {
+        StorageKey::Group(GroupKey::Proposal(group_id, proposal_id))
+    }
+
+    /// Creates a key for a member's vote on a proposal.
+    pub fn group_proposal_vote(group_id: u64, proposal_id: u32, member: Address) -> StorageKey {
+        StorageKey::Group(GroupKey::ProposalVote(group_id, proposal_id, member))
+    }
+
+    /// Creates a key for the total a group has returned to its members.
+    pub fn group_returned(group_id: u64) -> StorageKey {
+        StorageKey::Group(GroupKey::Returned(group_id))
+    }
+
+    /// Creates a key for a finalized group's summary.
+    pub fn group_summary(group_id: u64) -> StorageKey {
+        StorageKey::Group(GroupKey::Summary(group_id))
+    }
+
+    /// Creates a key for a group's payout order.
+    pub fn group_payout_order(group_id: u64) -> StorageKey {
+        StorageKey::Group(GroupKey::PayoutOrder(group_id))
+    }
+
+    /// Creates a key for the version of a group's terms.
+    pub fn group_terms_version(group_id: u64) -> StorageKey {
+        StorageKey::Group(GroupKey::TermsVersion(group_id))
+    }
+
+    /// Creates a key for a group's creator-funded referral bonus.
+    pub fn group_referral_bonus(group_id: u64) -> StorageKey {
+        StorageKey::Group(GroupKey::ReferralBonus(group_id))
+    }
+
+    /// Creates a key for a group's listing metadata.
+    pub fn group_metadata(group_id: u64) -> StorageKey {
+        StorageKey::Group(GroupKey::Metadata(group_id))
+    }
+
+    /// Creates a key for a savings-only group's goal.
+    pub fn group_savings_goal(group_id: u64) -> StorageKey {
+        StorageKey::Group(GroupKey::SavingsGoal(group_id))
+    }
+
+    /// Creates a key for the principal held by a savings-only group.
+    pub fn group_savings_total(group_id: u64) -> StorageKey {
+        StorageKey::Group(GroupKey::SavingsTotal(group_id))
+    }
+
+    /// Creates a key for the yield held by a savings-only group.
+    pub fn group_savings_yield(group_id: u64) -> StorageKey {
+        StorageKey::Group(GroupKey::SavingsYield(group_id))
+    }
+
+    /// Creates a key for the penalty a group charges on emergency withdrawal.
+    pub fn group_emergency_penalty_bps(group_id: u64) -> StorageKey {
+        StorageKey::Group(GroupKey::EmergencyPenaltyBps(group_id))
+    }
+
+    /// Creates a key for the address that resolves a group's disputes.
+    pub fn group_arbiter(group_id: u64) -> StorageKey {
+        StorageKey::Group(GroupKey::Arbiter(group_id))
+    }
+
+    /// Creates a key for the dispute opened over a cycle of a group.
+    pub fn group_dispute(group_id: u64, cycle: u32) -> StorageKey {
+        StorageKey::Group(GroupKey::Dispute(group_id, cycle))
+    }
+
+    /// Creates a key for the contract notified of a group's payouts.
+    pub fn group_payout_hook(group_id: u64) -> StorageKey {
+        StorageKey::Group(GroupKey::PayoutHook(group_id))
+    }
+
+    /// Creates a key for a group's fiat pricing settings.
+    pub fn group_fiat_pricing(group_id: u64) -> StorageKey {
+        StorageKey::Group(GroupKey::FiatPricing(group_id))
+    }
+
+    /// Creates a key for the price a cycle's contribution was set from.
+    pub fn group_price_snapshot(group_id: u64, cycle: u32) -> StorageKey {
+        StorageKey::Group(GroupKey::PriceSnapshot(group_id, cycle))
+    }
+
+    /// Creates a key for the condensed record of an archived group.
+    pub fn group_archived(group_id: u64) -> StorageKey {
+        StorageKey::Group(GroupKey::Archived(group_id))
+    }
+
+    /// Creates a key for a group's contribution reminder thresholds.
+    pub fn group_reminder_thresholds(group_id: u64) -> StorageKey {
+        StorageKey::Group(GroupKey::ReminderThresholds(group_id))
+    }
+
+    /// Creates a key for the last reminder sent for a cycle.
+    pub fn group_reminder_sent(group_id: u64, cycle: u32) -> StorageKey {
+        StorageKey::Group(GroupKey::ReminderSent(group_id, cycle))
+    }
+
+    /// Creates a key for the group a restarted group was cloned from.
+    pub fn group_previous(group_id: u64) -> StorageKey {
+        StorageKey::Group(GroupKey::Previous(group_id))
+    }
+
+    /// Creates a key for the members invited back into a restarted group.
+    pub fn group_invitees(group_id: u64) -> StorageKey {
+        StorageKey::Group(GroupKey::Invitees(group_id))
+    }
+
+    /// Creates a key for the roles appointed in a group.
+    pub fn group_roles(group_id: u64) -> StorageKey {
+        StorageKey::Group(GroupKey::Roles(group_id))
+    }
+
+    /// Creates a key for the observers invited to a group.
+    pub fn group_observers(group_id: u64) -> StorageKey {
+        StorageKey::Group(GroupKey::Observers(group_id))
+    }
+
+    /// Creates a key for the progress of chunked default processing in a
+    /// cycle of a group.
+    pub fn group_defaults_progress(group_id: u64, cycle: u32) -> StorageKey {
+        StorageKey::Group(GroupKey::DefaultsProgress(group_id, cycle))
+    }
+
+    /// Creates a key for the compensation a group pays on late payouts.
+    pub fn group_late_payout_compensation_bps(group_id: u64) -> StorageKey {
+        StorageKey::Group(GroupKey::LatePayoutCompensationBps(group_id))
+    }
+
+    /// Creates a key for the lifecycle status changes of a group.
+    pub fn group_status_history(group_id: u64) -> StorageKey {
+        StorageKey::Group(GroupKey::StatusHistory(group_id))
+    }
+
+    /// Creates a key for the contribution token decimals a group was created with.
+    pub fn group_token_decimals(group_id: u64) -> StorageKey {
+        StorageKey::Group(GroupKey::TokenDecimals(group_id))
+    }
+
+    /// Creates a key for the flag set while a group's payout is carried out.
+    pub fn group_payout_in_flight(group_id: u64) -> StorageKey {
+        StorageKey::Group(GroupKey::PayoutInFlight(group_id))
+    }
+
+    /// Creates a key for the flag requiring a group's members to escrow
+    /// their last contribution on joining.
+    pub fn group_join_escrow(group_id: u64) -> StorageKey {
+        StorageKey::Group(GroupKey::JoinEscrow(group_id))
+    }
+
+    /// Creates a key for the attestation gate a group admits members through.
+    pub fn group_attestation_gate(group_id: u64) -> StorageKey {
+        StorageKey::Group(GroupKey::AttestationGate(group_id))
+    }
+
+    /// Creates a key for when a group last saw activity.
+    pub fn group_last_activity(group_id: u64) -> StorageKey {
+        StorageKey::Group(GroupKey::LastActivity(group_id))
+    }
+
+    /// Creates a key for the recovery announced for a group's funds.
+    pub fn group_recovery(group_id: u64) -> StorageKey {
+        StorageKey::Group(GroupKey::Recovery(group_id))
+    }
+
     // Member key builders
 
     /// Creates a key for storing member profile data.
@@ -187,6 +937,91 @@ impl StorageKeyBuilder {
         StorageKey::Member(MemberKey::PayoutEligibility(group_id, address))
     }
 
+    /// Creates a key for a member removal record.
+    pub fn member_removal(group_id: u64, address: Address) -> StorageKey {
+        StorageKey::Member(MemberKey::Removal(group_id, address))
+    }
+
+    /// Creates a key for the household an address is linked to.
+    pub fn member_household(group_id: u64, address: Address) -> StorageKey {
+        StorageKey::Member(MemberKey::Household(group_id, address))
+    }
+
+    /// Creates a key for the cycle in which a member received their payout.
+    pub fn member_payout_received(group_id: u64, address: Address) -> StorageKey {
+        StorageKey::Member(MemberKey::PayoutReceived(group_id, address))
+    }
+
+    /// Creates a key for a member's auto-contribution opt-in.
+    pub fn member_auto_contribute(group_id: u64, address: Address) -> StorageKey {
+        StorageKey::Member(MemberKey::AutoContribute(group_id, address))
+    }
+
+    /// Creates a key for a removed member's final profile.
+    pub fn member_former_profile(group_id: u64, address: Address) -> StorageKey {
+        StorageKey::Member(MemberKey::FormerProfile(group_id, address))
+    }
+
+    /// Creates a key for a member's claim on the next payout.
+    pub fn member_payout_claim(group_id: u64, address: Address) -> StorageKey {
+        StorageKey::Member(MemberKey::PayoutClaim(group_id, address))
+    }
+
+    /// Creates a key for the member who referred an address into a group.
+    pub fn member_referrer(group_id: u64, address: Address) -> StorageKey {
+        StorageKey::Member(MemberKey::Referrer(group_id, address))
+    }
+
+    /// Creates a key for a member's balance in a savings-only group.
+    pub fn member_savings_balance(group_id: u64, address: Address) -> StorageKey {
+        StorageKey::Member(MemberKey::SavingsBalance(group_id, address))
+    }
+
+    /// Creates a key for the contributions a member owes from partial payouts.
+    pub fn member_shortfall(group_id: u64, address: Address) -> StorageKey {
+        StorageKey::Member(MemberKey::Shortfall(group_id, address))
+    }
+
+    /// Creates a key for a member's debt record.
+    pub fn member_debt(group_id: u64, address: Address) -> StorageKey {
+        StorageKey::Member(MemberKey::Debt(group_id, address))
+    }
+
+    /// Creates a key for the penalty waiver of a member's cycle.
+    pub fn member_waiver(group_id: u64, address: Address, cycle: u32) -> StorageKey {
+        StorageKey::Member(MemberKey::Waiver(group_id, address, cycle))
+    }
+
+    /// Creates a key for the payout position a member has accepted.
+    pub fn member_accepted_position(group_id: u64, address: Address) -> StorageKey {
+        StorageKey::Member(MemberKey::AcceptedPosition(group_id, address))
+    }
+
+    /// Creates a key for a member's pending position swap proposal.
+    pub fn member_swap_proposal(group_id: u64, address: Address) -> StorageKey {
+        StorageKey::Member(MemberKey::SwapProposal(group_id, address))
+    }
+
+    /// Creates a key for a member's contribution credit.
+    pub fn member_credit(group_id: u64, address: Address) -> StorageKey {
+        StorageKey::Member(MemberKey::Credit(group_id, address))
+    }
+
+    /// Creates a key for an idempotency nonce a member has used.
+    pub fn member_nonce(group_id: u64, address: Address, nonce: u64) -> StorageKey {
+        StorageKey::Member(MemberKey::Nonce(group_id, address, nonce))
+    }
+
+    /// Creates a key for a member's escrowed last-cycle contribution.
+    pub fn member_escrow(group_id: u64, address: Address) -> StorageKey {
+        StorageKey::Member(MemberKey::Escrow(group_id, address))
+    }
+
+    /// Creates a key for a member's pending deposit.
+    pub fn member_pending_deposit(group_id: u64, address: Address) -> StorageKey {
+        StorageKey::Member(MemberKey::PendingDeposit(group_id, address))
+    }
+
     // Contribution key builders
 
     /// Creates a key for individual contribution records.
@@ -204,6 +1039,26 @@ impl StorageKeyBuilder {
         StorageKey::Contribution(ContributionKey::CycleCount(group_id, cycle))
     }
 
+    /// Creates a key for the ordered list of a cycle's contributors.
+    pub fn contribution_cycle_contributors(group_id: u64, cycle: u32) -> StorageKey {
+        StorageKey::Contribution(ContributionKey::CycleContributors(group_id, cycle))
+    }
+
+    /// Creates a key for the swap a member paid a cycle's contribution with.
+    pub fn contribution_swap(group_id: u64, cycle: u32, member: Address) -> StorageKey {
+        StorageKey::Contribution(ContributionKey::Swap(group_id, cycle, member))
+    }
+
+    /// Creates a key for the hash of a group's latest contribution record.
+    pub fn contribution_chain_head(group_id: u64) -> StorageKey {
+        StorageKey::Contribution(ContributionKey::ChainHead(group_id))
+    }
+
+    /// Creates a key for the hash chain checkpoint of a cycle.
+    pub fn contribution_checkpoint(group_id: u64, cycle: u32) -> StorageKey {
+        StorageKey::Contribution(ContributionKey::Checkpoint(group_id, cycle))
+    }
+
     // Payout key builders
 
     /// Creates a key for payout records.
@@ -221,6 +1076,15 @@ impl StorageKeyBuilder {
         StorageKey::Payout(PayoutKey::Status(group_id, cycle))
     }
 
+    /// Creates a key for the payout record of a cycle's `index`-th
+    /// recipient. Index 0 is the cycle's `payout_record`.
+    pub fn payout_record_at(group_id: u64, cycle: u32, index: u32) -> StorageKey {
+        match index {
+            0 => Self::payout_record(group_id, cycle),
+            _ => StorageKey::Payout(PayoutKey::Share(group_id, cycle, index)),
+        }
+    }
+
     // Counter key builders
 
     /// Creates a key for the next group ID counter.
@@ -257,6 +1121,273 @@ impl StorageKeyBuilder {
     pub fn reentrancy_guard() -> StorageKey {
         StorageKey::Counter(CounterKey::ReentrancyGuard)
     }
+
+    /// Creates a key for the protocol admin address.
+    pub fn protocol_admin() -> StorageKey {
+        StorageKey::Counter(CounterKey::ProtocolAdmin)
+    }
+
+    /// Creates a key for the protocol fee in basis points.
+    pub fn protocol_fee_bps() -> StorageKey {
+        StorageKey::Counter(CounterKey::ProtocolFeeBps)
+    }
+
+    /// Creates a key for the accumulated treasury balance.
+    pub fn treasury_balance() -> StorageKey {
+        StorageKey::Counter(CounterKey::TreasuryBalance)
+    }
+
+    /// Creates a key for the per-transaction transfer caps.
+    pub fn risk_limits() -> StorageKey {
+        StorageKey::Counter(CounterKey::RiskLimits)
+    }
+
+    /// Creates a key for the protocol guardian address.
+    pub fn guardian() -> StorageKey {
+        StorageKey::Counter(CounterKey::Guardian)
+    }
+
+    /// Creates a key for the whitelist of yield strategies.
+    pub fn yield_strategies() -> StorageKey {
+        StorageKey::Counter(CounterKey::YieldStrategies)
+    }
+
+    /// Creates a key for the token contributions are paid in.
+    pub fn contribution_token() -> StorageKey {
+        StorageKey::Counter(CounterKey::ContributionToken)
+    }
+
+    /// Creates a key for the protocol's per-referral reward.
+    pub fn referral_reward() -> StorageKey {
+        StorageKey::Counter(CounterKey::ReferralReward)
+    }
+
+    /// Creates a key for a referrer's unclaimed rewards.
+    pub fn referral_rewards(referrer: Address) -> StorageKey {
+        StorageKey::Counter(CounterKey::ReferralRewards(referrer))
+    }
+
+    /// Creates a key for the protocol caps on group parameters.
+    pub fn safety_limits() -> StorageKey {
+        StorageKey::Counter(CounterKey::SafetyLimits)
+    }
+
+    /// Creates a key for the next template ID counter.
+    pub fn next_template_id() -> StorageKey {
+        StorageKey::Counter(CounterKey::NextTemplateId)
+    }
+
+    /// Creates a key for a group template.
+    pub fn template(template_id: u64) -> StorageKey {
+        StorageKey::Counter(CounterKey::Template(template_id))
+    }
+
+    /// Creates a key for the protocol-wide total value locked.
+    pub fn total_value_locked() -> StorageKey {
+        StorageKey::Counter(CounterKey::TotalValueLocked)
+    }
+
+    /// Creates a key for the protocol-wide total paid out.
+    pub fn total_paid_out() -> StorageKey {
+        StorageKey::Counter(CounterKey::TotalPaidOut)
+    }
+
+    /// Creates a key for an address's protocol blacklist entry.
+    pub fn blacklisted(address: Address) -> StorageKey {
+        StorageKey::Counter(CounterKey::Blacklisted(address))
+    }
+
+    /// Creates a key for the inactivity period before a group's funds can
+    /// be recovered.
+    pub fn recovery_inactivity_period() -> StorageKey {
+        StorageKey::Counter(CounterKey::RecoveryInactivityPeriod)
+    }
+
+    /// Creates a key for the sequence number of a group's last event.
+    pub fn event_sequence(group_id: u64) -> StorageKey {
+        StorageKey::Counter(CounterKey::EventSequence(group_id))
+    }
+
+    /// Creates a key for a group's rounding policy.
+    pub fn group_rounding_policy(group_id: u64) -> StorageKey {
+        StorageKey::Counter(CounterKey::RoundingPolicy(group_id))
+    }
+
+    /// Creates a key for the payout remainder a group carries to its next
+    /// cycle.
+    pub fn group_carried_remainder(group_id: u64) -> StorageKey {
+        StorageKey::Counter(CounterKey::CarriedRemainder(group_id))
+    }
+
+    /// Creates a key for whether a group's recipients contribute to the
+    /// cycle that pays them.
+    pub fn group_recipient_contributes(group_id: u64) -> StorageKey {
+        StorageKey::Counter(CounterKey::RecipientContributes(group_id))
+    }
+
+    /// Creates a key for the protocol's swap router.
+    pub fn swap_router() -> StorageKey {
+        StorageKey::Counter(CounterKey::SwapRouter)
+    }
+
+    /// Creates a key for a group's payout approval policy.
+    pub fn group_approval_policy(group_id: u64) -> StorageKey {
+        StorageKey::Counter(CounterKey::PayoutApprovalPolicy(group_id))
+    }
+
+    /// Creates a key for the approvals recorded for a cycle's payout.
+    pub fn payout_approvals(group_id: u64, cycle: u32) -> StorageKey {
+        StorageKey::Counter(CounterKey::PayoutApprovals(group_id, cycle))
+    }
+
+    /// Creates a key for the protocol's debug diagnostics flag.
+    pub fn debug_diagnostics() -> StorageKey {
+        StorageKey::Counter(CounterKey::DebugDiagnostics)
+    }
+
+    // Index key builders
+
+    /// Creates a key for the single index of joinable groups kept before
+    /// schema version 17.
+    pub fn open_groups_index() -> StorageKey {
+        StorageKey::Index(IndexKey::OpenGroups)
+    }
+
+    /// Creates a key for one page of the index of joinable groups.
+    pub fn open_groups_page(page: u64) -> StorageKey {
+        StorageKey::Index(IndexKey::OpenGroupsPage(page))
+    }
+
+    /// Creates a key for the index of groups created by an address.
+    pub fn creator_groups_index(creator: Address) -> StorageKey {
+        StorageKey::Index(IndexKey::CreatorGroups(creator))
+    }
+
+    /// Creates a key for the index of groups an address belongs to.
+    pub fn member_groups_index(member: Address) -> StorageKey {
+        StorageKey::Index(IndexKey::MemberGroups(member))
+    }
+
+    /// Creates a key for the index of groups an address holds a badge for.
+    pub fn member_badges_index(member: Address) -> StorageKey {
+        StorageKey::Index(IndexKey::MemberBadges(member))
+    }
+}
+
+/// Persistent entries are extended once their remaining TTL drops below this
+/// many ledgers (about 30 days).
+pub const TTL_THRESHOLD: u32 = 30 * LEDGERS_PER_DAY;
+
+/// Number of ledgers persistent entries are extended to (about 180 days).
+pub const TTL_EXTEND_TO: u32 = 180 * LEDGERS_PER_DAY;
+
+/// Writes a persistent entry and extends its TTL.
+///
+/// All contract writes go through this so that entries belonging to
+/// long-running groups are not archived between cycles.
+pub fn set_persistent<V: IntoVal<Env, Val>>(env: &Env, key: &StorageKey, value: &V) {
+    let storage = env.storage().persistent();
+    let policy = key.ttl_policy();
+    storage.set(key, value);
+    storage.extend_ttl(key, policy.threshold, policy.extend_to);
+}
+
+/// Writes a global configuration entry to instance storage and extends the
+/// instance TTL.
+///
+/// Instance entries share the contract instance's lifetime and are loaded
+/// with it, so configuration read on most calls costs no extra entry.
+pub fn set_instance<V: IntoVal<Env, Val>>(env: &Env, key: &StorageKey, value: &V) {
+    let storage = env.storage().instance();
+    let policy = key.ttl_policy();
+    storage.set(key, value);
+    storage.extend_ttl(policy.threshold, policy.extend_to);
+}
+
+/// Extends the TTL of a persistent or temporary entry if it exists,
+/// according to its key's policy. Instance entries are extended with the
+/// instance instead.
+///
+/// Returns `true` if the entry was present.
+pub fn extend(env: &Env, key: &StorageKey) -> bool {
+    let policy = key.ttl_policy();
+    match key.tier() {
+        StorageTier::Instance => return false,
+        StorageTier::Persistent => {
+            let storage = env.storage().persistent();
+            if !storage.has(key) {
+                return false;
+            }
+            storage.extend_ttl(key, policy.threshold, policy.extend_to);
+        }
+        StorageTier::Temporary => {
+            let storage = env.storage().temporary();
+            if !storage.has(key) {
+                return false;
+            }
+            storage.extend_ttl(key, policy.threshold, policy.extend_to);
+        }
+    }
+    true
+}
+
+/// Reads an entry from the storage its key belongs in.
+pub fn load<V: TryFromVal<Env, Val>>(env: &Env, key: &StorageKey) -> Option<V> {
+    match key.tier() {
+        StorageTier::Instance => env.storage().instance().get(key),
+        StorageTier::Persistent => env.storage().persistent().get(key),
+        StorageTier::Temporary => env.storage().temporary().get(key),
+    }
+}
+
+/// Writes an entry to the storage its key belongs in and extends its TTL
+/// according to the key's policy.
+pub fn save<V: IntoVal<Env, Val>>(env: &Env, key: &StorageKey, value: &V) {
+    match key.tier() {
+        StorageTier::Instance => set_instance(env, key, value),
+        StorageTier::Persistent => set_persistent(env, key, value),
+        StorageTier::Temporary => {
+            let storage = env.storage().temporary();
+            let policy = key.ttl_policy();
+            storage.set(key, value);
+            storage.extend_ttl(key, policy.threshold, policy.extend_to);
+        }
+    }
+}
+
+/// Typed access to stored groups.
+pub struct GroupStore;
+
+impl GroupStore {
+    /// Loads a group, or `None` if there is no group with this ID.
+    pub fn load(env: &Env, group_id: u64) -> Option<Group> {
+        load(env, &StorageKeyBuilder::group_data(group_id))
+    }
+
+    /// Saves a group under its ID.
+    pub fn save(env: &Env, group: &Group) {
+        save(env, &StorageKeyBuilder::group_data(group.id), group);
+    }
+}
+
+/// Typed access to stored member profiles.
+pub struct MemberStore;
+
+impl MemberStore {
+    /// Loads a member's profile, or `None` if `address` is not a member of
+    /// the group.
+    pub fn load(env: &Env, group_id: u64, address: &Address) -> Option<MemberProfile> {
+        load(
+            env,
+            &StorageKeyBuilder::member_profile(group_id, address.clone()),
+        )
+    }
+
+    /// Saves a profile under its group and address.
+    pub fn save(env: &Env, profile: &MemberProfile) {
+        let key = StorageKeyBuilder::member_profile(profile.group_id, profile.address.clone());
+        save(env, &key, profile);
+    }
 }
 
 /// Constants for storage key prefixes used in string representations.
@@ -273,6 +1404,147 @@ pub mod key_prefixes {
     /// Group status prefix
     pub const GROUP_STATUS: &str = "GROUP_STATUS";
 
+    /// Group admin prefix
+    pub const GROUP_ADMIN: &str = "GROUP_ADMIN";
+
+    /// Pending group admin prefix
+    pub const GROUP_PENDING_ADMIN: &str = "GROUP_PENDING_ADMIN";
+
+    /// Group halt flag prefix
+    pub const GROUP_HALTED: &str = "GROUP_HALTED";
+
+    /// Group halt votes prefix
+    pub const GROUP_HALT_VOTES: &str = "GROUP_HALT_VOTES";
+
+    /// Group fee total prefix
+    pub const GROUP_FEES: &str = "GROUP_FEES";
+
+    /// Payout position index prefix
+    pub const GROUP_POSITION: &str = "GROUP_POSITION";
+
+    /// Household policy prefix
+    pub const GROUP_HOUSEHOLD_POLICY: &str = "GROUP_HOUSEHOLD_POLICY";
+
+    /// Insurance reserve prefix
+    pub const GROUP_INSURANCE: &str = "GROUP_INSURANCE";
+
+    /// Yield strategy configuration prefix
+    pub const GROUP_YIELD_CONFIG: &str = "GROUP_YIELD_CONFIG";
+
+    /// Outstanding yield deposit prefix
+    pub const GROUP_YIELD_DEPOSIT: &str = "GROUP_YIELD_DEPOSIT";
+
+    /// Yield total prefix
+    pub const GROUP_YIELD_EARNED: &str = "GROUP_YIELD_EARNED";
+
+    /// Held-back yield prefix
+    pub const GROUP_YIELD_RESERVE: &str = "GROUP_YIELD_RESERVE";
+
+    /// Governance rules prefix
+    pub const GROUP_GOVERNANCE: &str = "GROUP_GOVERNANCE";
+
+    /// Proposal counter prefix
+    pub const GROUP_PROPOSAL_COUNT: &str = "GROUP_PROPOSAL_COUNT";
+
+    /// Proposal prefix
+    pub const GROUP_PROPOSAL: &str = "GROUP_PROPOSAL";
+
+    /// Proposal vote prefix
+    pub const GROUP_PROPOSAL_VOTE: &str = "GROUP_PROPOSAL_VOTE";
+
+    /// Returned total prefix
+    pub const GROUP_RETURNED: &str = "GROUP_RETURNED";
+
+    /// Final summary prefix
+    pub const GROUP_SUMMARY: &str = "GROUP_SUMMARY";
+
+    /// Payout order prefix
+    pub const GROUP_PAYOUT_ORDER: &str = "GROUP_PAYOUT_ORDER";
+
+    /// Terms version prefix
+    pub const GROUP_TERMS_VERSION: &str = "GROUP_TERMS_VERSION";
+
+    /// Referral bonus prefix
+    pub const GROUP_REFERRAL_BONUS: &str = "GROUP_REFERRAL_BONUS";
+
+    /// Group metadata prefix
+    pub const GROUP_METADATA: &str = "GROUP_METADATA";
+
+    /// Savings goal prefix
+    pub const GROUP_SAVINGS_GOAL: &str = "GROUP_SAVINGS_GOAL";
+
+    /// Savings principal prefix
+    pub const GROUP_SAVINGS_TOTAL: &str = "GROUP_SAVINGS_TOTAL";
+
+    /// Savings yield prefix
+    pub const GROUP_SAVINGS_YIELD: &str = "GROUP_SAVINGS_YIELD";
+
+    /// Emergency withdrawal penalty prefix
+    pub const GROUP_EMERGENCY_PENALTY: &str = "GROUP_EMERGENCY_PENALTY";
+
+    /// Arbiter prefix
+    pub const GROUP_ARBITER: &str = "GROUP_ARBITER";
+
+    /// Dispute prefix
+    pub const GROUP_DISPUTE: &str = "GROUP_DISPUTE";
+
+    /// Payout hook prefix
+    pub const GROUP_PAYOUT_HOOK: &str = "GROUP_PAYOUT_HOOK";
+
+    /// Fiat pricing prefix
+    pub const GROUP_FIAT_PRICING: &str = "GROUP_FIAT_PRICING";
+
+    /// Price snapshot prefix
+    pub const GROUP_PRICE_SNAPSHOT: &str = "GROUP_PRICE_SNAPSHOT";
+
+    /// Archived group prefix
+    pub const GROUP_ARCHIVED: &str = "GROUP_ARCHIVED";
+
+    /// Reminder thresholds prefix
+    pub const GROUP_REMINDER_THRESHOLDS: &str = "GROUP_REMINDER_THRESHOLDS";
+
+    /// Reminder sent prefix
+    pub const GROUP_REMINDER_SENT: &str = "GROUP_REMINDER_SENT";
+
+    /// Previous group prefix
+    pub const GROUP_PREVIOUS: &str = "GROUP_PREVIOUS";
+
+    /// Invitees prefix
+    pub const GROUP_INVITEES: &str = "GROUP_INVITEES";
+
+    /// Roles prefix
+    pub const GROUP_ROLES: &str = "GROUP_ROLES";
+
+    /// Observers prefix
+    pub const GROUP_OBSERVERS: &str = "GROUP_OBSERVERS";
+
+    /// Defaults progress prefix
+    pub const GROUP_DEFAULTS_PROGRESS: &str = "GROUP_DEFAULTS_PROGRESS";
+
+    /// Late payout compensation prefix
+    pub const GROUP_LATE_PAYOUT_COMP: &str = "GROUP_LATE_PAYOUT_COMP";
+
+    /// Status history prefix
+    pub const GROUP_STATUS_HISTORY: &str = "GROUP_STATUS_HISTORY";
+
+    /// Token decimals prefix
+    pub const GROUP_TOKEN_DECIMALS: &str = "GROUP_TOKEN_DECIMALS";
+
+    /// Payout in flight prefix
+    pub const GROUP_PAYOUT_IN_FLIGHT: &str = "GROUP_PAYOUT_IN_FLIGHT";
+
+    /// Join escrow prefix
+    pub const GROUP_JOIN_ESCROW: &str = "GROUP_JOIN_ESCROW";
+
+    /// Attestation gate prefix
+    pub const GROUP_ATTESTATION_GATE: &str = "GROUP_ATTESTATION_GATE";
+
+    /// Last activity prefix
+    pub const GROUP_LAST_ACTIVITY: &str = "GROUP_LAST_ACTIVITY";
+
+    /// Recovery prefix
+    pub const GROUP_RECOVERY: &str = "GROUP_RECOVERY";
+
     /// Member profile prefix
     pub const MEMBER: &str = "MEMBER";
 
@@ -282,6 +1554,54 @@ pub mod key_prefixes {
     /// Member payout eligibility prefix
     pub const MEMBER_PAYOUT: &str = "MEMBER_PAYOUT";
 
+    /// Member removal record prefix
+    pub const MEMBER_REMOVAL: &str = "MEMBER_REMOVAL";
+
+    /// Member household prefix
+    pub const MEMBER_HOUSEHOLD: &str = "MEMBER_HOUSEHOLD";
+
+    /// Member payout receipt prefix
+    pub const MEMBER_PAYOUT_RECEIVED: &str = "MEMBER_PAYOUT_RECEIVED";
+
+    /// Member auto-contribution opt-in prefix
+    pub const MEMBER_AUTO_CONTRIB: &str = "MEMBER_AUTO_CONTRIB";
+
+    /// Former member profile prefix
+    pub const MEMBER_FORMER: &str = "MEMBER_FORMER";
+
+    /// Member payout claim prefix
+    pub const MEMBER_PAYOUT_CLAIM: &str = "MEMBER_PAYOUT_CLAIM";
+
+    /// Member referrer prefix
+    pub const MEMBER_REFERRER: &str = "MEMBER_REFERRER";
+
+    /// Member savings balance prefix
+    pub const MEMBER_SAVINGS: &str = "MEMBER_SAVINGS";
+
+    /// Member shortfall prefix
+    pub const MEMBER_SHORTFALL: &str = "MEMBER_SHORTFALL";
+
+    /// Member debt prefix
+    pub const MEMBER_DEBT: &str = "MEMBER_DEBT";
+
+    /// Member penalty waiver prefix
+    pub const MEMBER_WAIVER: &str = "MEMBER_WAIVER";
+
+    /// Member accepted payout position prefix
+    pub const MEMBER_ACCEPTED_POSITION: &str = "MEMBER_ACCEPTED_POSITION";
+
+    /// Member position swap proposal prefix
+    pub const MEMBER_SWAP_PROPOSAL: &str = "MEMBER_SWAP_PROPOSAL";
+
+    /// Member contribution credit prefix
+    pub const MEMBER_CREDIT: &str = "MEMBER_CREDIT";
+
+    /// Member idempotency nonce prefix
+    pub const MEMBER_NONCE: &str = "MEMBER_NONCE";
+
+    /// Member last-cycle escrow prefix
+    pub const MEMBER_ESCROW: &str = "MEMBER_ESCROW";
+
     /// Individual contribution prefix
     pub const CONTRIB: &str = "CONTRIB";
 
@@ -291,6 +1611,18 @@ pub mod key_prefixes {
     /// Cycle contributor count prefix
     pub const CONTRIB_COUNT: &str = "CONTRIB_COUNT";
 
+    /// Cycle contributors index prefix
+    pub const CONTRIB_INDEX: &str = "CONTRIB_INDEX";
+
+    /// Contribution swap prefix
+    pub const CONTRIB_SWAP: &str = "CONTRIB_SWAP";
+
+    /// Contribution chain head prefix
+    pub const CONTRIB_CHAIN: &str = "CONTRIB_CHAIN";
+
+    /// Cycle checkpoint prefix
+    pub const CONTRIB_CHECKPOINT: &str = "CONTRIB_CHECKPOINT";
+
     /// Payout record prefix
     pub const PAYOUT: &str = "PAYOUT";
 
@@ -300,8 +1632,80 @@ pub mod key_prefixes {
     /// Payout status prefix
     pub const PAYOUT_STATUS: &str = "PAYOUT_STATUS";
 
+    /// Additional payout recipient record prefix
+    pub const PAYOUT_SHARE: &str = "PAYOUT_SHARE";
+
     /// Counter prefix
     pub const COUNTER: &str = "COUNTER";
+
+    /// Protocol admin prefix
+    pub const PROTOCOL_ADMIN: &str = "PROTOCOL_ADMIN";
+
+    /// Protocol fee prefix
+    pub const PROTOCOL_FEE_BPS: &str = "PROTOCOL_FEE_BPS";
+
+    /// Treasury balance prefix
+    pub const TREASURY_BALANCE: &str = "TREASURY_BALANCE";
+
+    /// Risk limits prefix
+    pub const RISK_LIMITS: &str = "RISK_LIMITS";
+
+    /// Guardian prefix
+    pub const GUARDIAN: &str = "GUARDIAN";
+
+    /// Yield strategy whitelist prefix
+    pub const YIELD_STRATEGIES: &str = "YIELD_STRATEGIES";
+
+    /// Contribution token prefix
+    pub const CONTRIBUTION_TOKEN: &str = "CONTRIBUTION_TOKEN";
+
+    /// Protocol referral reward prefix
+    pub const REFERRAL_REWARD: &str = "REFERRAL_REWARD";
+
+    /// Unclaimed referral rewards prefix
+    pub const REFERRAL_REWARDS: &str = "REFERRAL_REWARDS";
+
+    /// Group parameter caps prefix
+    pub const SAFETY_LIMITS: &str = "SAFETY_LIMITS";
+
+    /// Group template prefix
+    pub const TEMPLATE: &str = "TEMPLATE";
+
+    /// Protocol blacklist prefix
+    pub const BLACKLISTED: &str = "BLACKLISTED";
+
+    /// Recovery inactivity period prefix
+    pub const RECOVERY_INACTIVITY_PERIOD: &str = "RECOVERY_INACTIVITY_PERIOD";
+
+    /// Group event sequence prefix
+    pub const EVENT_SEQUENCE: &str = "EVENT_SEQUENCE";
+
+    /// Group rounding policy prefix
+    pub const GROUP_ROUNDING_POLICY: &str = "GROUP_ROUNDING_POLICY";
+
+    /// Carried payout remainder prefix
+    pub const GROUP_CARRIED_REMAINDER: &str = "GROUP_CARRIED_REMAINDER";
+
+    /// Swap router prefix
+    pub const SWAP_ROUTER: &str = "SWAP_ROUTER";
+
+    /// Payout approval policy prefix
+    pub const GROUP_APPROVAL_POLICY: &str = "GROUP_APPROVAL_POLICY";
+
+    /// Payout approvals prefix
+    pub const PAYOUT_APPROVALS: &str = "PAYOUT_APPROVALS";
+
+    /// Open groups index prefix
+    pub const INDEX_OPEN_GROUPS: &str = "INDEX_OPEN_GROUPS";
+
+    /// Groups-by-creator index prefix
+    pub const INDEX_CREATOR_GROUPS: &str = "INDEX_CREATOR_GROUPS";
+
+    /// Groups-by-member index prefix
+    pub const INDEX_MEMBER_GROUPS: &str = "INDEX_MEMBER_GROUPS";
+
+    /// Membership badges index prefix
+    pub const INDEX_MEMBER_BADGES: &str = "INDEX_MEMBER_BADGES";
 }
 
 #[cfg(test)]
@@ -325,11 +1729,132 @@ mod tests {
         let data_key = StorageKeyBuilder::group_data(group_id);
         let members_key = StorageKeyBuilder::group_members(group_id);
         let status_key = StorageKeyBuilder::group_status(group_id);
+        let admin_key = StorageKeyBuilder::group_admin(group_id);
+        let pending_admin_key = StorageKeyBuilder::group_pending_admin(group_id);
+        let halted_key = StorageKeyBuilder::group_halted(group_id);
+        let halt_votes_key = StorageKeyBuilder::group_halt_votes(group_id);
+        let fees_key = StorageKeyBuilder::group_fees_collected(group_id);
+        let position_key = StorageKeyBuilder::group_position_member(group_id, 0);
+        let household_policy_key = StorageKeyBuilder::group_household_policy(group_id);
+        let insurance_key = StorageKeyBuilder::group_insurance_reserve(group_id);
+        let yield_config_key = StorageKeyBuilder::group_yield_config(group_id);
+        let yield_deposit_key = StorageKeyBuilder::group_yield_deposit(group_id);
+        let yield_earned_key = StorageKeyBuilder::group_yield_earned(group_id);
+        let yield_reserve_key = StorageKeyBuilder::group_yield_reserve(group_id);
+        let governance_key = StorageKeyBuilder::group_governance_config(group_id);
+        let proposal_count_key = StorageKeyBuilder::group_proposal_count(group_id);
+        let proposal_key = StorageKeyBuilder::group_proposal(group_id, 0);
+        let returned_key = StorageKeyBuilder::group_returned(group_id);
+        let summary_key = StorageKeyBuilder::group_summary(group_id);
+        let payout_order_key = StorageKeyBuilder::group_payout_order(group_id);
+        let terms_version_key = StorageKeyBuilder::group_terms_version(group_id);
+        let referral_bonus_key = StorageKeyBuilder::group_referral_bonus(group_id);
+        let metadata_key = StorageKeyBuilder::group_metadata(group_id);
+        let savings_goal_key = StorageKeyBuilder::group_savings_goal(group_id);
+        let savings_total_key = StorageKeyBuilder::group_savings_total(group_id);
+        let savings_yield_key = StorageKeyBuilder::group_savings_yield(group_id);
+        let emergency_penalty_key = StorageKeyBuilder::group_emergency_penalty_bps(group_id);
+        let arbiter_key = StorageKeyBuilder::group_arbiter(group_id);
+        let dispute_key = StorageKeyBuilder::group_dispute(group_id, 0);
+        let payout_hook_key = StorageKeyBuilder::group_payout_hook(group_id);
+        let fiat_pricing_key = StorageKeyBuilder::group_fiat_pricing(group_id);
+        let price_snapshot_key = StorageKeyBuilder::group_price_snapshot(group_id, 0);
+        let archived_key = StorageKeyBuilder::group_archived(group_id);
+        let reminder_thresholds_key = StorageKeyBuilder::group_reminder_thresholds(group_id);
 
         // Verify the keys are different
         assert_ne!(data_key, members_key);
         assert_ne!(data_key, status_key);
         assert_ne!(members_key, status_key);
+        assert_ne!(admin_key, pending_admin_key);
+        assert_ne!(admin_key, data_key);
+        assert_ne!(halted_key, halt_votes_key);
+        assert_ne!(halted_key, status_key);
+        assert_ne!(fees_key, data_key);
+        assert_ne!(household_policy_key, data_key);
+        assert_ne!(insurance_key, fees_key);
+        assert_ne!(yield_config_key, yield_deposit_key);
+        assert_ne!(yield_earned_key, yield_reserve_key);
+        assert_ne!(yield_reserve_key, insurance_key);
+        assert_ne!(governance_key, proposal_count_key);
+        assert_ne!(proposal_key, StorageKeyBuilder::group_proposal(group_id, 1));
+        assert_ne!(returned_key, fees_key);
+        assert_ne!(summary_key, data_key);
+        assert_ne!(payout_order_key, data_key);
+        assert_ne!(terms_version_key, payout_order_key);
+        assert_ne!(referral_bonus_key, fees_key);
+        assert_ne!(metadata_key, data_key);
+        assert_ne!(savings_goal_key, savings_total_key);
+        assert_ne!(savings_total_key, savings_yield_key);
+        assert_ne!(emergency_penalty_key, insurance_key);
+        assert_ne!(arbiter_key, admin_key);
+        assert_ne!(payout_hook_key, yield_config_key);
+        assert_ne!(fiat_pricing_key, payout_hook_key);
+        assert_ne!(archived_key, summary_key);
+        assert_ne!(reminder_thresholds_key, payout_hook_key);
+        assert_ne!(
+            StorageKeyBuilder::group_previous(group_id),
+            StorageKeyBuilder::group_invitees(group_id)
+        );
+        assert_ne!(StorageKeyBuilder::group_roles(group_id), admin_key);
+        assert_ne!(
+            StorageKeyBuilder::group_observers(group_id),
+            StorageKeyBuilder::group_roles(group_id)
+        );
+        assert_ne!(
+            StorageKeyBuilder::group_defaults_progress(group_id, 0),
+            StorageKeyBuilder::group_defaults_progress(group_id, 1)
+        );
+        assert_ne!(
+            StorageKeyBuilder::group_late_payout_compensation_bps(group_id),
+            emergency_penalty_key
+        );
+        assert_ne!(
+            StorageKeyBuilder::group_status_history(group_id),
+            status_key
+        );
+        assert_ne!(StorageKeyBuilder::group_token_decimals(group_id), data_key);
+        assert_ne!(
+            StorageKeyBuilder::group_join_escrow(group_id),
+            StorageKeyBuilder::group_payout_in_flight(group_id)
+        );
+        assert_ne!(
+            StorageKeyBuilder::group_attestation_gate(group_id),
+            StorageKeyBuilder::group_join_escrow(group_id)
+        );
+        assert_ne!(
+            StorageKeyBuilder::group_last_activity(group_id),
+            StorageKeyBuilder::group_recovery(group_id)
+        );
+        assert_ne!(
+            StorageKeyBuilder::group_payout_in_flight(group_id),
+            StorageKeyBuilder::payout_recipient(group_id, 0)
+        );
+        assert_ne!(
+            StorageKeyBuilder::group_rounding_policy(group_id),
+            StorageKeyBuilder::group_carried_remainder(group_id)
+        );
+        assert_ne!(
+            StorageKeyBuilder::group_recipient_contributes(group_id),
+            StorageKeyBuilder::group_rounding_policy(group_id)
+        );
+        assert_ne!(
+            StorageKeyBuilder::payout_approvals(group_id, 0),
+            StorageKeyBuilder::payout_approvals(group_id, 1)
+        );
+        assert_ne!(
+            StorageKeyBuilder::group_reminder_sent(group_id, 0),
+            StorageKeyBuilder::group_reminder_sent(group_id, 1)
+        );
+        assert_ne!(
+            price_snapshot_key,
+            StorageKeyBuilder::group_price_snapshot(group_id, 1)
+        );
+        assert_ne!(dispute_key, StorageKeyBuilder::group_dispute(group_id, 1));
+        assert_ne!(
+            position_key,
+            StorageKeyBuilder::group_position_member(group_id, 1)
+        );
 
         // Verify they contain the correct group ID
         match data_key {
@@ -347,11 +1872,55 @@ mod tests {
         let profile_key = StorageKeyBuilder::member_profile(group_id, address.clone());
         let contrib_key = StorageKeyBuilder::member_contribution_status(group_id, address.clone());
         let payout_key = StorageKeyBuilder::member_payout_eligibility(group_id, address.clone());
+        let removal_key = StorageKeyBuilder::member_removal(group_id, address.clone());
+        let household_key = StorageKeyBuilder::member_household(group_id, address.clone());
+        let received_key = StorageKeyBuilder::member_payout_received(group_id, address.clone());
+        let auto_key = StorageKeyBuilder::member_auto_contribute(group_id, address.clone());
+        let former_key = StorageKeyBuilder::member_former_profile(group_id, address.clone());
+        let claim_key = StorageKeyBuilder::member_payout_claim(group_id, address.clone());
+        let referrer_key = StorageKeyBuilder::member_referrer(group_id, address.clone());
+        let savings_key = StorageKeyBuilder::member_savings_balance(group_id, address.clone());
+        let shortfall_key = StorageKeyBuilder::member_shortfall(group_id, address.clone());
+        let debt_key = StorageKeyBuilder::member_debt(group_id, address.clone());
+        let waiver_key = StorageKeyBuilder::member_waiver(group_id, address.clone(), 0);
+        let accepted_key = StorageKeyBuilder::member_accepted_position(group_id, address.clone());
+        let swap_key = StorageKeyBuilder::member_swap_proposal(group_id, address.clone());
+        let credit_key = StorageKeyBuilder::member_credit(group_id, address.clone());
+        let nonce_key = StorageKeyBuilder::member_nonce(group_id, address.clone(), 0);
 
         // Verify all keys are different
         assert_ne!(profile_key, contrib_key);
         assert_ne!(profile_key, payout_key);
         assert_ne!(contrib_key, payout_key);
+        assert_ne!(profile_key, removal_key);
+        assert_ne!(removal_key, household_key);
+        assert_ne!(payout_key, received_key);
+        assert_ne!(contrib_key, auto_key);
+        assert_ne!(profile_key, former_key);
+        assert_ne!(payout_key, claim_key);
+        assert_ne!(profile_key, referrer_key);
+        assert_ne!(contrib_key, savings_key);
+        assert_ne!(savings_key, shortfall_key);
+        assert_ne!(shortfall_key, debt_key);
+        assert_ne!(
+            waiver_key,
+            StorageKeyBuilder::member_waiver(group_id, address.clone(), 1)
+        );
+        assert_ne!(payout_key, accepted_key);
+        assert_ne!(accepted_key, swap_key);
+        assert_ne!(debt_key, credit_key);
+        assert_ne!(
+            credit_key,
+            StorageKeyBuilder::member_escrow(group_id, address.clone())
+        );
+        assert_ne!(
+            credit_key,
+            StorageKeyBuilder::member_pending_deposit(group_id, address.clone())
+        );
+        assert_ne!(
+            nonce_key,
+            StorageKeyBuilder::member_nonce(group_id, address.clone(), 1)
+        );
 
         // Verify they contain the correct data
         match profile_key {
@@ -374,11 +1943,17 @@ mod tests {
             StorageKeyBuilder::contribution_individual(group_id, cycle, address.clone());
         let total_key = StorageKeyBuilder::contribution_cycle_total(group_id, cycle);
         let count_key = StorageKeyBuilder::contribution_cycle_count(group_id, cycle);
+        let contributors_key = StorageKeyBuilder::contribution_cycle_contributors(group_id, cycle);
+        let chain_key = StorageKeyBuilder::contribution_chain_head(group_id);
+        let checkpoint_key = StorageKeyBuilder::contribution_checkpoint(group_id, cycle);
 
         // Verify all keys are different
         assert_ne!(individual_key, total_key);
         assert_ne!(individual_key, count_key);
         assert_ne!(total_key, count_key);
+        assert_ne!(count_key, contributors_key);
+        assert_ne!(chain_key, checkpoint_key);
+        assert_ne!(checkpoint_key, total_key);
 
         // Verify they contain the correct data
         match individual_key {
@@ -399,11 +1974,17 @@ mod tests {
         let record_key = StorageKeyBuilder::payout_record(group_id, cycle);
         let recipient_key = StorageKeyBuilder::payout_recipient(group_id, cycle);
         let status_key = StorageKeyBuilder::payout_status(group_id, cycle);
+        let share_key = StorageKeyBuilder::payout_record_at(group_id, cycle, 1);
 
         // Verify all keys are different
         assert_ne!(record_key, recipient_key);
         assert_ne!(record_key, status_key);
         assert_ne!(recipient_key, status_key);
+        assert_ne!(record_key, share_key);
+        assert_eq!(
+            StorageKeyBuilder::payout_record_at(group_id, cycle, 0),
+            record_key
+        );
 
         // Verify they contain the correct data
         match record_key {
@@ -422,6 +2003,24 @@ mod tests {
         let active_groups_key = StorageKeyBuilder::active_groups();
         let total_members_key = StorageKeyBuilder::total_members();
         let version_key = StorageKeyBuilder::contract_version();
+        let protocol_admin_key = StorageKeyBuilder::protocol_admin();
+        let fee_bps_key = StorageKeyBuilder::protocol_fee_bps();
+        let treasury_key = StorageKeyBuilder::treasury_balance();
+        let risk_limits_key = StorageKeyBuilder::risk_limits();
+        let guardian_key = StorageKeyBuilder::guardian();
+        let strategies_key = StorageKeyBuilder::yield_strategies();
+        let token_key = StorageKeyBuilder::contribution_token();
+        let referral_reward_key = StorageKeyBuilder::referral_reward();
+        let referral_rewards_key =
+            StorageKeyBuilder::referral_rewards(Address::generate(&Env::default()));
+        let safety_limits_key = StorageKeyBuilder::safety_limits();
+        let next_template_key = StorageKeyBuilder::next_template_id();
+        let template_key = StorageKeyBuilder::template(1);
+        let tvl_key = StorageKeyBuilder::total_value_locked();
+        let paid_out_key = StorageKeyBuilder::total_paid_out();
+        let blacklisted_key = StorageKeyBuilder::blacklisted(Address::generate(&Env::default()));
+        let inactivity_key = StorageKeyBuilder::recovery_inactivity_period();
+        let sequence_key = StorageKeyBuilder::event_sequence(1);
 
         // Verify all keys are different
         let keys = [
@@ -430,6 +2029,23 @@ mod tests {
             &active_groups_key,
             &total_members_key,
             &version_key,
+            &protocol_admin_key,
+            &fee_bps_key,
+            &treasury_key,
+            &risk_limits_key,
+            &guardian_key,
+            &strategies_key,
+            &token_key,
+            &referral_reward_key,
+            &referral_rewards_key,
+            &safety_limits_key,
+            &next_template_key,
+            &template_key,
+            &tvl_key,
+            &paid_out_key,
+            &blacklisted_key,
+            &inactivity_key,
+            &sequence_key,
         ];
 
         for i in 0..keys.len() {
@@ -449,6 +2065,72 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_index_key_builders() {
+        let env = Env::default();
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+
+        let open_key = StorageKeyBuilder::open_groups_index();
+        let first_page_key = StorageKeyBuilder::open_groups_page(0);
+        let second_page_key = StorageKeyBuilder::open_groups_page(1);
+        let alice_key = StorageKeyBuilder::creator_groups_index(alice.clone());
+        let bob_key = StorageKeyBuilder::creator_groups_index(bob);
+        let member_key = StorageKeyBuilder::member_groups_index(alice.clone());
+        let badge_key = StorageKeyBuilder::member_badges_index(alice.clone());
+
+        assert_ne!(open_key, alice_key);
+        assert_ne!(open_key, first_page_key);
+        assert_ne!(first_page_key, second_page_key);
+        assert_ne!(alice_key, bob_key);
+        assert_ne!(alice_key, member_key);
+        assert_ne!(member_key, badge_key);
+
+        match alice_key {
+            StorageKey::Index(IndexKey::CreatorGroups(addr)) => assert_eq!(addr, alice),
+            _ => panic!("Wrong key type"),
+        }
+    }
+
+    #[test]
+    fn test_key_tiers() {
+        let env = Env::default();
+        let address = Address::generate(&env);
+
+        assert_eq!(
+            StorageKeyBuilder::protocol_admin().tier(),
+            StorageTier::Instance
+        );
+        assert_eq!(
+            StorageKeyBuilder::total_value_locked().tier(),
+            StorageTier::Instance
+        );
+        assert_eq!(
+            StorageKeyBuilder::swap_router().tier(),
+            StorageTier::Instance
+        );
+        assert_eq!(
+            StorageKeyBuilder::next_group_id().tier(),
+            StorageTier::Persistent
+        );
+        assert_eq!(
+            StorageKeyBuilder::group_data(1).tier(),
+            StorageTier::Persistent
+        );
+        assert_eq!(
+            StorageKeyBuilder::member_profile(1, address).ttl_policy(),
+            TtlPolicy::GROUP_LIFETIME
+        );
+        assert_eq!(
+            StorageKeyBuilder::group_reminder_sent(1, 0).tier(),
+            StorageTier::Temporary
+        );
+        assert_eq!(
+            StorageKeyBuilder::group_defaults_progress(1, 0).ttl_policy(),
+            TtlPolicy::CYCLE_SCRATCH
+        );
+    }
+
     #[test]
     fn test_key_equality_and_cloning() {
         let key1 = StorageKeyBuilder::group_data(1);
@@ -469,6 +2151,7 @@ mod tests {
         let contrib_key = StorageKeyBuilder::contribution_cycle_total(1, 1);
         let payout_key = StorageKeyBuilder::payout_record(1, 1);
         let counter_key = StorageKeyBuilder::next_group_id();
+        let index_key = StorageKeyBuilder::open_groups_page(0);
 
         // Verify all different categories produce different keys
         let keys = [
@@ -477,6 +2160,7 @@ mod tests {
             &contrib_key,
             &payout_key,
             &counter_key,
+            &index_key,
         ];
 
         for i in 0..keys.len() {
@@ -0,0 +1,110 @@
+//! Contributions paid in another asset.
+//!
+//! A member holding USDC can contribute to a group that settles in XLM, or
+//! the other way round. The protocol admin configures a swap router with a
+//! Soroswap-style interface; the contract asks it to buy exactly the
+//! cycle's contribution in the contribution token, spending no more of the
+//! member's asset than they allow. The bought tokens land with the member
+//! and are contributed as usual, and the swap is recorded next to the
+//! contribution.
+
+use crate::error::StellarSaveError;
+use crate::storage::{set_instance, set_persistent, StorageKeyBuilder};
+use soroban_sdk::{contractclient, contracttype, vec, Address, Env, Vec};
+
+/// Interface a swap router contract must implement.
+#[contractclient(name = "SwapRouterClient")]
+pub trait SwapRouter {
+    /// Swaps at most `amount_in_max` of the first token in `path`, taken
+    /// from `to`, for exactly `amount_out` of the last, sent to `to`.
+    /// Returns the amount at each step of the path, starting with the
+    /// amount spent.
+    fn swap_tokens_for_exact_tokens(
+        env: Env,
+        amount_out: i128,
+        amount_in_max: i128,
+        path: Vec<Address>,
+        to: Address,
+        deadline: u64,
+    ) -> Vec<i128>;
+}
+
+/// A contribution paid in another asset.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContributionSwap {
+    pub member: Address,
+    pub group_id: u64,
+    pub cycle: u32,
+    /// Asset the member paid with
+    pub pay_token: Address,
+    /// Amount of `pay_token` spent
+    pub amount_in: i128,
+    /// Contribution tokens bought, the amount contributed
+    pub amount_out: i128,
+    pub swapped_at: u64,
+}
+
+/// Returns the protocol's swap router, if one is configured.
+pub fn load_router(env: &Env) -> Option<Address> {
+    env.storage()
+        .instance()
+        .get(&StorageKeyBuilder::swap_router())
+}
+
+/// Sets the protocol's swap router, or removes it with `None`.
+pub fn store_router(env: &Env, router: &Option<Address>) {
+    let key = StorageKeyBuilder::swap_router();
+    match router {
+        Some(router) => set_instance(env, &key, router),
+        None => env.storage().instance().remove(&key),
+    }
+}
+
+/// Returns the swap a member paid a cycle's contribution with, if any.
+pub fn load(env: &Env, group_id: u64, cycle: u32, member: Address) -> Option<ContributionSwap> {
+    env.storage()
+        .persistent()
+        .get(&StorageKeyBuilder::contribution_swap(
+            group_id, cycle, member,
+        ))
+}
+
+/// Records the swap a contribution was paid with.
+pub(crate) fn store(env: &Env, swap: &ContributionSwap) {
+    let key = StorageKeyBuilder::contribution_swap(swap.group_id, swap.cycle, swap.member.clone());
+    set_persistent(env, &key, swap);
+}
+
+/// Buys exactly `amount_out` of `token` for `member` with at most
+/// `max_amount_in` of `pay_token`, returning the amount spent.
+///
+/// # Errors
+/// - `SwapFailed` - The router failed, or reports spending more than
+///   `max_amount_in`
+pub(crate) fn buy_exact(
+    env: &Env,
+    router: &Address,
+    member: &Address,
+    pay_token: &Address,
+    token: &Address,
+    amount_out: i128,
+    max_amount_in: i128,
+) -> Result<i128, StellarSaveError> {
+    let path = vec![env, pay_token.clone(), token.clone()];
+    let amounts = SwapRouterClient::new(env, router).try_swap_tokens_for_exact_tokens(
+        &amount_out,
+        &max_amount_in,
+        &path,
+        member,
+        &env.ledger().timestamp(),
+    );
+    let spent = match amounts {
+        Ok(Ok(amounts)) => amounts.get(0),
+        _ => None,
+    };
+    match spent {
+        Some(spent) if (0..=max_amount_in).contains(&spent) => Ok(spent),
+        _ => Err(StellarSaveError::SwapFailed),
+    }
+}
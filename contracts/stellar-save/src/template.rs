@@ -0,0 +1,164 @@
+//! Group templates and restarts.
+//!
+//! A template captures a group's configuration — its terms, payout rules and
+//! listing metadata — so new groups can be created from it without setting
+//! each option again. Restarting a completed group creates a fresh group from
+//! the same configuration, links it to its predecessor, and can hold a seat
+//! for each of the previous members until they join or decline.
+
+use crate::error::StellarSaveError;
+use crate::group::{Group, GroupMetadata, PayoutPolicy};
+use crate::payout_order::{self, PayoutOrder};
+use crate::storage::{set_persistent, GroupStore, StorageKeyBuilder};
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+/// Configuration new groups can be created from.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupTemplate {
+    /// Address that saved the template
+    pub owner: Address,
+    /// Amount each member contributes per cycle, in stroops
+    pub contribution_amount: i128,
+    /// Length of one cycle in seconds
+    pub cycle_duration: u64,
+    /// Seats in the rotation
+    pub max_members: u32,
+    /// Seconds after each deadline during which contributions are still accepted
+    pub grace_period_secs: Option<u64>,
+    /// Share of each contribution paid into the insurance reserve, in basis points
+    pub insurance_bps: u32,
+    /// Whether a cycle some members failed to pay into can still pay out
+    pub payout_policy: PayoutPolicy,
+    /// Members paid out each cycle
+    pub recipients_per_cycle: u32,
+    /// How the rotation orders its payouts
+    pub payout_order: PayoutOrder,
+    /// Listing metadata; every field is empty if the group had none
+    pub metadata: GroupMetadata,
+}
+
+/// Captures the configuration of `group`, saved by `owner`.
+pub fn from_group(env: &Env, group: &Group, owner: Address) -> GroupTemplate {
+    GroupTemplate {
+        owner,
+        contribution_amount: group.contribution_amount,
+        cycle_duration: group.cycle_duration,
+        max_members: group.max_members,
+        grace_period_secs: group.grace_period_secs,
+        insurance_bps: group.insurance_bps,
+        payout_policy: group.payout_policy,
+        recipients_per_cycle: group.recipients_per_cycle,
+        payout_order: payout_order::load_order(env, group.id),
+        metadata: env
+            .storage()
+            .persistent()
+            .get(&StorageKeyBuilder::group_metadata(group.id))
+            .unwrap_or(GroupMetadata::unnamed(env)),
+    }
+}
+
+/// Stores a template under the next template ID and returns the ID.
+pub fn store(env: &Env, template: &GroupTemplate) -> Result<u64, StellarSaveError> {
+    let counter_key = StorageKeyBuilder::next_template_id();
+    let last_id: u64 = env.storage().persistent().get(&counter_key).unwrap_or(0);
+    let template_id = last_id.checked_add(1).ok_or(StellarSaveError::Overflow)?;
+    set_persistent(env, &counter_key, &template_id);
+    set_persistent(env, &StorageKeyBuilder::template(template_id), template);
+    Ok(template_id)
+}
+
+/// Returns a template, or `None` if no template has the ID.
+pub fn load(env: &Env, template_id: u64) -> Option<GroupTemplate> {
+    env.storage()
+        .persistent()
+        .get(&StorageKeyBuilder::template(template_id))
+}
+
+/// Applies the parts of a template that `open_group` does not set to the
+/// freshly created group `group_id`.
+pub fn apply(env: &Env, group_id: u64, template: &GroupTemplate) -> Result<(), StellarSaveError> {
+    let mut group: Group =
+        GroupStore::load(env, group_id).ok_or(StellarSaveError::GroupNotFound)?;
+    group.grace_period_secs = template.grace_period_secs;
+    group.insurance_bps = template.insurance_bps;
+    group.payout_policy = template.payout_policy;
+    group.recipients_per_cycle = template.recipients_per_cycle;
+    GroupStore::save(env, &group);
+
+    if template.payout_order != PayoutOrder::JoinOrder {
+        set_persistent(
+            env,
+            &StorageKeyBuilder::group_payout_order(group_id),
+            &template.payout_order,
+        );
+    }
+    set_persistent(
+        env,
+        &StorageKeyBuilder::group_metadata(group_id),
+        &template.metadata,
+    );
+    Ok(())
+}
+
+/// Members whose seats in a restarted group are still held for them.
+pub fn load_invitees(env: &Env, group_id: u64) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&StorageKeyBuilder::group_invitees(group_id))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Releases the seat held for `member`.
+///
+/// # Returns
+/// Whether the member was invited.
+pub fn remove_invitee(env: &Env, group_id: u64, member: &Address) -> bool {
+    let mut invitees = load_invitees(env, group_id);
+    let Some(index) = invitees.first_index_of(member) else {
+        return false;
+    };
+    invitees.remove(index);
+    let key = StorageKeyBuilder::group_invitees(group_id);
+    if invitees.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        set_persistent(env, &key, &invitees);
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+
+    #[test]
+    fn test_store_and_remove_invitees() {
+        let env = Env::default();
+        let contract_id = crate::testutils::register(&env);
+        let (alice, bob) = (Address::generate(&env), Address::generate(&env));
+
+        env.as_contract(&contract_id, || {
+            let group = Group::new(1, alice.clone(), 100, 3600, 4, 2, 0);
+            let template = from_group(&env, &group, alice.clone());
+            assert_eq!(template.payout_order, PayoutOrder::JoinOrder);
+            assert_eq!(template.metadata, GroupMetadata::unnamed(&env));
+            assert_eq!(store(&env, &template), Ok(1));
+            assert_eq!(store(&env, &template), Ok(2));
+            assert_eq!(load(&env, 2), Some(template));
+            assert_eq!(load(&env, 3), None);
+
+            let invitees = Vec::from_array(&env, [alice.clone(), bob.clone()]);
+            set_persistent(&env, &StorageKeyBuilder::group_invitees(1), &invitees);
+            assert!(remove_invitee(&env, 1, &alice));
+            assert!(!remove_invitee(&env, 1, &alice));
+            assert_eq!(load_invitees(&env, 1), Vec::from_array(&env, [bob.clone()]));
+            assert!(remove_invitee(&env, 1, &bob));
+            assert!(!env
+                .storage()
+                .persistent()
+                .has(&StorageKeyBuilder::group_invitees(1)));
+        });
+    }
+}
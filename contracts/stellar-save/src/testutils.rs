@@ -0,0 +1,614 @@
+//! Test helpers for setting up groups and driving them through time.
+//!
+//! Available to the crate's own tests and, with the `testutils` feature, to
+//! downstream contracts that want to exercise Stellar-Save in their test suites.
+//! The helpers write contract storage directly, so they must be given the
+//! address the contract was registered under. [`TestGroupBuilder`] builds a
+//! populated group in one expression:
+//!
+//! ```ignore
+//! let group = TestGroupBuilder::new(&env)
+//!     .members(5)
+//!     .contribution(units::xlm(10))
+//!     .activated()
+//!     .contributed_all()
+//!     .build();
+//! ```
+
+use crate::error::StellarSaveError;
+use crate::events::{ContractEvent, ContributionMade, PayoutExecuted};
+use crate::group::{Group, GroupStatus};
+use crate::payout_executor;
+use crate::payout_order;
+use crate::pool::{InsuranceSettlement, PoolCalculator};
+use crate::schedule;
+use crate::storage::{set_persistent, GroupStore, StorageKeyBuilder};
+use crate::units;
+use crate::yield_strategy;
+use crate::{StellarSaveContract, StellarSaveContractClient};
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, Env, String, TryFromVal, Val, Vec};
+
+/// Registers the contract and initializes it with a fresh protocol admin, a
+/// fresh Stellar asset contract as its token, no fee and no transfer caps.
+///
+/// Returns the contract's address. Only the `initialize` call is authorized;
+/// later calls need the test's own auth setup.
+pub fn register(env: &Env) -> Address {
+    let contract_id = env.register(StellarSaveContract, ());
+    let token = env.register_stellar_asset_contract_v2(Address::generate(env));
+    StellarSaveContractClient::new(env, &contract_id)
+        .mock_all_auths()
+        .initialize(&Address::generate(env), &token.address(), &0, &None);
+    contract_id
+}
+
+/// Returns a client for the contract's contribution token.
+pub fn token<'a>(env: &'a Env, contract_id: &Address) -> TokenClient<'a> {
+    let token = StellarSaveContractClient::new(env, contract_id)
+        .get_contribution_token()
+        .expect("contract not initialized");
+    TokenClient::new(env, &token)
+}
+
+/// Mints `amount` of the contract's contribution token to `to`. The token must
+/// be a Stellar asset contract; the mint is authorized on its admin's behalf.
+pub fn mint(env: &Env, contract_id: &Address, to: &Address, amount: i128) {
+    let token = token(env, contract_id);
+    StellarAssetClient::new(env, &token.address)
+        .mock_all_auths()
+        .mint(to, &amount);
+}
+
+/// Mints `amount` of the contribution token to each of `accounts`.
+pub fn fund(env: &Env, contract_id: &Address, accounts: &Vec<Address>, amount: i128) {
+    for account in accounts.iter() {
+        mint(env, contract_id, &account, amount);
+    }
+}
+
+/// Moves the ledger clock forward by `seconds`.
+pub fn advance_time(env: &Env, seconds: u64) {
+    env.ledger().with_mut(|li| {
+        li.timestamp = li.timestamp.saturating_add(seconds);
+    });
+}
+
+/// Moves the ledger sequence number forward by `ledgers`.
+pub fn advance_sequence(env: &Env, ledgers: u32) {
+    env.ledger().with_mut(|li| {
+        li.sequence_number = li.sequence_number.saturating_add(ledgers);
+    });
+}
+
+/// Sets the ledger clock to an absolute timestamp.
+pub fn set_time(env: &Env, timestamp: u64) {
+    env.ledger().with_mut(|li| {
+        li.timestamp = timestamp;
+    });
+}
+
+/// Loads a group from contract storage.
+pub fn load_group(env: &Env, contract_id: &Address, group_id: u64) -> Group {
+    env.as_contract(contract_id, || {
+        GroupStore::load(env, group_id).expect("group not found")
+    })
+}
+
+/// Starts a group at the current ledger time, marking it Active.
+pub fn start_group(env: &Env, contract_id: &Address, group_id: u64) {
+    let timestamp = env.ledger().timestamp();
+    env.as_contract(contract_id, || {
+        let mut group: Group = GroupStore::load(env, group_id).expect("group not found");
+        group.started = true;
+        group.started_at = timestamp;
+        group.status = GroupStatus::Active;
+        group.is_active = true;
+        GroupStore::save(env, &group);
+
+        let status_key = StorageKeyBuilder::group_status(group_id);
+        set_persistent(env, &status_key, &GroupStatus::Active);
+    });
+}
+
+/// Moves the ledger clock to `offset` seconds past the deadline of the group's
+/// current cycle. Use an offset of 0 to land exactly on the deadline.
+pub fn advance_past_deadline(env: &Env, contract_id: &Address, group_id: u64, offset: u64) {
+    let group = load_group(env, contract_id, group_id);
+    let deadline = schedule::contribution_deadline(&group, group.current_cycle).unwrap();
+    set_time(env, deadline + offset);
+}
+
+/// Records a member's contribution of the group's fixed amount for the
+/// current cycle.
+pub fn contribute(
+    env: &Env,
+    contract_id: &Address,
+    group_id: u64,
+    member: &Address,
+) -> Result<(), StellarSaveError> {
+    let timestamp = env.ledger().timestamp();
+    env.as_contract(contract_id, || {
+        let group = StellarSaveContract::get_group(env.clone(), group_id)?;
+        let profile_key = StorageKeyBuilder::member_profile(group_id, member.clone());
+        if !env.storage().persistent().has(&profile_key) {
+            return Err(StellarSaveError::NotMember);
+        }
+
+        StellarSaveContract::record_contribution(
+            env,
+            group_id,
+            group.current_cycle,
+            member.clone(),
+            group.contribution_amount,
+            timestamp,
+        )
+    })
+}
+
+/// Records a contribution from every member for the group's current cycle.
+pub fn contribute_all(env: &Env, contract_id: &Address, group_id: u64) {
+    let members: Vec<Address> = env.as_contract(contract_id, || {
+        env.storage()
+            .persistent()
+            .get(&StorageKeyBuilder::group_members(group_id))
+            .unwrap_or(Vec::new(env))
+    });
+
+    for member in members.iter() {
+        contribute(env, contract_id, group_id, &member).expect("contribution failed");
+    }
+}
+
+/// Pays the current cycle's pool, net of the protocol fee and any insurance
+/// premium, to the member holding the matching payout position and advances the
+/// group to the next cycle. A pool deposited into a yield strategy is withdrawn
+/// first.
+pub fn payout_and_advance(env: &Env, contract_id: &Address, group_id: u64) {
+    let timestamp = env.ledger().timestamp();
+    env.as_contract(contract_id, || {
+        let mut group: Group = GroupStore::load(env, group_id).expect("group not found");
+        let recipient = payout_executor::identify_recipient(
+            env,
+            group_id,
+            group.current_cycle,
+            group.member_count,
+        )
+        .expect("no recipient for cycle");
+        payout_order::seat_recipient(env, group_id, group.current_cycle, &recipient)
+            .expect("recipient is not a member");
+
+        let pool = group.contribution_amount * group.member_count as i128;
+        let premium = units::bps_of(pool, group.insurance_bps).expect("invalid premium");
+        let settlement = InsuranceSettlement {
+            premium,
+            coverage: 0,
+            payout_base: pool - premium,
+            shortfall: 0,
+        };
+        let net_payout = PoolCalculator::calculate_payout_amount(
+            settlement.payout_base,
+            PoolCalculator::get_fee_bps(env),
+        )
+        .expect("invalid payout amount");
+        let recipient_yield =
+            yield_strategy::withdraw_pool(env, group_id, group.current_cycle, timestamp)
+                .expect("yield withdrawal failed");
+        StellarSaveContract::record_payout(
+            env,
+            group_id,
+            group.current_cycle,
+            recipient,
+            net_payout + recipient_yield,
+            timestamp,
+        )
+        .expect("payout failed");
+        payout_executor::collect_protocol_fee(
+            env,
+            group_id,
+            group.current_cycle,
+            settlement.payout_base - net_payout,
+            timestamp,
+        )
+        .expect("fee collection failed");
+        payout_executor::apply_insurance_settlement(
+            env,
+            group_id,
+            group.current_cycle,
+            &settlement,
+            timestamp,
+        )
+        .expect("insurance settlement failed");
+
+        payout_executor::advance_cycle_or_complete(env, &mut group)
+            .expect("cycle advancement failed");
+    });
+}
+
+/// Fast-forwards a started group through `cycles` full cycles.
+///
+/// Each cycle every member contributes, the clock moves to the cycle deadline,
+/// and the pool is paid to the scheduled recipient.
+pub fn fast_forward_cycles(env: &Env, contract_id: &Address, group_id: u64, cycles: u32) {
+    for _ in 0..cycles {
+        contribute_all(env, contract_id, group_id);
+        advance_past_deadline(env, contract_id, group_id, 0);
+        payout_and_advance(env, contract_id, group_id);
+    }
+}
+
+/// Payloads of every `T` event published by the most recent invocation, in
+/// the order they were published. Events are matched on their first two
+/// topics, the name and the payload version, so a payload published under
+/// another version is not decoded as `T`.
+pub fn events<T>(env: &Env) -> Vec<T>
+where
+    T: ContractEvent + TryFromVal<Env, Val>,
+{
+    let name = String::from_str(env, T::NAME);
+    let mut found = Vec::new(env);
+    for (_, topics, data) in env.events().all().iter() {
+        let matches = topics.len() >= 2
+            && topics
+                .get(0)
+                .and_then(|topic| String::try_from_val(env, &topic).ok())
+                == Some(name.clone())
+            && topics
+                .get(1)
+                .and_then(|topic| u32::try_from_val(env, &topic).ok())
+                == Some(T::VERSION);
+        if matches {
+            found.push_back(T::try_from_val(env, &data).expect("malformed event payload"));
+        }
+    }
+    found
+}
+
+/// Payload of the last `T` event published by the most recent invocation.
+pub fn last_event<T>(env: &Env) -> Option<T>
+where
+    T: ContractEvent + TryFromVal<Env, Val>,
+{
+    events::<T>(env).last()
+}
+
+/// Like [`last_event`], but panics naming the event when there is none.
+pub fn expect_event<T>(env: &Env) -> T
+where
+    T: ContractEvent + TryFromVal<Env, Val>,
+{
+    last_event(env).unwrap_or_else(|| panic!("no {} event published", T::NAME))
+}
+
+/// Asserts the most recent invocation paid `amount` to `recipient` from the
+/// group's pool, and returns the event.
+pub fn assert_payout_event(
+    env: &Env,
+    group_id: u64,
+    recipient: &Address,
+    amount: i128,
+) -> PayoutExecuted {
+    let event = expect_event::<PayoutExecuted>(env);
+    assert_eq!(event.group_id, group_id, "payout from another group");
+    assert_eq!(event.recipient, *recipient, "payout to another recipient");
+    assert_eq!(event.amount, amount, "payout of another amount");
+    event
+}
+
+/// Asserts the most recent invocation recorded a contribution of `amount`
+/// from `contributor` to the group, and returns the event.
+pub fn assert_contribution_event(
+    env: &Env,
+    group_id: u64,
+    contributor: &Address,
+    amount: i128,
+) -> ContributionMade {
+    let event = expect_event::<ContributionMade>(env);
+    assert_eq!(event.group_id, group_id, "contribution to another group");
+    assert_eq!(
+        event.contributor, *contributor,
+        "contribution from another member"
+    );
+    assert_eq!(event.amount, amount, "contribution of another amount");
+    event
+}
+
+/// A group set up by [`TestGroupBuilder`].
+pub struct TestGroup {
+    /// Address the contract is registered under
+    pub contract_id: Address,
+    /// ID of the group
+    pub group_id: u64,
+    /// Account that created the group
+    pub creator: Address,
+    /// Members in the order they joined
+    pub members: Vec<Address>,
+}
+
+impl TestGroup {
+    /// Returns a client for the contract holding the group.
+    pub fn client<'a>(&self, env: &'a Env) -> StellarSaveContractClient<'a> {
+        StellarSaveContractClient::new(env, &self.contract_id)
+    }
+
+    /// Loads the group from contract storage.
+    pub fn load(&self, env: &Env) -> Group {
+        load_group(env, &self.contract_id, self.group_id)
+    }
+}
+
+/// Builds a group with fully populated storage state.
+///
+/// Defaults to three members contributing 10 XLM a week on a freshly
+/// registered contract. The group is created and joined through the contract's
+/// entry points, so every index and counter a real group has is in place.
+pub struct TestGroupBuilder<'a> {
+    env: &'a Env,
+    contract_id: Option<Address>,
+    members: u32,
+    contribution: i128,
+    cycle_duration: u64,
+    funding: i128,
+    activated: bool,
+    contributed_all: bool,
+}
+
+impl<'a> TestGroupBuilder<'a> {
+    pub fn new(env: &'a Env) -> Self {
+        Self {
+            env,
+            contract_id: None,
+            members: 3,
+            contribution: units::xlm(10),
+            cycle_duration: units::SECONDS_PER_WEEK,
+            funding: 0,
+            activated: false,
+            contributed_all: false,
+        }
+    }
+
+    /// Creates the group on an already registered contract instead of a new one.
+    pub fn contract(mut self, contract_id: &Address) -> Self {
+        self.contract_id = Some(contract_id.clone());
+        self
+    }
+
+    /// Number of members, which is also the group's maximum.
+    pub fn members(mut self, count: u32) -> Self {
+        self.members = count;
+        self
+    }
+
+    /// Contribution per member per cycle, in stroops.
+    pub fn contribution(mut self, amount: i128) -> Self {
+        self.contribution = amount;
+        self
+    }
+
+    /// Length of a cycle in seconds.
+    pub fn cycle_duration(mut self, seconds: u64) -> Self {
+        self.cycle_duration = seconds;
+        self
+    }
+
+    /// Mints `amount` of the contribution token to every member.
+    pub fn funded(mut self, amount: i128) -> Self {
+        self.funding = amount;
+        self
+    }
+
+    /// Starts the group at the current ledger time.
+    pub fn activated(mut self) -> Self {
+        self.activated = true;
+        self
+    }
+
+    /// Records every member's contribution for the first cycle and mints the
+    /// pool into the contract, as if each had paid. Implies [`Self::activated`].
+    pub fn contributed_all(mut self) -> Self {
+        self.activated = true;
+        self.contributed_all = true;
+        self
+    }
+
+    pub fn build(self) -> TestGroup {
+        let env = self.env;
+        let contract_id = self.contract_id.unwrap_or_else(|| register(env));
+        let client = StellarSaveContractClient::new(env, &contract_id);
+
+        let creator = Address::generate(env);
+        let group_id = client.mock_all_auths().create_group(
+            &creator,
+            &self.contribution,
+            &self.cycle_duration,
+            &self.members,
+        );
+        let mut members = Vec::new(env);
+        for _ in 0..self.members {
+            let member = Address::generate(env);
+            client
+                .mock_all_auths()
+                .join_group(&group_id, &member, &None);
+            members.push_back(member);
+        }
+
+        if self.funding > 0 {
+            fund(env, &contract_id, &members, self.funding);
+        }
+        if self.activated {
+            start_group(env, &contract_id, group_id);
+        }
+        if self.contributed_all {
+            contribute_all(env, &contract_id, group_id);
+            mint(
+                env,
+                &contract_id,
+                &contract_id,
+                self.contribution * self.members as i128,
+            );
+        }
+
+        TestGroup {
+            contract_id,
+            group_id,
+            creator,
+            members,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::SECONDS_PER_HOUR;
+
+    fn setup(env: &Env, size: u32) -> (Address, u64) {
+        env.mock_all_auths();
+        let contract_id = register(env);
+        let client = StellarSaveContractClient::new(env, &contract_id);
+
+        let creator = Address::generate(env);
+        let group_id = client.create_group(&creator, &100, &SECONDS_PER_HOUR, &size);
+        for _ in 0..size {
+            client.join_group(&group_id, &Address::generate(env), &None);
+        }
+
+        (contract_id, group_id)
+    }
+
+    #[test]
+    fn test_advance_time_and_sequence() {
+        let env = Env::default();
+        set_time(&env, 1_000);
+        advance_time(&env, 500);
+        assert_eq!(env.ledger().timestamp(), 1_500);
+
+        let sequence = env.ledger().sequence();
+        advance_sequence(&env, 10);
+        assert_eq!(env.ledger().sequence(), sequence + 10);
+    }
+
+    #[test]
+    fn test_advance_past_deadline() {
+        let env = Env::default();
+        set_time(&env, 10_000);
+        let (contract_id, group_id) = setup(&env, 3);
+        start_group(&env, &contract_id, group_id);
+
+        advance_past_deadline(&env, &contract_id, group_id, 1);
+        assert_eq!(env.ledger().timestamp(), 10_000 + SECONDS_PER_HOUR + 1);
+    }
+
+    #[test]
+    fn test_fast_forward_cycles() {
+        let env = Env::default();
+        set_time(&env, 10_000);
+        let (contract_id, group_id) = setup(&env, 4);
+        let client = StellarSaveContractClient::new(&env, &contract_id);
+        start_group(&env, &contract_id, group_id);
+
+        fast_forward_cycles(&env, &contract_id, group_id, 2);
+
+        let group = load_group(&env, &contract_id, group_id);
+        assert_eq!(group.current_cycle, 2);
+        assert_eq!(env.ledger().timestamp(), 10_000 + 2 * SECONDS_PER_HOUR);
+        assert_eq!(client.get_total_paid_out(&group_id), 800);
+        assert!(client.is_cycle_complete(&group_id, &1));
+        assert!(!client.is_cycle_complete(&group_id, &2));
+    }
+
+    #[test]
+    fn test_fast_forward_to_completion() {
+        let env = Env::default();
+        let (contract_id, group_id) = setup(&env, 3);
+        let client = StellarSaveContractClient::new(&env, &contract_id);
+        start_group(&env, &contract_id, group_id);
+
+        fast_forward_cycles(&env, &contract_id, group_id, 3);
+
+        assert!(client.is_complete(&group_id));
+        assert_eq!(client.get_payout_history(&group_id, &0, &10).len(), 3);
+    }
+
+    #[test]
+    fn test_group_builder_defaults() {
+        let env = Env::default();
+        let group = TestGroupBuilder::new(&env).build();
+        let client = group.client(&env);
+
+        let loaded = group.load(&env);
+        assert_eq!(loaded.member_count, 3);
+        assert_eq!(loaded.contribution_amount, units::xlm(10));
+        assert_eq!(loaded.cycle_duration, units::SECONDS_PER_WEEK);
+        assert!(!loaded.started);
+        assert_eq!(group.members.len(), 3);
+        assert_eq!(client.get_member_count(&group.group_id), 3);
+    }
+
+    #[test]
+    fn test_group_builder_contributed_all() {
+        let env = Env::default();
+        let group = TestGroupBuilder::new(&env)
+            .members(5)
+            .contribution(units::xlm(10))
+            .funded(units::xlm(50))
+            .contributed_all()
+            .build();
+        let client = group.client(&env);
+
+        assert!(group.load(&env).started);
+        assert!(client.is_cycle_complete(&group.group_id, &0));
+        let token = token(&env, &group.contract_id);
+        assert_eq!(token.balance(&group.contract_id), units::xlm(50));
+        assert_eq!(
+            token.balance(&group.members.get(4).unwrap()),
+            units::xlm(50)
+        );
+    }
+
+    #[test]
+    fn test_group_builder_shares_contract() {
+        let env = Env::default();
+        let first = TestGroupBuilder::new(&env).build();
+        let second = TestGroupBuilder::new(&env)
+            .contract(&first.contract_id)
+            .members(2)
+            .build();
+
+        assert_eq!(second.contract_id, first.contract_id);
+        assert_ne!(second.group_id, first.group_id);
+    }
+
+    #[test]
+    fn test_event_assertions() {
+        let env = Env::default();
+        let group = TestGroupBuilder::new(&env)
+            .contribution(100)
+            .activated()
+            .build();
+        let member = group.members.get(1).unwrap();
+
+        contribute(&env, &group.contract_id, group.group_id, &member).unwrap();
+        let event = assert_contribution_event(&env, group.group_id, &member, 100);
+        assert_eq!(event.cycle, 0);
+        assert_eq!(events::<ContributionMade>(&env).len(), 1);
+        assert!(last_event::<PayoutExecuted>(&env).is_none());
+
+        let group = TestGroupBuilder::new(&env)
+            .contribution(100)
+            .contributed_all()
+            .build();
+        let client = group.client(&env);
+        client.execute_payouts(&Vec::from_array(&env, [group.group_id]));
+        let payout = assert_payout_event(&env, group.group_id, &group.members.get(0).unwrap(), 300);
+        assert_eq!(payout.cycle, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "no payout_executed event published")]
+    fn test_expect_event_names_missing_event() {
+        let env = Env::default();
+        expect_event::<PayoutExecuted>(&env);
+    }
+}
@@ -0,0 +1,130 @@
+//! Named time and amount units.
+//!
+//! Ledger timestamps are in seconds and token amounts are in stroops, so raw
+//! literals like `604800` or `10_000_000` are easy to misread. Use these
+//! constants and helpers instead of spelling the values out.
+
+/// Seconds in one minute.
+pub const SECONDS_PER_MINUTE: u64 = 60;
+
+/// Seconds in one hour.
+pub const SECONDS_PER_HOUR: u64 = 60 * SECONDS_PER_MINUTE;
+
+/// Seconds in one day.
+pub const SECONDS_PER_DAY: u64 = 24 * SECONDS_PER_HOUR;
+
+/// Seconds in one week.
+pub const SECONDS_PER_WEEK: u64 = 7 * SECONDS_PER_DAY;
+
+/// Ledgers closed per day, assuming the network's 5 second close time.
+pub const LEDGERS_PER_DAY: u32 = (SECONDS_PER_DAY / 5) as u32;
+
+/// Stroops in one XLM (the smallest unit of a Stellar asset is 10^-7).
+pub const STROOPS_PER_XLM: i128 = 10_000_000;
+
+/// Denominator for basis-point calculations (100% = 10_000 bps).
+pub const BPS_DENOMINATOR: u32 = 10_000;
+
+/// Converts a number of hours to seconds.
+pub const fn hours(count: u64) -> u64 {
+    count * SECONDS_PER_HOUR
+}
+
+/// Converts a number of days to seconds.
+pub const fn days(count: u64) -> u64 {
+    count * SECONDS_PER_DAY
+}
+
+/// Converts a number of weeks to seconds.
+pub const fn weeks(count: u64) -> u64 {
+    count * SECONDS_PER_WEEK
+}
+
+/// Converts a whole XLM amount to stroops.
+pub const fn xlm(amount: i128) -> i128 {
+    amount * STROOPS_PER_XLM
+}
+
+/// Converts stroops to whole XLM, truncating any fractional part.
+pub const fn stroops_to_xlm(stroops: i128) -> i128 {
+    stroops / STROOPS_PER_XLM
+}
+
+/// Decimals of a Stellar asset, whose smallest unit is one stroop.
+pub const STELLAR_DECIMALS: u32 = 7;
+
+/// Returns the smallest amount step of a token with `decimals` decimals that
+/// is not dust: one stroop's worth (10^-7 of a whole token), or 1 for tokens
+/// with 7 or fewer decimals.
+///
+/// Returns `None` if the step does not fit in an i128.
+pub fn dust_step(decimals: u32) -> Option<i128> {
+    10i128.checked_pow(decimals.saturating_sub(STELLAR_DECIMALS))
+}
+
+/// Rescales an amount of a token with `decimals` decimals to stroops, the
+/// scale of a Stellar asset, so amounts in different tokens display alike.
+///
+/// Digits below one stroop are dropped, and amounts too large for the scale
+/// saturate.
+pub fn normalize(amount: i128, decimals: u32) -> i128 {
+    if decimals >= STELLAR_DECIMALS {
+        dust_step(decimals).map_or(0, |step| amount / step)
+    } else {
+        amount.saturating_mul(10i128.pow(STELLAR_DECIMALS - decimals))
+    }
+}
+
+/// Returns `bps` basis points of `amount`, rounded down.
+///
+/// Returns `None` if the intermediate product overflows.
+pub fn bps_of(amount: i128, bps: u32) -> Option<i128> {
+    amount
+        .checked_mul(bps as i128)
+        .map(|scaled| scaled / BPS_DENOMINATOR as i128)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_time_units() {
+        assert_eq!(SECONDS_PER_HOUR, 3_600);
+        assert_eq!(SECONDS_PER_DAY, 86_400);
+        assert_eq!(SECONDS_PER_WEEK, 604_800);
+        assert_eq!(hours(2), 7_200);
+        assert_eq!(days(30), 2_592_000);
+        assert_eq!(weeks(2), 1_209_600);
+        assert_eq!(LEDGERS_PER_DAY, 17_280);
+    }
+
+    #[test]
+    fn test_amount_units() {
+        assert_eq!(xlm(1), 10_000_000);
+        assert_eq!(xlm(100), 1_000_000_000);
+        assert_eq!(stroops_to_xlm(xlm(5)), 5);
+        assert_eq!(stroops_to_xlm(xlm(5) + 9_999_999), 5);
+    }
+
+    #[test]
+    fn test_bps_of() {
+        assert_eq!(bps_of(xlm(100), 100), Some(xlm(1)));
+        assert_eq!(bps_of(xlm(1), BPS_DENOMINATOR), Some(xlm(1)));
+        assert_eq!(bps_of(999, 1), Some(0));
+        assert_eq!(bps_of(i128::MAX, 2), None);
+    }
+
+    #[test]
+    fn test_token_decimals() {
+        assert_eq!(dust_step(STELLAR_DECIMALS), Some(1));
+        assert_eq!(dust_step(2), Some(1));
+        assert_eq!(dust_step(18), Some(100_000_000_000));
+        assert_eq!(dust_step(50), None);
+
+        assert_eq!(normalize(xlm(3), STELLAR_DECIMALS), xlm(3));
+        assert_eq!(normalize(250, 2), xlm(2) + xlm(1) / 2);
+        assert_eq!(normalize(3 * 10i128.pow(18) + 99, 18), xlm(3));
+        assert_eq!(normalize(i128::MAX, 0), i128::MAX);
+    }
+}
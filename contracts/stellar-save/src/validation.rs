@@ -0,0 +1,167 @@
+//! Validation helpers for user-supplied strings.
+//!
+//! Soroban `String` lengths are measured in bytes, so every limit in this
+//! module is a UTF-8 byte budget rather than a character count. A name written
+//! in a non-Latin script uses more bytes per character than ASCII, so limits
+//! are sized generously enough that such names fit without truncation, while
+//! still bounding the storage a single entry can consume.
+//!
+//! All checks reject invalid UTF-8 so that stored text always renders
+//! correctly in clients.
+
+use crate::error::StellarSaveError;
+use soroban_sdk::String;
+
+/// Maximum size of a group name in bytes (32 characters of 4-byte script).
+pub const MAX_GROUP_NAME_BYTES: u32 = 128;
+
+/// Maximum size of a group announcement in bytes.
+pub const MAX_ANNOUNCEMENT_BYTES: u32 = 512;
+
+/// Maximum size of a member nickname in bytes (16 characters of 4-byte script).
+pub const MAX_NICKNAME_BYTES: u32 = 64;
+
+/// Maximum size of a group description in bytes.
+pub const MAX_DESCRIPTION_BYTES: u32 = 280;
+
+/// Largest byte budget of any validated string; sizes the decoding buffer.
+pub const MAX_STRING_BYTES: u32 = MAX_ANNOUNCEMENT_BYTES;
+
+/// Validates a string against a byte budget and checks it is valid UTF-8.
+///
+/// # Arguments
+/// * `value` - The string to validate
+/// * `max_bytes` - Maximum allowed length in bytes (at most `MAX_STRING_BYTES`)
+/// * `allow_empty` - Whether an empty string is acceptable
+///
+/// # Returns
+/// * `Ok(())` - The string is within budget and valid UTF-8
+/// * `Err(StellarSaveError::EmptyString)` - Empty but a value is required
+/// * `Err(StellarSaveError::StringTooLong)` - Longer than `max_bytes`
+/// * `Err(StellarSaveError::InvalidUtf8)` - Contains invalid UTF-8 sequences
+pub fn validate_string(
+    value: &String,
+    max_bytes: u32,
+    allow_empty: bool,
+) -> Result<(), StellarSaveError> {
+    let len = value.len();
+
+    if len == 0 {
+        return if allow_empty {
+            Ok(())
+        } else {
+            Err(StellarSaveError::EmptyString)
+        };
+    }
+
+    if len > max_bytes || len > MAX_STRING_BYTES {
+        return Err(StellarSaveError::StringTooLong);
+    }
+
+    let mut buffer = [0u8; MAX_STRING_BYTES as usize];
+    let bytes = &mut buffer[..len as usize];
+    value.copy_into_slice(bytes);
+
+    core::str::from_utf8(bytes).map_err(|_| StellarSaveError::InvalidUtf8)?;
+
+    Ok(())
+}
+
+/// Validates a group name: required, at most `MAX_GROUP_NAME_BYTES` bytes.
+pub fn validate_group_name(name: &String) -> Result<(), StellarSaveError> {
+    validate_string(name, MAX_GROUP_NAME_BYTES, false)
+}
+
+/// Validates a group announcement: optional, at most `MAX_ANNOUNCEMENT_BYTES` bytes.
+pub fn validate_announcement(announcement: &String) -> Result<(), StellarSaveError> {
+    validate_string(announcement, MAX_ANNOUNCEMENT_BYTES, true)
+}
+
+/// Validates a group description: optional, at most `MAX_DESCRIPTION_BYTES` bytes.
+pub fn validate_description(description: &String) -> Result<(), StellarSaveError> {
+    validate_string(description, MAX_DESCRIPTION_BYTES, true)
+}
+
+/// Validates a member nickname: required, at most `MAX_NICKNAME_BYTES` bytes.
+pub fn validate_nickname(nickname: &String) -> Result<(), StellarSaveError> {
+    validate_string(nickname, MAX_NICKNAME_BYTES, false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::Env;
+
+    #[test]
+    fn test_validate_ascii_name() {
+        let env = Env::default();
+        let name = String::from_str(&env, "Market Women Circle");
+        assert!(validate_group_name(&name).is_ok());
+    }
+
+    #[test]
+    fn test_validate_non_latin_name_within_budget() {
+        let env = Env::default();
+        // 10 CJK characters = 30 bytes, well within the byte budget
+        let name = String::from_str(&env, "互助储蓄会互助储蓄会");
+        assert_eq!(name.len(), 30);
+        assert!(validate_group_name(&name).is_ok());
+
+        // 20 Amharic characters = 60 bytes
+        let nickname = String::from_str(&env, "ሰላምሰላምሰላምሰላምሰላምሰላምሰላ");
+        assert_eq!(nickname.len(), 60);
+        assert!(validate_nickname(&nickname).is_ok());
+    }
+
+    #[test]
+    fn test_byte_limit_not_char_limit() {
+        let env = Env::default();
+        // 22 characters of 3 bytes = 66 bytes, over the 64-byte nickname budget
+        let nickname = String::from_str(&env, "互助储蓄会互助储蓄会互助储蓄会互助储蓄会互助");
+        assert_eq!(
+            validate_nickname(&nickname),
+            Err(StellarSaveError::StringTooLong)
+        );
+    }
+
+    #[test]
+    fn test_exact_byte_limit() {
+        let env = Env::default();
+        let exact = String::from_bytes(&env, &[b'a'; MAX_NICKNAME_BYTES as usize]);
+        let over = String::from_bytes(&env, &[b'a'; MAX_NICKNAME_BYTES as usize + 1]);
+
+        assert!(validate_nickname(&exact).is_ok());
+        assert_eq!(
+            validate_nickname(&over),
+            Err(StellarSaveError::StringTooLong)
+        );
+    }
+
+    #[test]
+    fn test_invalid_utf8_rejected() {
+        let env = Env::default();
+        // Truncated 3-byte sequence
+        let invalid = String::from_bytes(&env, &[b'a', 0xE4, 0xBA]);
+        assert_eq!(
+            validate_group_name(&invalid),
+            Err(StellarSaveError::InvalidUtf8)
+        );
+    }
+
+    #[test]
+    fn test_empty_strings() {
+        let env = Env::default();
+        let empty = String::from_str(&env, "");
+
+        assert_eq!(
+            validate_group_name(&empty),
+            Err(StellarSaveError::EmptyString)
+        );
+        assert_eq!(
+            validate_nickname(&empty),
+            Err(StellarSaveError::EmptyString)
+        );
+        assert!(validate_announcement(&empty).is_ok());
+        assert!(validate_description(&empty).is_ok());
+    }
+}
@@ -0,0 +1,150 @@
+//! Penalty waivers.
+//!
+//! A moderator, or the members by vote, can forgive a member for one cycle:
+//! a late contribution is counted as on time, and a missed one no longer
+//! counts as a default. Forgiving a default also cancels what it cost the
+//! member — the shortfall charged for a partially paid cycle and the debt
+//! accrued for missing a cycle after their payout. Each waiver is recorded
+//! with a reason code and an optional memo hash, and a cycle can only be
+//! waived once per member.
+
+use crate::contribution::ContributionRecord;
+use crate::debt;
+use crate::error::StellarSaveError;
+use crate::group::{Group, PayoutPolicy};
+use crate::schedule::{self, ContributionTiming};
+use crate::storage::{set_persistent, MemberStore, StorageKeyBuilder};
+use crate::{MemberProfile, MemberStatus};
+use soroban_sdk::{contracttype, Address, BytesN, Env};
+
+/// What a waiver forgave.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum WaiverKind {
+    /// A contribution made after the deadline
+    Late,
+    /// A contribution the member missed
+    Default,
+}
+
+/// Why a penalty was waived.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum WaiverReason {
+    /// Medical or family emergency
+    Emergency,
+    /// The member could not pay through no fault of their own, e.g. a
+    /// network or wallet outage
+    TechnicalIssue,
+    /// The penalty was recorded in error
+    Mistake,
+    /// Anything else; the moderator or proposal explains off-chain
+    Other,
+}
+
+/// A recorded waiver for one member and cycle.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PenaltyWaiver {
+    pub kind: WaiverKind,
+    pub reason: WaiverReason,
+    /// SHA-256 of a short explanation kept off-chain
+    pub memo: Option<BytesN<32>>,
+    /// Moderator who waived it, or the contract for a passed proposal
+    pub waived_by: Address,
+    /// Shortfall and debt cancelled, in stroops
+    pub forgiven: i128,
+    pub waived_at: u64,
+}
+
+/// Returns the waiver recorded for a member's cycle, if any.
+pub fn load(env: &Env, group_id: u64, member: Address, cycle: u32) -> Option<PenaltyWaiver> {
+    env.storage()
+        .persistent()
+        .get(&StorageKeyBuilder::member_waiver(group_id, member, cycle))
+}
+
+/// Waives the penalty `member` incurred in `cycle` and records the waiver.
+///
+/// # Errors
+/// * `NotMember` - If the address is not in the group
+/// * `InvalidState` - If the cycle was already waived, has not been reached,
+///   or the member contributed to it on time
+pub fn waive(
+    env: &Env,
+    group: &Group,
+    member: Address,
+    cycle: u32,
+    reason: WaiverReason,
+    memo: Option<BytesN<32>>,
+    waived_by: Address,
+) -> Result<PenaltyWaiver, StellarSaveError> {
+    let waiver_key = StorageKeyBuilder::member_waiver(group.id, member.clone(), cycle);
+    if env.storage().persistent().has(&waiver_key) || cycle > group.current_cycle {
+        return Err(StellarSaveError::InvalidState);
+    }
+    let mut profile: MemberProfile =
+        MemberStore::load(env, group.id, &member).ok_or(StellarSaveError::NotMember)?;
+
+    let contrib_key = StorageKeyBuilder::contribution_individual(group.id, cycle, member.clone());
+    let contribution: Option<ContributionRecord> = env.storage().persistent().get(&contrib_key);
+    let (kind, forgiven) = match contribution {
+        Some(record) => {
+            let timing = schedule::contribution_timing(group, cycle, record.timestamp);
+            if timing == Some(ContributionTiming::OnTime) || profile.late_contributions == 0 {
+                return Err(StellarSaveError::InvalidState);
+            }
+            profile.late_contributions -= 1;
+            profile.on_time_contributions = profile.on_time_contributions.saturating_add(1);
+            (WaiverKind::Late, 0)
+        }
+        // Defaults are only recorded once a cycle pays out
+        None if cycle < group.current_cycle && profile.missed_contributions > 0 => {
+            profile.missed_contributions -= 1;
+            if profile.missed_contributions == 0 && profile.status == MemberStatus::Defaulted {
+                profile.status = if profile.has_received_payout {
+                    MemberStatus::PaidOut
+                } else {
+                    MemberStatus::Active
+                };
+            }
+            let mut forgiven = 0;
+            if matches!(group.payout_policy, PayoutPolicy::BestEffort(_)) {
+                forgiven += forgive_shortfall(env, group, member.clone());
+            }
+            if profile.payout_cycle.is_some_and(|paid| paid < cycle) {
+                forgiven += debt::forgive(env, group.id, member, group.contribution_amount);
+            }
+            (WaiverKind::Default, forgiven)
+        }
+        None => return Err(StellarSaveError::InvalidState),
+    };
+    MemberStore::save(env, &profile);
+
+    let waiver = PenaltyWaiver {
+        kind,
+        reason,
+        memo,
+        waived_by,
+        forgiven,
+        waived_at: env.ledger().timestamp(),
+    };
+    set_persistent(env, &waiver_key, &waiver);
+    Ok(waiver)
+}
+
+/// Cancels up to one contribution of the shortfall charged to `member`.
+fn forgive_shortfall(env: &Env, group: &Group, member: Address) -> i128 {
+    let key = StorageKeyBuilder::member_shortfall(group.id, member);
+    let owed: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+    let forgiven = owed.min(group.contribution_amount);
+    if forgiven <= 0 {
+        return 0;
+    }
+    if owed == forgiven {
+        env.storage().persistent().remove(&key);
+    } else {
+        set_persistent(env, &key, &(owed - forgiven));
+    }
+    forgiven
+}
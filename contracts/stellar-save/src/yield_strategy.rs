@@ -0,0 +1,202 @@
+//! Yield on idle pool funds.
+//!
+//! Between the moment a cycle's pool is complete and its payout, the funds sit
+//! idle in the contract. A group admin can point the group at a strategy
+//! contract from the protocol admin's whitelist: a complete pool is then
+//! deposited into the strategy and withdrawn again, together with whatever it
+//! earned, right before the payout. The yield either tops up that cycle's
+//! payout or is held back and split among the members when the group
+//! completes.
+
+use crate::error::StellarSaveError;
+use crate::events::EventEmitter;
+use crate::storage::{set_persistent, StorageKey, StorageKeyBuilder};
+use soroban_sdk::{contractclient, contracttype, Address, Env, Vec};
+
+/// Interface a yield strategy contract must implement.
+#[contractclient(name = "YieldStrategyClient")]
+pub trait YieldStrategy {
+    /// Accepts `amount` from `from` and holds it on the depositor's behalf.
+    fn deposit(env: Env, from: Address, amount: i128);
+
+    /// Returns the depositor's whole position, principal plus yield, to `to`
+    /// and reports the amount returned.
+    fn withdraw(env: Env, to: Address) -> i128;
+}
+
+/// Who receives the yield earned on a group's pools.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum YieldDistribution {
+    /// Added to the payout of the cycle whose pool earned it.
+    Recipient,
+    /// Held back and split equally among the members at completion.
+    Members,
+}
+
+/// A group's yield strategy.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct YieldConfig {
+    /// Whitelisted strategy contract
+    pub strategy: Address,
+    /// Who receives the earned yield
+    pub distribution: YieldDistribution,
+}
+
+/// A pool currently held by a strategy.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct YieldDeposit {
+    /// Strategy the pool was deposited into
+    pub strategy: Address,
+    /// Cycle whose pool was deposited
+    pub cycle: u32,
+    /// Amount deposited, in stroops
+    pub principal: i128,
+}
+
+/// Returns the strategies group admins may use.
+pub fn allowed_strategies(env: &Env) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&StorageKeyBuilder::yield_strategies())
+        .unwrap_or(Vec::new(env))
+}
+
+/// Whether `strategy` is on the protocol's whitelist.
+pub fn is_allowed(env: &Env, strategy: &Address) -> bool {
+    allowed_strategies(env).contains(strategy)
+}
+
+/// Returns the group's yield strategy, if one is set.
+pub fn load_config(env: &Env, group_id: u64) -> Option<YieldConfig> {
+    env.storage()
+        .persistent()
+        .get(&StorageKeyBuilder::group_yield_config(group_id))
+}
+
+/// Returns the pool the group currently has deposited, if any.
+pub fn load_deposit(env: &Env, group_id: u64) -> Option<YieldDeposit> {
+    env.storage()
+        .persistent()
+        .get(&StorageKeyBuilder::group_yield_deposit(group_id))
+}
+
+/// Deposits a cycle's pool into the group's strategy.
+///
+/// # Errors
+/// - `InvalidState` - The group has no strategy or already has a deposit
+/// - `StrategyNotAllowed` - The strategy has been removed from the whitelist
+pub(crate) fn deposit_pool(
+    env: &Env,
+    group_id: u64,
+    cycle: u32,
+    principal: i128,
+) -> Result<YieldDeposit, StellarSaveError> {
+    let config = load_config(env, group_id).ok_or(StellarSaveError::InvalidState)?;
+    if !is_allowed(env, &config.strategy) {
+        return Err(StellarSaveError::StrategyNotAllowed);
+    }
+    if load_deposit(env, group_id).is_some() {
+        return Err(StellarSaveError::InvalidState);
+    }
+
+    let deposit = YieldDeposit {
+        strategy: config.strategy,
+        cycle,
+        principal,
+    };
+    set_persistent(
+        env,
+        &StorageKeyBuilder::group_yield_deposit(group_id),
+        &deposit,
+    );
+
+    YieldStrategyClient::new(env, &deposit.strategy)
+        .deposit(&env.current_contract_address(), &principal);
+
+    EventEmitter::emit_yield_deposited(
+        env,
+        group_id,
+        cycle,
+        deposit.strategy.clone(),
+        principal,
+        env.ledger().timestamp(),
+    );
+    Ok(deposit)
+}
+
+/// Withdraws the pool deposited for `cycle`, if any, and books its yield.
+///
+/// The withdrawal goes to the strategy that took the deposit, even if it has
+/// since been removed from the whitelist, so funds are never stranded.
+///
+/// # Returns
+/// The yield to add to the cycle's payout: all of it when the group pays
+/// yield to recipients, nothing when it is held back for the members.
+///
+/// # Errors
+/// - `InvalidState` - The outstanding deposit belongs to another cycle
+/// - `PayoutFailed` - The strategy returned less than the principal
+/// - `Overflow` - The yield totals overflow
+pub(crate) fn withdraw_pool(
+    env: &Env,
+    group_id: u64,
+    cycle: u32,
+    timestamp: u64,
+) -> Result<i128, StellarSaveError> {
+    let deposit = match load_deposit(env, group_id) {
+        Some(deposit) => deposit,
+        None => return Ok(0),
+    };
+    if deposit.cycle != cycle {
+        return Err(StellarSaveError::InvalidState);
+    }
+
+    let returned =
+        YieldStrategyClient::new(env, &deposit.strategy).withdraw(&env.current_contract_address());
+    let earned = returned
+        .checked_sub(deposit.principal)
+        .filter(|earned| *earned >= 0)
+        .ok_or(StellarSaveError::PayoutFailed)?;
+    env.storage()
+        .persistent()
+        .remove(&StorageKeyBuilder::group_yield_deposit(group_id));
+
+    add_to_balance(
+        env,
+        &StorageKeyBuilder::group_yield_earned(group_id),
+        earned,
+    )?;
+
+    let distribution = load_config(env, group_id)
+        .map(|config| config.distribution)
+        .unwrap_or(YieldDistribution::Recipient);
+    let recipient_yield = match distribution {
+        YieldDistribution::Recipient => earned,
+        YieldDistribution::Members => {
+            add_to_balance(
+                env,
+                &StorageKeyBuilder::group_yield_reserve(group_id),
+                earned,
+            )?;
+            0
+        }
+    };
+
+    EventEmitter::emit_yield_withdrawn(env, group_id, cycle, deposit.principal, earned, timestamp);
+    Ok(recipient_yield)
+}
+
+fn add_to_balance(env: &Env, key: &StorageKey, amount: i128) -> Result<(), StellarSaveError> {
+    if amount == 0 {
+        return Ok(());
+    }
+    let balance: i128 = env.storage().persistent().get(key).unwrap_or(0);
+    let balance = balance
+        .checked_add(amount)
+        .ok_or(StellarSaveError::Overflow)?;
+    set_persistent(env, key, &balance);
+    Ok(())
+}